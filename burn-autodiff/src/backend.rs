@@ -1,5 +1,5 @@
-use crate::{grads::Gradients, graph::backward::backward, tensor::ADTensor};
-use burn_tensor::backend::{ADBackend, Backend};
+use crate::{grads::Gradients, graph::backward::backward, ops::checkpoint, tensor::ADTensor};
+use burn_tensor::backend::{ADBackend, Backend, CheckpointBackend, CheckpointBackward};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ADBackendDecorator<B> {
@@ -62,3 +62,22 @@ impl<B: Backend> ADBackend for ADBackendDecorator<B> {
         ADTensor::new(tensor)
     }
 }
+
+impl<B: Backend> CheckpointBackend for ADBackendDecorator<B> {
+    fn grad_register<const D: usize>(
+        tensor: &ADTensor<B, D>,
+        grads: &mut Gradients,
+        grad: B::TensorPrimitive<D>,
+    ) {
+        grads.register::<B, D>(tensor.node.clone(), grad)
+    }
+
+    fn checkpoint<const D_IN: usize, const D_OUT: usize>(
+        input: ADTensor<B, D_IN>,
+        output: B::TensorPrimitive<D_OUT>,
+        backward: CheckpointBackward<Self, D_IN, D_OUT>,
+        captures_tracked: bool,
+    ) -> ADTensor<B, D_OUT> {
+        checkpoint(input, output, backward, captures_tracked)
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     tensor::ADTensor,
     ADBackendDecorator,
 };
-use burn_tensor::{backend::Backend, ops::ActivationOps};
+use burn_tensor::{backend::Backend, ops::ActivationOps, ElementConversion};
 
 impl<B: Backend> ActivationOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn gelu<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
@@ -52,4 +52,29 @@ impl<B: Backend> ActivationOps<ADBackendDecorator<B>> for ADBackendDecorator<B>
             OpsKind::UnTracked(prep) => prep.finish(output),
         }
     }
+
+    fn sigmoid<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Sigmoid;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sigmoid {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let output = ops.state;
+                    let value = B::mul(output.clone(), B::add_scalar(B::neg(output), 1.elem()));
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Sigmoid.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::sigmoid(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::sigmoid(tensor.primitive)),
+        }
+    }
 }
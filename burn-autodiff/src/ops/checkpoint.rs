@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use super::{Backward, Init, Ops, OpsKind, OpsPrep};
+use crate::{grads::Gradients, graph::Requirement, tensor::ADTensor, ADBackendDecorator};
+use burn_tensor::backend::{Backend, CheckpointBackward};
+
+#[derive(Debug)]
+pub(crate) struct Checkpoint<const D_IN: usize>;
+
+/// State of a checkpoint: only the input is kept, the intermediate activations are recomputed.
+#[derive(Clone)]
+pub(crate) struct CheckpointState<B: Backend, const D_IN: usize, const D_OUT: usize> {
+    input: B::TensorPrimitive<D_IN>,
+    backward: Arc<CheckpointBackward<ADBackendDecorator<B>, D_IN, D_OUT>>,
+}
+
+impl<B: Backend, const D_IN: usize, const D_OUT: usize> std::fmt::Debug
+    for CheckpointState<B, D_IN, D_OUT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckpointState")
+            .field("input", &self.input)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Backend, const D_IN: usize, const D_OUT: usize> Backward<B, D_OUT, 1> for Checkpoint<D_IN> {
+    type State = CheckpointState<B, D_IN, D_OUT>;
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [parent] = ops.parents;
+        let grad = grads.consume::<B, D_OUT>(&ops.node);
+        let grad_input = (ops.state.backward)(ops.state.input, grad, grads);
+
+        if let Some(node) = parent {
+            grads.register::<B, D_IN>(node, grad_input);
+        }
+    }
+}
+
+pub(crate) fn checkpoint<B: Backend, const D_IN: usize, const D_OUT: usize>(
+    input: ADTensor<B, D_IN>,
+    output: B::TensorPrimitive<D_OUT>,
+    backward: CheckpointBackward<ADBackendDecorator<B>, D_IN, D_OUT>,
+    captures_tracked: bool,
+) -> ADTensor<B, D_OUT> {
+    // The backward function may capture tracked tensors that aren't parents of the output, such as
+    // module parameters, in which case the output is tracked even if the input isn't.
+    let requirement = match captures_tracked {
+        true => Requirement::GradInBackward,
+        false => Requirement::from_nodes(core::slice::from_ref(&input.node)),
+    };
+    let prep = OpsPrep::<Checkpoint<D_IN>, B, CheckpointState<B, D_IN, D_OUT>, D_OUT, 1, Init>::new(
+        [input.node],
+        [input.graph],
+        requirement,
+        Checkpoint,
+    );
+
+    match prep.statefull() {
+        OpsKind::Tracked(prep) => {
+            let state = CheckpointState {
+                input: input.primitive,
+                backward: Arc::new(backward),
+            };
+            prep.finish(state, output)
+        }
+        OpsKind::UnTracked(prep) => prep.finish(output),
+    }
+}
@@ -135,6 +135,14 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_sum_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
+        B::int_prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        B::int_prod_dim(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, 1> {
         B::int_mean(tensor)
     }
@@ -151,6 +159,22 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::int_repeat(tensor, dim, times)
     }
 
+    fn int_chunk<const D: usize>(
+        tensor: IntTensor<B, D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<B, D>> {
+        B::int_chunk(tensor, chunks, dim)
+    }
+
+    fn int_split<const D: usize>(
+        tensor: IntTensor<B, D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<IntTensor<B, D>> {
+        B::int_split(tensor, split_size, dim)
+    }
+
     fn int_greater<const D: usize>(lhs: IntTensor<B, D>, rhs: IntTensor<B, D>) -> BoolTensor<B, D> {
         B::int_greater(lhs, rhs)
     }
@@ -281,4 +305,32 @@ impl<B: Backend> IntTensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         B::int_min_dim_with_indexes(tensor, dim)
     }
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+    fn int_sort_with_indexes<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_sort_with_indexes(tensor, dim, descending)
+    }
+    fn int_topk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        B::int_topk(tensor, k, dim)
+    }
 }
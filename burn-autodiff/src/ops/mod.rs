@@ -2,6 +2,7 @@ mod activation;
 mod backward;
 mod base;
 mod bool_tensor;
+mod checkpoint;
 mod int_tensor;
 mod module;
 mod tensor;
@@ -11,3 +12,5 @@ pub(crate) mod maxmin;
 pub use backward::*;
 pub use base::*;
 pub use int_tensor::*;
+
+pub(crate) use checkpoint::checkpoint;
@@ -1028,6 +1028,14 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::argmin(tensor.primitive, dim)
     }
 
+    fn argsort<const D: usize>(
+        tensor: ADTensor<B, D>,
+        dim: usize,
+        descending: bool,
+    ) -> IntTensor<B, D> {
+        B::argsort(tensor.primitive, dim, descending)
+    }
+
     fn exp<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Exp;
@@ -1221,6 +1229,121 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn atan<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Atan;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Atan {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::add_scalar(B::powf(ops.state, 2.0), 1.elem());
+                    B::div(grad, value)
+                });
+            }
+        }
+
+        match Atan.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::atan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::atan(tensor.primitive)),
+        }
+    }
+
+    fn asin<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Asin;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Asin {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::add_scalar(B::neg(B::powf(ops.state, 2.0)), 1.elem());
+                    B::div(grad, B::sqrt(value))
+                });
+            }
+        }
+
+        match Asin.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::asin(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::asin(tensor.primitive)),
+        }
+    }
+
+    fn acos<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Acos;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Acos {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::add_scalar(B::neg(B::powf(ops.state, 2.0)), 1.elem());
+                    B::neg(B::div(grad, B::sqrt(value)))
+                });
+            }
+        }
+
+        match Acos.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::acos(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::acos(tensor.primitive)),
+        }
+    }
+
+    fn sinh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Sinh;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sinh {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::cosh(ops.state);
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Sinh.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::sinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::sinh(tensor.primitive)),
+        }
+    }
+
+    fn cosh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cosh;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cosh {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::sinh(ops.state);
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Cosh.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::cosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cosh(tensor.primitive)),
+        }
+    }
+
     fn erf<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Erf;
@@ -1248,6 +1371,62 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn floor<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Floor;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Floor {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Floor
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::floor(tensor.primitive))
+    }
+
+    fn ceil<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Ceil;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Ceil {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Ceil.prepare([tensor.node], [tensor.graph])
+            .stateless(B::ceil(tensor.primitive))
+    }
+
+    fn round<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Round;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Round {
+            type State = ();
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::zeros(B::shape(&grad), &B::device(&grad))
+                });
+            }
+        }
+
+        Round
+            .prepare([tensor.node], [tensor.graph])
+            .stateless(B::round(tensor.primitive))
+    }
+
     fn cat<const D: usize>(tensors: Vec<ADTensor<B, D>>, dim: usize) -> ADTensor<B, D> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend, const D: usize> {
@@ -1310,7 +1489,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indexes(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::max_dim(tensor.primitive, dim)),
         }
@@ -1323,7 +1502,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::max_dim_with_indexes(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
@@ -1340,7 +1519,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indexes(tensor.primitive, dim);
-                prep.finish((index, shape), tensor)
+                prep.finish((index, shape, dim), tensor)
             }
             OpsKind::UnTracked(prep) => prep.finish(B::min_dim(tensor.primitive, dim)),
         }
@@ -1353,7 +1532,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             OpsKind::Tracked(prep) => {
                 let shape = B::shape(&tensor.primitive);
                 let (tensor, index) = B::min_dim_with_indexes(tensor.primitive, dim);
-                let tensor = prep.finish((index.clone(), shape), tensor);
+                let tensor = prep.finish((index.clone(), shape, dim), tensor);
 
                 (tensor, index)
             }
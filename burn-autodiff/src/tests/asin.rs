@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(ad_asin)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_asin() {
+        let data = Data::<f32, 2>::from([[0.0, 0.5], [-0.5, 0.8]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+        let grads = tensor.clone().asin().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.1547], [1.1547, 1.6667]]), 3);
+    }
+}
@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(ad_cosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cosh() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0], [-0.5, 2.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+        let grads = tensor.clone().cosh().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.1752], [-0.5211, 3.6269]]), 3);
+    }
+}
@@ -42,4 +42,23 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[10.0, 8.0], [15.0, 56.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_max_min_dim_along_first_dim() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+
+        let grads_1 = tensor_1.clone().max_dim(0).mul_scalar(2.0).backward();
+        let grads_2 = tensor_2.clone().min_dim(0).mul_scalar(2.0).backward();
+
+        let grad_1 = tensor_1.grad(&grads_1).unwrap();
+        let grad_2 = tensor_2.grad(&grads_2).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [0.0, 0.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [2.0, 2.0]]), 5);
+    }
 }
@@ -1,5 +1,8 @@
+mod acos;
 mod add;
 mod aggregation;
+mod asin;
+mod atan;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
@@ -9,6 +12,7 @@ mod complex;
 mod conv1d;
 mod conv2d;
 mod cos;
+mod cosh;
 mod cross_entropy;
 mod div;
 mod erf;
@@ -29,9 +33,13 @@ mod neg;
 mod pow;
 mod relu;
 mod reshape;
+mod round;
+mod sigmoid;
 mod sin;
+mod sinh;
 mod softmax;
 mod sqrt;
+mod straight_through;
 mod sub;
 mod tanh;
 mod transpose;
@@ -65,6 +73,11 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_maxmin!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
+        burn_autodiff::testgen_ad_cosh!();
+        burn_autodiff::testgen_ad_acos!();
+        burn_autodiff::testgen_ad_asin!();
+        burn_autodiff::testgen_ad_atan!();
+        burn_autodiff::testgen_ad_sinh!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
@@ -80,9 +93,12 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_neg!();
         burn_autodiff::testgen_ad_powf!();
         burn_autodiff::testgen_ad_reshape!();
+        burn_autodiff::testgen_ad_round!();
+        burn_autodiff::testgen_ad_sigmoid!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
         burn_autodiff::testgen_ad_sqrt!();
+        burn_autodiff::testgen_ad_straight_through!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_transpose!();
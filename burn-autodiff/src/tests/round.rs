@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(ad_round)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_floor() {
+        let tensor = TestADTensor::from_data(data()).require_grad();
+        let grads = tensor.clone().floor().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&zeros(), 3);
+    }
+
+    #[test]
+    fn should_diff_ceil() {
+        let tensor = TestADTensor::from_data(data()).require_grad();
+        let grads = tensor.clone().ceil().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&zeros(), 3);
+    }
+
+    #[test]
+    fn should_diff_round() {
+        let tensor = TestADTensor::from_data(data()).require_grad();
+        let grads = tensor.clone().round().sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&zeros(), 3);
+    }
+
+    fn data() -> Data<f32, 2> {
+        Data::from([[0.5, 1.2], [-0.7, 2.5]])
+    }
+
+    fn zeros() -> Data<f32, 2> {
+        Data::from([[0.0, 0.0], [0.0, 0.0]])
+    }
+}
@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(ad_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data};
+
+    #[test]
+    fn should_diff_sigmoid() {
+        let data = Data::<f32, 2>::from([[0.0, 2.0], [-2.0, 50.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+        let grads = activation::sigmoid(tensor.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[0.25, 0.105], [0.105, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_straight_through)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_straight_through() {
+        let data = Data::<f32, 2>::from([[0.2, 1.7], [2.4, -0.6]]);
+        let data_quantized = Data::<f32, 2>::from([[0.0, 2.0], [2.0, -1.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+        let quantized = TestADTensor::from_data(data_quantized.clone());
+
+        let tensor_st = tensor.clone().straight_through(quantized);
+        assert_eq!(tensor_st.to_data(), data_quantized);
+
+        let tensor_out = tensor_st.clone().mul(tensor_st);
+        let grads = tensor_out.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), Data::from([[0.0, 4.0], [4.0, -2.0]]));
+    }
+}
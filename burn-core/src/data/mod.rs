@@ -1,4 +1,5 @@
 pub mod dataloader;
+pub mod transform;
 pub mod dataset {
     pub use burn_dataset::*;
 }
@@ -0,0 +1,140 @@
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Scale each feature to a given range, `[0, 1]` by default, based on its minimum and maximum:
+///
+/// `y = (x - min) / (max - min) * (range_max - range_min) + range_min`
+///
+/// The per-feature statistics are computed by [fit](MinMaxScaler::fit), features having the same
+/// minimum and maximum being only shifted to the lower bound of the range.
+#[derive(Clone, Debug)]
+pub struct MinMaxScaler<B: Backend> {
+    min: Tensor<B, 2>,
+    scale: Tensor<B, 2>,
+    range: (f64, f64),
+}
+
+impl<B: Backend> MinMaxScaler<B> {
+    /// Compute the minimum and maximum of each feature of the given data to scale it to `[0, 1]`.
+    ///
+    /// # Shapes
+    ///
+    /// - data: `[num_samples, num_features]`
+    pub fn fit(data: Tensor<B, 2>) -> Self {
+        Self::fit_with_range(data, (0.0, 1.0))
+    }
+
+    /// Compute the minimum and maximum of each feature of the given data to scale it to the
+    /// given `(min, max)` range.
+    ///
+    /// # Shapes
+    ///
+    /// - data: `[num_samples, num_features]`
+    ///
+    /// # Panics
+    ///
+    /// If the lower bound of the range isn't smaller than the upper bound.
+    pub fn fit_with_range(data: Tensor<B, 2>, range: (f64, f64)) -> Self {
+        assert!(
+            range.0 < range.1,
+            "The lower bound of the range ({}) should be smaller than the upper bound ({})",
+            range.0,
+            range.1
+        );
+
+        // Features are moved to the last dimension to reduce over the samples.
+        let features = data.transpose();
+        let min = features.clone().min_dim(1).transpose();
+        let max = features.max_dim(1).transpose();
+
+        let scale = max.sub(min.clone());
+        let scale = scale.clone().mask_fill(scale.equal_elem(0.0), 1.0);
+
+        Self { min, scale, range }
+    }
+
+    /// Scale the given data using the fitted statistics.
+    ///
+    /// # Shapes
+    ///
+    /// - data: `[num_samples, num_features]`
+    /// - output: `[num_samples, num_features]`
+    pub fn transform(&self, data: Tensor<B, 2>) -> Tensor<B, 2> {
+        let (range_min, range_max) = self.range;
+
+        data.sub(self.min.clone())
+            .div(self.scale.clone())
+            .mul_scalar(range_max - range_min)
+            .add_scalar(range_min)
+    }
+
+    /// Compute the statistics on the given data to scale it to `[0, 1]`, then scale it.
+    pub fn fit_transform(data: Tensor<B, 2>) -> (Self, Tensor<B, 2>) {
+        let scaler = Self::fit(data.clone());
+        let output = scaler.transform(data);
+
+        (scaler, output)
+    }
+
+    /// Scale back the data to the original representation.
+    pub fn inverse_transform(&self, data: Tensor<B, 2>) -> Tensor<B, 2> {
+        let (range_min, range_max) = self.range;
+
+        data.sub_scalar(range_min)
+            .div_scalar(range_max - range_min)
+            .mul(self.scale.clone())
+            .add(self.min.clone())
+    }
+
+    /// The minimum of each feature, of shape `[1, num_features]`.
+    pub fn min(&self) -> Tensor<B, 2> {
+        self.min.clone()
+    }
+
+    /// The maximum of each feature, of shape `[1, num_features]`.
+    pub fn max(&self) -> Tensor<B, 2> {
+        self.min.clone().add(self.scale.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    fn data() -> Tensor<TestBackend, 2> {
+        Tensor::from_floats([
+            [1.0, -10.0, 0.5],
+            [3.0, 20.0, 0.1],
+            [2.0, 0.0, 0.3],
+            [5.0, 10.0, 0.2],
+        ])
+    }
+
+    #[test]
+    fn transformed_features_should_be_between_zero_and_one() {
+        let (_scaler, output) = MinMaxScaler::fit_transform(data());
+
+        let features = output.transpose();
+        let min = features.clone().min_dim(1);
+        let max = features.max_dim(1);
+
+        min.into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0], [0.0]]), 3);
+        max.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn inverse_transform_should_recover_data() {
+        let scaler = MinMaxScaler::fit_with_range(data(), (-1.0, 1.0));
+
+        let output = scaler.transform(data());
+
+        output.clone().into_data().assert_within_range(-1.0..1.0001);
+        scaler
+            .inverse_transform(output)
+            .into_data()
+            .assert_approx_eq(&data().into_data(), 3);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::tensor::{backend::Backend, Data, Int, Shape, Tensor};
+use alloc::vec::Vec;
+use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand_distr::Beta;
+
+/// Apply [mixup](https://arxiv.org/abs/1710.09412) on a batch, mixing each sample with another
+/// sample from a shuffled copy of the batch.
+///
+/// The mixing coefficient is sampled from a `Beta(alpha, alpha)` distribution and is shared by
+/// the whole batch, see [mixup_with](mixup_with) for the blending itself.
+///
+/// # Panics
+///
+/// If `alpha` isn't strictly positive.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, ...dims]`
+/// - targets: `[batch_size, num_classes]`
+pub fn mixup<B: Backend, const D: usize>(
+    inputs: Tensor<B, D>,
+    targets: Tensor<B, 2>,
+    alpha: f64,
+    device: &B::Device,
+) -> (Tensor<B, D>, Tensor<B, 2>) {
+    let mut rng = thread_rng();
+    let beta = Beta::new(alpha, alpha).expect("The mixup alpha should be strictly positive");
+    let lambda = rng.sample(beta);
+
+    let batch_size = inputs.dims()[0];
+    let mut permutation: Vec<i64> = (0..batch_size as i64).collect();
+    permutation.shuffle(&mut rng);
+    let permutation = Tensor::from_data_device(
+        Data::new(permutation, Shape::new([batch_size])).convert(),
+        device,
+    );
+
+    mixup_with(inputs, targets, lambda, permutation)
+}
+
+/// Blend each sample of the batch with the sample at the same position in the permuted batch:
+///
+/// `y = lambda * x + (1 - lambda) * x[permutation]`
+///
+/// The same blending is applied on the inputs and the targets.
+///
+/// # Shapes
+///
+/// - inputs: `[batch_size, ...dims]`
+/// - targets: `[batch_size, num_classes]`
+/// - permutation: `[batch_size]`
+pub fn mixup_with<B: Backend, const D: usize>(
+    inputs: Tensor<B, D>,
+    targets: Tensor<B, 2>,
+    lambda: f64,
+    permutation: Tensor<B, 1, Int>,
+) -> (Tensor<B, D>, Tensor<B, 2>) {
+    let inputs = blend(inputs, lambda, permutation.clone());
+    let targets = blend(targets, lambda, permutation);
+
+    (inputs, targets)
+}
+
+fn blend<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    lambda: f64,
+    permutation: Tensor<B, 1, Int>,
+) -> Tensor<B, D> {
+    let shuffled = tensor.clone().index_select(0, permutation);
+
+    tensor
+        .mul_scalar(lambda)
+        .add(shuffled.mul_scalar(1.0 - lambda))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_mixup_with_fixed_coefficient() {
+        let inputs = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let targets = Tensor::<TestBackend, 2>::from_floats([
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        let permutation = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 1]);
+
+        let (inputs, targets) = mixup_with(inputs, targets, 0.7, permutation);
+
+        let inputs_expected = Data::from([[2.2, 3.2], [2.4, 3.4], [4.4, 5.4]]);
+        let targets_expected = Data::from([[0.7, 0.0, 0.3], [0.3, 0.7, 0.0], [0.0, 0.3, 0.7]]);
+        inputs.into_data().assert_approx_eq(&inputs_expected, 5);
+        targets.into_data().assert_approx_eq(&targets_expected, 5);
+    }
+
+    #[test]
+    fn test_mixup_keeps_targets_normalized() {
+        let device = Default::default();
+        let inputs = Tensor::<TestBackend, 3>::ones([4, 2, 3]);
+        let targets =
+            Tensor::<TestBackend, 2>::from_floats([[1.0, 0.0], [0.0, 1.0], [0.5, 0.5], [0.2, 0.8]]);
+
+        let (inputs, targets) = mixup(inputs, targets, 0.4, &device);
+
+        assert_eq!(inputs.dims(), [4, 2, 3]);
+        inputs
+            .into_data()
+            .assert_approx_eq(&Tensor::<TestBackend, 3>::ones([4, 2, 3]).into_data(), 5);
+        targets
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0], [1.0], [1.0]]), 5);
+    }
+}
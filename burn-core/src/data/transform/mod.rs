@@ -0,0 +1,7 @@
+mod min_max_scaler;
+mod mixup;
+mod standard_scaler;
+
+pub use min_max_scaler::*;
+pub use mixup::*;
+pub use standard_scaler::*;
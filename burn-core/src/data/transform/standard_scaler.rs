@@ -0,0 +1,100 @@
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Standardize features by removing the mean and scaling to unit variance:
+///
+/// `y = (x - mean) / std`
+///
+/// The per-feature statistics are computed by [fit](StandardScaler::fit) using the biased
+/// variance, features with a standard deviation of zero being left unscaled.
+#[derive(Clone, Debug)]
+pub struct StandardScaler<B: Backend> {
+    mean: Tensor<B, 2>,
+    std: Tensor<B, 2>,
+}
+
+impl<B: Backend> StandardScaler<B> {
+    /// Compute the mean and standard deviation of each feature of the given data.
+    ///
+    /// # Shapes
+    ///
+    /// - data: `[num_samples, num_features]`
+    pub fn fit(data: Tensor<B, 2>) -> Self {
+        let (var, mean) = data.var_mean_bias(0);
+        let std = var.sqrt();
+        let std = std.clone().mask_fill(std.equal_elem(0.0), 1.0);
+
+        Self { mean, std }
+    }
+
+    /// Standardize the given data using the fitted statistics.
+    ///
+    /// # Shapes
+    ///
+    /// - data: `[num_samples, num_features]`
+    /// - output: `[num_samples, num_features]`
+    pub fn transform(&self, data: Tensor<B, 2>) -> Tensor<B, 2> {
+        data.sub(self.mean.clone()).div(self.std.clone())
+    }
+
+    /// Compute the statistics on the given data, then standardize it.
+    pub fn fit_transform(data: Tensor<B, 2>) -> (Self, Tensor<B, 2>) {
+        let scaler = Self::fit(data.clone());
+        let output = scaler.transform(data);
+
+        (scaler, output)
+    }
+
+    /// Scale back standardized data to the original representation.
+    pub fn inverse_transform(&self, data: Tensor<B, 2>) -> Tensor<B, 2> {
+        data.mul(self.std.clone()).add(self.mean.clone())
+    }
+
+    /// The mean of each feature, of shape `[1, num_features]`.
+    pub fn mean(&self) -> Tensor<B, 2> {
+        self.mean.clone()
+    }
+
+    /// The standard deviation of each feature, of shape `[1, num_features]`.
+    pub fn std(&self) -> Tensor<B, 2> {
+        self.std.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::{Data, Distribution, ElementConversion};
+    use crate::TestBackend;
+
+    #[test]
+    fn transformed_data_should_have_zero_mean_and_unit_variance() {
+        TestBackend::seed(0);
+
+        let data = Tensor::<TestBackend, 2>::random([1000, 3], Distribution::Normal(5.0, 3.0))
+            .mul(Tensor::from_floats([[1.0, 10.0, 0.1]]));
+
+        let (_scaler, output) = StandardScaler::fit_transform(data);
+        let (var, mean) = output.var_mean_bias(0);
+
+        mean.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0, 0.0]]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn inverse_transform_should_recover_data() {
+        let data = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 2.0], [5.0, 2.0]]);
+
+        let scaler = StandardScaler::fit(data.clone());
+        let output = scaler.transform(data.clone());
+
+        let std: f32 = scaler.std().sum().into_scalar().elem();
+        // The constant feature has a standard deviation of zero, so it isn't scaled.
+        assert!((std - (1.0 + libm::sqrt(8.0 / 3.0) as f32)).abs() < 1e-4);
+        scaler
+            .inverse_transform(output)
+            .into_data()
+            .assert_approx_eq(&data.into_data(), 3);
+    }
+}
@@ -0,0 +1,146 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [warmup cosine](WarmupCosineLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct WarmupCosineLRSchedulerConfig {
+    /// The learning rate reached at the end of the warmup.
+    peak_lr: LearningRate,
+    /// The total number of steps, after which the learning rate stays at its minimum.
+    total_steps: usize,
+    /// The number of steps of the linear warmup.
+    #[config(default = 0)]
+    warmup_steps: usize,
+    /// The learning rate reached at the end of the cosine decay.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+/// Learning rate scheduler linearly increasing the learning rate from zero to its peak value
+/// during the warmup steps, then following a cosine decay down to the minimum learning rate at
+/// the last step.
+#[derive(Clone, Debug)]
+pub struct WarmupCosineLRScheduler {
+    peak_lr: LearningRate,
+    min_lr: LearningRate,
+    warmup_steps: usize,
+    total_steps: usize,
+    step: usize,
+}
+
+impl WarmupCosineLRSchedulerConfig {
+    /// Initialize a new [warmup cosine](WarmupCosineLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> WarmupCosineLRScheduler {
+        assert!(
+            self.warmup_steps <= self.total_steps,
+            "The warmup steps ({}) can't be greater than the total steps ({})",
+            self.warmup_steps,
+            self.total_steps
+        );
+
+        WarmupCosineLRScheduler {
+            peak_lr: self.peak_lr,
+            min_lr: self.min_lr,
+            warmup_steps: self.warmup_steps,
+            total_steps: self.total_steps,
+            step: 0,
+        }
+    }
+}
+
+impl WarmupCosineLRScheduler {
+    /// Get the learning rate at the given step, without updating the state of the scheduler.
+    pub fn lr_at(&self, step: usize) -> LearningRate {
+        if step < self.warmup_steps {
+            return self.peak_lr * step as f64 / self.warmup_steps as f64;
+        }
+
+        let decay_steps = self.total_steps - self.warmup_steps;
+        if decay_steps == 0 {
+            return self.min_lr;
+        }
+
+        let progress = (step - self.warmup_steps) as f64 / decay_steps as f64;
+        let progress = progress.clamp(0.0, 1.0);
+        let cosine = 0.5 * (1.0 + libm::cos(core::f64::consts::PI * progress));
+
+        self.min_lr + (self.peak_lr - self.min_lr) * cosine
+    }
+}
+
+impl LRScheduler for WarmupCosineLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.lr_at(self.step);
+        self.step += 1;
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PEAK_LR: LearningRate = 0.1;
+    const MIN_LR: LearningRate = 0.001;
+
+    fn scheduler() -> WarmupCosineLRScheduler {
+        WarmupCosineLRSchedulerConfig::new(PEAK_LR, 110)
+            .with_warmup_steps(10)
+            .with_min_lr(MIN_LR)
+            .init()
+    }
+
+    fn assert_lr(actual: LearningRate, expected: LearningRate) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "Expected learning rate {expected}, but got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_lr_at_should_follow_warmup_and_cosine_decay() {
+        let scheduler = scheduler();
+
+        assert_lr(scheduler.lr_at(0), 0.0);
+        assert_lr(scheduler.lr_at(5), PEAK_LR * 0.5);
+        assert_lr(scheduler.lr_at(10), PEAK_LR);
+        assert_lr(scheduler.lr_at(60), MIN_LR + (PEAK_LR - MIN_LR) * 0.5);
+        assert_lr(scheduler.lr_at(110), MIN_LR);
+        assert_lr(scheduler.lr_at(200), MIN_LR);
+    }
+
+    #[test]
+    fn test_step_should_match_lr_at() {
+        let mut scheduler = scheduler();
+        let reference = scheduler.clone();
+
+        for step in 0..=110 {
+            assert_lr(scheduler.step(), reference.lr_at(step));
+        }
+    }
+
+    #[test]
+    fn test_load_record_should_resume_schedule() {
+        let mut scheduler = scheduler();
+        for _ in 0..20 {
+            scheduler.step();
+        }
+
+        let mut resumed = self::scheduler().load_record(scheduler.to_record());
+
+        assert_lr(resumed.step(), scheduler.step());
+    }
+}
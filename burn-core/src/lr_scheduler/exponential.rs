@@ -0,0 +1,72 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create an [exponential](ExponentialLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct ExponentialLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The factor multiplying the learning rate at each step.
+    gamma: f64,
+}
+
+/// Learning rate scheduler multiplying the learning rate by `gamma` at each step.
+#[derive(Clone, Debug)]
+pub struct ExponentialLRScheduler {
+    init_lr: LearningRate,
+    gamma: f64,
+    step: usize,
+}
+
+impl ExponentialLRSchedulerConfig {
+    /// Initialize a new [exponential](ExponentialLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> ExponentialLRScheduler {
+        ExponentialLRScheduler {
+            init_lr: self.init_lr,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LRScheduler for ExponentialLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.init_lr * self.gamma.powi(self.step as i32);
+        self.step += 1;
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_should_decay_by_gamma_each_step() {
+        let gamma = 0.9;
+        let mut scheduler = ExponentialLRSchedulerConfig::new(2.0, gamma).init();
+        let mut lr_previous = scheduler.step();
+
+        assert_eq!(lr_previous, 2.0);
+
+        for _ in 0..10 {
+            let lr = scheduler.step();
+            assert!((lr - lr_previous * gamma).abs() < 1e-12);
+            lr_previous = lr;
+        }
+    }
+}
@@ -1,5 +1,10 @@
 pub mod constant;
+pub mod cosine;
+pub mod exponential;
 pub mod noam;
+pub mod one_cycle;
+pub mod plateau;
+pub mod step;
 
 mod base;
 
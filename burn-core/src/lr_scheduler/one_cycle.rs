@@ -0,0 +1,183 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [one cycle](OneCycleLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct OneCycleLRSchedulerConfig {
+    /// The highest learning rate, reached at the end of the rising phase.
+    max_lr: LearningRate,
+    /// The total number of steps of the cycle.
+    total_steps: usize,
+    /// The fraction of the cycle spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    /// The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    /// The final learning rate is `max_lr / (div_factor * final_div_factor)`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+    /// The lowest momentum, reached at the peak learning rate.
+    #[config(default = 0.85)]
+    base_momentum: f64,
+    /// The highest momentum, used at the start and end of the cycle.
+    #[config(default = 0.95)]
+    max_momentum: f64,
+}
+
+/// One cycle learning rate scheduler as described in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// The learning rate follows a cosine annealing from its initial value to `max_lr` during the
+/// first `pct_start` fraction of the steps, then down to its final value at the last step, while
+/// the momentum varies inversely between `max_momentum` and `base_momentum`.
+#[derive(Clone, Debug)]
+pub struct OneCycleLRScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    base_momentum: f64,
+    max_momentum: f64,
+    peak_step: f64,
+    last_step: f64,
+    step: usize,
+}
+
+impl OneCycleLRSchedulerConfig {
+    /// Initialize a new [one cycle](OneCycleLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> OneCycleLRScheduler {
+        assert!(
+            self.pct_start > 0.0 && self.pct_start < 1.0,
+            "pct_start should be between 0 and 1, got {}",
+            self.pct_start
+        );
+        assert!(
+            self.total_steps > 1,
+            "The cycle should have at least 2 steps"
+        );
+
+        let initial_lr = self.max_lr / self.div_factor;
+        let peak_step = f64::max(self.pct_start * self.total_steps as f64 - 1.0, 0.0);
+
+        OneCycleLRScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            final_lr: initial_lr / self.final_div_factor,
+            base_momentum: self.base_momentum,
+            max_momentum: self.max_momentum,
+            peak_step,
+            last_step: (self.total_steps - 1) as f64,
+            step: 0,
+        }
+    }
+}
+
+impl OneCycleLRScheduler {
+    /// Get the learning rate at the given step, without updating the state of the scheduler.
+    pub fn lr_at(&self, step: usize) -> LearningRate {
+        self.interpolate(step, self.initial_lr, self.max_lr, self.final_lr)
+    }
+
+    /// Get the momentum at the given step, without updating the state of the scheduler.
+    pub fn momentum_at(&self, step: usize) -> f64 {
+        let (max, base) = (self.max_momentum, self.base_momentum);
+        self.interpolate(step, max, base, max)
+    }
+
+    fn interpolate(&self, step: usize, start: f64, peak: f64, end: f64) -> f64 {
+        let step = f64::min(step as f64, self.last_step);
+
+        if step <= self.peak_step {
+            let pct = if self.peak_step > 0.0 {
+                step / self.peak_step
+            } else {
+                1.0
+            };
+            cosine_annealing(start, peak, pct)
+        } else {
+            let pct = (step - self.peak_step) / (self.last_step - self.peak_step);
+            cosine_annealing(peak, end, pct)
+        }
+    }
+}
+
+fn cosine_annealing(start: f64, end: f64, pct: f64) -> f64 {
+    end + (start - end) / 2.0 * (1.0 + libm::cos(core::f64::consts::PI * pct))
+}
+
+impl LRScheduler for OneCycleLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let lr = self.lr_at(self.step);
+        self.step += 1;
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "Expected {expected}, but got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_lr_should_start_peak_and_end_at_expected_values() {
+        let scheduler = OneCycleLRSchedulerConfig::new(1.0, 101)
+            .with_pct_start(0.5)
+            .with_div_factor(10.0)
+            .with_final_div_factor(100.0)
+            .init();
+
+        assert_close(scheduler.lr_at(0), 0.1);
+        assert_close(scheduler.lr_at(100), 0.001);
+
+        let lrs: Vec<f64> = (0..101).map(|step| scheduler.lr_at(step)).collect();
+        let peak = lrs
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(step, _)| step)
+            .unwrap();
+
+        // The peak is at `pct_start * total_steps - 1 = 49.5`, between the steps 49 and 50, and
+        // the rising phase is closer to `max_lr` at step 49 than the falling phase at step 50.
+        assert_eq!(peak, 49);
+        assert_close(lrs[49], cosine_annealing(0.1, 1.0, 49.0 / 49.5));
+        assert_close(lrs[50], cosine_annealing(1.0, 0.001, 0.5 / 50.5));
+    }
+
+    #[test]
+    fn test_momentum_should_vary_inversely() {
+        let mut scheduler = OneCycleLRSchedulerConfig::new(1.0, 100).init();
+        let peak_step = 29;
+
+        assert_close(scheduler.momentum_at(0), 0.95);
+        assert_close(scheduler.momentum_at(peak_step), 0.85);
+        assert_close(scheduler.momentum_at(99), 0.95);
+        assert_close(scheduler.lr_at(peak_step), 1.0);
+
+        let lr_first = scheduler.step();
+        let lr_second = scheduler.step();
+        assert_close(lr_first, 1.0 / 25.0);
+        assert!(lr_second > lr_first);
+    }
+}
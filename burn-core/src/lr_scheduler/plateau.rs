@@ -0,0 +1,140 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::record::Record;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [reduce on plateau](ReduceOnPlateauLRScheduler) learning rate
+/// scheduler.
+#[derive(Config)]
+pub struct ReduceOnPlateauLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The factor multiplying the learning rate when the monitored metric stops improving.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of reports without improvement after which the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    /// The minimum decrease of the metric to be considered as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    /// The lower bound of the learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+/// Learning rate scheduler reducing the learning rate when a monitored metric, e.g. the
+/// validation loss, stops decreasing for more than `patience` consecutive reports.
+///
+/// The metric is given using [report](ReduceOnPlateauLRScheduler::report), while
+/// [step](LRScheduler::step) only returns the current learning rate.
+#[derive(Clone, Debug)]
+pub struct ReduceOnPlateauLRScheduler {
+    factor: f64,
+    patience: usize,
+    threshold: f64,
+    min_lr: LearningRate,
+    state: ReduceOnPlateauState,
+}
+
+/// The [reduce on plateau](ReduceOnPlateauLRScheduler) learning rate scheduler state.
+#[derive(Record, Clone, Debug, new)]
+pub struct ReduceOnPlateauState {
+    lr: LearningRate,
+    best: Option<f64>,
+    num_bad_reports: usize,
+}
+
+impl ReduceOnPlateauLRSchedulerConfig {
+    /// Initialize a new [reduce on plateau](ReduceOnPlateauLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> ReduceOnPlateauLRScheduler {
+        ReduceOnPlateauLRScheduler {
+            factor: self.factor,
+            patience: self.patience,
+            threshold: self.threshold,
+            min_lr: self.min_lr,
+            state: ReduceOnPlateauState::new(self.init_lr, None, 0),
+        }
+    }
+}
+
+impl ReduceOnPlateauLRScheduler {
+    /// Report a new value of the monitored metric, reducing the learning rate if it didn't
+    /// improve for more than `patience` reports, and return the effective learning rate.
+    pub fn report(&mut self, metric: f64) -> LearningRate {
+        let state = &mut self.state;
+
+        match state.best {
+            Some(best) if metric >= best - self.threshold => state.num_bad_reports += 1,
+            _ => {
+                state.best = Some(metric);
+                state.num_bad_reports = 0;
+            }
+        }
+
+        if state.num_bad_reports > self.patience {
+            state.lr = f64::max(state.lr * self.factor, self.min_lr);
+            state.num_bad_reports = 0;
+        }
+
+        state.lr
+    }
+}
+
+impl LRScheduler for ReduceOnPlateauLRScheduler {
+    type Record = ReduceOnPlateauState;
+
+    fn step(&mut self) -> LearningRate {
+        self.state.lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.state.clone()
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.state = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lr_should_be_reduced_after_stagnation() {
+        let mut scheduler = ReduceOnPlateauLRSchedulerConfig::new(1.0)
+            .with_factor(0.5)
+            .with_patience(2)
+            .init();
+
+        assert_eq!(scheduler.report(1.0), 1.0);
+        assert_eq!(scheduler.report(0.8), 1.0);
+        // Two reports without improvement are tolerated.
+        assert_eq!(scheduler.report(0.8), 1.0);
+        assert_eq!(scheduler.report(0.9), 1.0);
+        // The third one triggers the reduction.
+        assert_eq!(scheduler.report(0.85), 0.5);
+        assert_eq!(scheduler.step(), 0.5);
+        // An improvement resets the patience.
+        assert_eq!(scheduler.report(0.5), 0.5);
+        assert_eq!(scheduler.report(0.6), 0.5);
+        assert_eq!(scheduler.report(0.6), 0.5);
+        assert_eq!(scheduler.report(0.6), 0.25);
+    }
+
+    #[test]
+    fn test_lr_should_not_go_below_min_lr() {
+        let mut scheduler = ReduceOnPlateauLRSchedulerConfig::new(1.0)
+            .with_patience(0)
+            .with_min_lr(0.05)
+            .init();
+
+        scheduler.report(1.0);
+        assert_eq!(scheduler.report(1.0), 0.1);
+        assert_eq!(scheduler.report(1.0), 0.05);
+        assert_eq!(scheduler.report(1.0), 0.05);
+    }
+}
@@ -0,0 +1,78 @@
+use crate as burn;
+
+use super::LRScheduler;
+use crate::{config::Config, LearningRate};
+
+/// Configuration to create a [step](StepLRScheduler) learning rate scheduler.
+#[derive(Config)]
+pub struct StepLRSchedulerConfig {
+    /// The initial learning rate.
+    init_lr: LearningRate,
+    /// The number of steps between each decay of the learning rate.
+    step_size: usize,
+    /// The factor multiplying the learning rate every `step_size` steps.
+    #[config(default = 0.1)]
+    gamma: f64,
+}
+
+/// Learning rate scheduler multiplying the learning rate by `gamma` every `step_size` steps.
+#[derive(Clone, Debug)]
+pub struct StepLRScheduler {
+    init_lr: LearningRate,
+    step_size: usize,
+    gamma: f64,
+    step: usize,
+}
+
+impl StepLRSchedulerConfig {
+    /// Initialize a new [step](StepLRScheduler) learning rate scheduler.
+    pub fn init(&self) -> StepLRScheduler {
+        assert!(
+            self.step_size > 0,
+            "The step size should be greater than zero"
+        );
+
+        StepLRScheduler {
+            init_lr: self.init_lr,
+            step_size: self.step_size,
+            gamma: self.gamma,
+            step: 0,
+        }
+    }
+}
+
+impl LRScheduler for StepLRScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let num_decays = (self.step / self.step_size) as i32;
+        self.step += 1;
+
+        self.init_lr * self.gamma.powi(num_decays)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.step
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.step = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_lr_should_decay_at_step_boundaries() {
+        let mut scheduler = StepLRSchedulerConfig::new(1.0, 3).with_gamma(0.5).init();
+
+        let lrs: Vec<LearningRate> = (0..7).map(|_| scheduler.step()).collect();
+
+        assert_eq!(lrs, vec![1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.25]);
+    }
+}
@@ -0,0 +1,178 @@
+use alloc::boxed::Box;
+
+use super::{ADModule, ModuleVisitor, ParamId};
+use crate::tensor::{
+    backend::{ADBackend, Backend, CheckpointBackend},
+    Tensor,
+};
+
+type InnerPrimitive<B, const D: usize> =
+    <<B as ADBackend>::InnerBackend as Backend>::TensorPrimitive<D>;
+
+/// Applies the forward function of a module without storing its intermediate activations.
+///
+/// The forward pass is computed with the module parameters and the input untracked, so only the
+/// input is kept for the backward pass. During the backward pass, the forward function is called
+/// again to compute the gradients of the input and of the module parameters, trading compute for
+/// memory.
+///
+/// # Notes
+///
+/// The forward function is called twice, so it should be deterministic. When neither the input nor
+/// the module parameters are tracked, the output is untracked and the forward function is only
+/// called once.
+pub fn checkpoint<B, M, F, const D_IN: usize, const D_OUT: usize>(
+    module: &M,
+    input: Tensor<B, D_IN>,
+    forward: F,
+) -> Tensor<B, D_OUT>
+where
+    B: CheckpointBackend,
+    M: ADModule<B> + 'static,
+    F: Fn(&M, Tensor<B, D_IN>) -> Tensor<B, D_OUT> + Send + Sync + 'static,
+{
+    let output = forward(
+        &module.clone().no_grad(),
+        input.clone().set_require_grad(false),
+    );
+
+    let mut params_require_grad = RequireGradFinder { found: false };
+    module.visit(&mut params_require_grad);
+
+    let module = module.clone();
+    let backward = move |input: InnerPrimitive<B, D_IN>,
+                         grad: InnerPrimitive<B, D_OUT>,
+                         grads: &mut B::Gradients| {
+        let input = Tensor::<B, D_IN>::from_inner(Tensor::from_primitive(input)).require_grad();
+        let grad = Tensor::<B, D_OUT>::from_inner(Tensor::from_primitive(grad));
+        let grads_recomputed = forward(&module, input.clone()).mul(grad).sum().backward();
+
+        module.visit(&mut GradientsRegister::<B> {
+            source: &grads_recomputed,
+            target: grads,
+        });
+
+        input
+            .grad(&grads_recomputed)
+            .unwrap_or_else(|| input.inner().zeros_like())
+            .into_primitive()
+    };
+
+    Tensor::from_primitive(B::checkpoint(
+        input.into_primitive(),
+        output.inner().into_primitive(),
+        Box::new(backward),
+        params_require_grad.found,
+    ))
+}
+
+/// Finds whether any of the visited parameters requires gradients.
+struct RequireGradFinder {
+    found: bool,
+}
+
+impl<B: ADBackend> ModuleVisitor<B> for RequireGradFinder {
+    fn visit<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        self.found = self.found || tensor.is_require_grad();
+    }
+}
+
+/// Registers the gradients of the visited parameters into other gradients.
+struct GradientsRegister<'a, B: CheckpointBackend> {
+    source: &'a B::Gradients,
+    target: &'a mut B::Gradients,
+}
+
+impl<'a, B: CheckpointBackend> ModuleVisitor<B> for GradientsRegister<'a, B> {
+    fn visit<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+        if let Some(grad) = tensor.grad(self.source) {
+            tensor.grad_register(self.target, grad);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{
+        module::Module,
+        nn::{Linear, LinearConfig},
+        tensor::{activation::relu, Distribution},
+        TestADBackend,
+    };
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_checkpoint_gradients_match_forward() {
+        let linear = LinearConfig::new(4, 4).init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 2>::random([3, 4], Distribution::Standard);
+        let input = input.require_grad();
+
+        let grads = forward(&linear, input.clone()).powf(2.0).sum().backward();
+        let grads_checkpoint = checkpoint(&linear, input.clone(), forward)
+            .powf(2.0)
+            .sum()
+            .backward();
+
+        let grad_input = input.grad(&grads).unwrap();
+        let grad_input_checkpoint = input.grad(&grads_checkpoint).unwrap();
+        grad_input
+            .into_data()
+            .assert_approx_eq(&grad_input_checkpoint.into_data(), 4);
+
+        let grad_weight = linear.weight.grad(&grads).unwrap();
+        let grad_weight_checkpoint = linear.weight.grad(&grads_checkpoint).unwrap();
+        grad_weight
+            .into_data()
+            .assert_approx_eq(&grad_weight_checkpoint.into_data(), 4);
+
+        let bias = linear.bias.as_ref().unwrap();
+        let grad_bias = bias.grad(&grads).unwrap();
+        let grad_bias_checkpoint = bias.grad(&grads_checkpoint).unwrap();
+        grad_bias
+            .into_data()
+            .assert_approx_eq(&grad_bias_checkpoint.into_data(), 4);
+    }
+
+    #[test]
+    fn test_checkpoint_stores_fewer_activations() {
+        let linear = LinearConfig::new(4, 4).init::<TestADBackend>();
+        let data = Tensor::<TestADBackend, 2>::random([3, 4], Distribution::Standard).into_data();
+        // Each tensor has its own graph, so the steps of both forward passes are counted apart.
+        let input = Tensor::<TestADBackend, 2>::from_data(data.clone()).require_grad();
+        let input_checkpoint = Tensor::<TestADBackend, 2>::from_data(data).require_grad();
+
+        let output = forward(&linear, input);
+        let output_checkpoint = checkpoint(&linear, input_checkpoint, forward);
+
+        let num_steps = output.into_primitive().graph.steps().len();
+        let num_steps_checkpoint = output_checkpoint.into_primitive().graph.steps().len();
+        // Only the input and the checkpoint itself are stored.
+        assert_eq!(num_steps_checkpoint, 2);
+        assert!(num_steps_checkpoint < num_steps);
+    }
+
+    #[test]
+    fn test_checkpoint_output_is_untracked_without_tracked_inputs() {
+        let linear = LinearConfig::new(4, 4).init::<TestADBackend>().no_grad();
+        let input = Tensor::<TestADBackend, 2>::random([3, 4], Distribution::Standard);
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let output = checkpoint(&linear, input, {
+            let calls = calls.clone();
+
+            move |linear: &Linear<TestADBackend>, x| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                forward(linear, x)
+            }
+        });
+
+        assert!(!output.into_primitive().is_tracked());
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    fn forward<B: Backend>(linear: &Linear<B>, x: Tensor<B, 2>) -> Tensor<B, 2> {
+        linear.forward(relu(linear.forward(x)))
+    }
+}
@@ -1,5 +1,7 @@
 mod base;
+mod checkpoint;
 mod param;
 
 pub use base::*;
+pub use checkpoint::*;
 pub use param::*;
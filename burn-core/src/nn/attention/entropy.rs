@@ -0,0 +1,59 @@
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Computes the entropy `-sum(p * log(p))` of each attention distribution over the keys,
+/// averaged over the queries of each head.
+///
+/// Fully peaked distributions have an entropy of zero, while uniform distributions reach the
+/// maximum of `log(seq_length_2)`, which makes it useful to detect attention collapse.
+///
+/// # Shapes
+///
+/// - weights: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+/// - output: `[batch_size, n_heads]`
+pub fn attention_entropy<B: Backend>(weights: Tensor<B, 4>) -> Tensor<B, 2> {
+    let [batch_size, n_heads, _, _] = weights.dims();
+
+    // Zero probabilities don't contribute to the entropy, even though `0 * log(0)` is NaN.
+    let zeros = weights.clone().equal_elem(0.0);
+    let entropy = weights
+        .clone()
+        .mul(weights.log())
+        .mask_fill(zeros, 0.0)
+        .sum_dim(3)
+        .neg();
+
+    entropy.mean_dim(2).reshape([batch_size, n_heads])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn attention_entropy_should_be_zero_for_peaked_distributions() {
+        let weights = Tensor::<TestBackend, 4>::from_floats([[
+            [[1.0, 0.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0]],
+            [[0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]],
+        ]]);
+
+        let entropy = attention_entropy(weights);
+
+        entropy
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0]]), 5);
+    }
+
+    #[test]
+    fn attention_entropy_should_be_log_seq_length_for_uniform_distributions() {
+        let weights = Tensor::<TestBackend, 4>::ones([2, 3, 5, 4]).div_scalar(4.0);
+
+        let entropy = attention_entropy(weights);
+
+        let expected = libm::logf(4.0);
+        entropy
+            .into_data()
+            .assert_approx_eq(&Data::from([[expected; 3]; 2]), 5);
+    }
+}
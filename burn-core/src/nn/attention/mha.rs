@@ -3,10 +3,11 @@ use crate as burn;
 use crate::nn::cache::TensorCache;
 use crate::{
     config::Config,
-    module::Module,
+    module::{Module, Param},
     nn,
-    tensor::{activation, backend::Backend, Bool, Tensor},
+    tensor::{activation, backend::Backend, Bool, Data, Int, Shape, Tensor},
 };
+use alloc::vec::Vec;
 use libm::sqrtf;
 
 /// Configuration to create a [Multi Head Attention](MultiHeadAttention) layer.
@@ -139,7 +140,10 @@ impl<B: Backend> MhaInput<B> {
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights [batch_size, seq_length_1, seq_length_2].
+    /// The attention weights of each head [batch_size, n_heads, seq_length_1, seq_length_2].
+    ///
+    /// The weights are the probabilities over the keys, i.e. they sum to one along the last
+    /// dimension, and are applied on the projected values of each head to produce the context.
     pub weights: Tensor<B, 4>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
@@ -154,8 +158,9 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - key: `[batch_size, seq_length_2, d_model]`
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
+    /// - weights: `[batch_size, n_heads, seq_length_1, seq_length_2]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let [batch_size, seq_length_1, _d_model] = input.query.dims();
 
         let query = self.attention_linear(input.query, &self.query);
         let key = self.attention_linear(input.key, &self.key);
@@ -165,14 +170,31 @@ impl<B: Backend> MultiHeadAttention<B> {
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
         let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
+        let context =
+            context
+                .swap_dims(1, 2)
+                .reshape([batch_size, seq_length_1, self.n_heads * self.d_k]);
         let context = self.output.forward(context);
 
         MhaOutput { weights, context }
     }
 
+    /// Applies the forward pass on the input tensors, returning the output along with the
+    /// attention weights of each head, which can be used to inspect what the module attends to.
+    ///
+    /// # Shapes
+    ///
+    /// - query: `[batch_size, seq_length_1, d_model]`
+    /// - key: `[batch_size, seq_length_2, d_model]`
+    /// - value: `[batch_size, seq_length_2, d_model]`
+    /// - output: `[batch_size, seq_length_1, d_model]`
+    /// - weights: `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    pub fn forward_with_attn(&self, input: MhaInput<B>) -> (Tensor<B, 3>, Tensor<B, 4>) {
+        let output = self.forward(input);
+
+        (output.context, output.weights)
+    }
+
     /// Applies the forward pass using a cache.
     ///
     /// # Shapes
@@ -181,8 +203,9 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - key: `[batch_size, seq_length_2, d_model]`
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
+    /// - weights: `[batch_size, n_heads, seq_length_1, seq_length_2]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
+        let [batch_size, seq_length_1, _d_model] = input.query.dims();
 
         let query = cache
             .query
@@ -198,15 +221,75 @@ impl<B: Backend> MultiHeadAttention<B> {
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
         let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
+        let context =
+            context
+                .swap_dims(1, 2)
+                .reshape([batch_size, seq_length_1, self.n_heads * self.d_k]);
 
         let context = cache.output.forward(context, |t| self.output.forward(t));
 
         MhaOutput { weights, context }
     }
 
+    /// Create a pruned copy of the module only keeping the given attention heads.
+    ///
+    /// The query, key and value projections only keep the output features of the selected heads,
+    /// while the output projection only keeps their input features. The pruned module produces
+    /// the same output as the original one where the other heads don't contribute.
+    ///
+    /// # Panics
+    ///
+    /// If no head is kept or if a head index is out of range.
+    pub fn prune_heads(&self, heads: &[usize]) -> Self {
+        assert!(
+            !heads.is_empty(),
+            "At least one attention head should be kept"
+        );
+
+        let features: Vec<i32> = heads
+            .iter()
+            .flat_map(|head| {
+                assert!(
+                    *head < self.n_heads,
+                    "Head index {head} is out of range for {} heads",
+                    self.n_heads
+                );
+                (head * self.d_k..(head + 1) * self.d_k).map(|index| index as i32)
+            })
+            .collect();
+        let num_features = features.len();
+        let indexes =
+            Tensor::<B, 1, Int>::from_ints(Data::new(features, Shape::new([num_features])))
+                .to_device(&self.query.weight.device());
+
+        let select_output = |linear: &nn::Linear<B>| nn::Linear {
+            weight: Param::from(linear.weight.val().index_select(1, indexes.clone())),
+            bias: linear
+                .bias
+                .as_ref()
+                .map(|bias| Param::from(bias.val().index_select(0, indexes.clone()))),
+        };
+
+        Self {
+            query: select_output(&self.query),
+            key: select_output(&self.key),
+            value: select_output(&self.value),
+            output: nn::Linear {
+                weight: Param::from(self.output.weight.val().index_select(0, indexes.clone())),
+                bias: self
+                    .output
+                    .bias
+                    .as_ref()
+                    .map(|bias| Param::from(bias.val())),
+            },
+            dropout: self.dropout.clone(),
+            activation: self.activation.clone(),
+            n_heads: heads.len(),
+            d_k: self.d_k,
+            min_float: self.min_float,
+        }
+    }
+
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
         let attn_scores = query
             .matmul(key.transpose())
@@ -359,6 +442,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_forward_with_attn_weights_should_produce_the_output() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 16, 4];
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+
+        let (output, weights) = mha.forward_with_attn(MhaInput::self_attn(tensor.clone()));
+
+        let weights_sum = Tensor::<TestBackend, 4>::ones([batch_size, n_heads, seq_length, 1]);
+        weights
+            .clone()
+            .sum_dim(3)
+            .into_data()
+            .assert_approx_eq(&weights_sum.into_data(), 3);
+
+        let value = mha.attention_linear(tensor, &mha.value);
+        let context = weights
+            .matmul(value)
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+        let context = mha.output.forward(context);
+
+        output.into_data().assert_approx_eq(&context.into_data(), 3);
+    }
+
+    #[test]
+    fn test_prune_heads_should_match_original_without_pruned_heads() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 16, 4];
+        let d_k = d_model / n_heads;
+        let heads = [1, 3];
+        let mut mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>();
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Standard,
+        );
+
+        let pruned = mha.prune_heads(&heads);
+
+        // Only run the kept heads in the original module by removing the contribution of the
+        // other heads to the output projection.
+        let mut weight = mha.output.weight.val();
+        for head in [0, 2] {
+            weight = weight.index_assign(
+                [head * d_k..(head + 1) * d_k, 0..d_model],
+                Tensor::zeros([d_k, d_model]),
+            );
+        }
+        mha.output.weight = Param::from(weight);
+
+        let output = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let output_pruned = pruned.forward(MhaInput::self_attn(tensor));
+
+        assert_eq!(
+            output_pruned.weights.shape(),
+            Shape::new([batch_size, heads.len(), seq_length, seq_length])
+        );
+        output
+            .context
+            .into_data()
+            .assert_approx_eq(&output_pruned.context.into_data(), 3);
+        output
+            .weights
+            .index_select(1, Tensor::from_ints([1, 3]))
+            .into_data()
+            .assert_approx_eq(&output_pruned.weights.into_data(), 3);
+    }
+
     #[test]
     fn test_self_attention_mask_pad() {
         let [batch_size, seq_length, d_model, n_heads, num_padded] = [3, 6, 32, 2, 2];
@@ -1,5 +1,7 @@
+mod entropy;
 mod mask;
 mod mha;
 
+pub use entropy::*;
 pub use mask::*;
 pub use mha::*;
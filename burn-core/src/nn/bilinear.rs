@@ -0,0 +1,192 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::{backend::Backend, Tensor};
+
+use libm::sqrt;
+
+use super::Initializer;
+
+/// Configuration to create a [Bilinear](Bilinear) layer.
+#[derive(Config, Debug)]
+pub struct BilinearConfig {
+    /// The size of the first input features.
+    pub d_input_1: usize,
+    /// The size of the second input features.
+    pub d_input_2: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the bilinear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Applies a bilinear transformation to the input tensors:
+///
+/// `O_k = I1 W_k I2^T + b_k`
+///
+/// # Params
+///
+/// - weight: Tensor of shape `[d_output, d_input_1, d_input_2]` initialized from a uniform
+///     distribution: `U(-k, k)`, where `k = sqrt(1 / d_input_1)`
+///
+/// - bias (optional): Vector of size `d_output` initialized from a uniform distribution:
+///     `U(-k, k)`, where `k = sqrt(1 / d_input_1)`
+#[derive(Module, Debug)]
+pub struct Bilinear<B: Backend> {
+    pub(crate) weight: Param<Tensor<B, 3>>,
+    pub(crate) bias: Option<Param<Tensor<B, 1>>>,
+}
+
+impl BilinearConfig {
+    /// Initialize a new [bilinear](Bilinear) module.
+    pub fn init<B: Backend>(&self) -> Bilinear<B> {
+        let k = sqrt(1.0 / self.d_input_1 as f64);
+
+        let initializer = if let Initializer::UniformDefault = self.initializer {
+            Initializer::Uniform(-k, k)
+        } else {
+            self.initializer.clone()
+        };
+
+        let weight = initializer.init([self.d_output, self.d_input_1, self.d_input_2]);
+
+        let bias = if self.bias {
+            Some(initializer.init([self.d_output]))
+        } else {
+            None
+        };
+
+        Bilinear {
+            weight: Param::from(weight),
+            bias: bias.map(Param::from),
+        }
+    }
+
+    /// Initialize a new [bilinear](Bilinear) module with a [record](BilinearRecord).
+    pub fn init_with<B: Backend>(&self, record: BilinearRecord<B>) -> Bilinear<B> {
+        Bilinear {
+            weight: record.weight,
+            bias: record.bias,
+        }
+    }
+}
+
+impl<B: Backend> Bilinear<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: `[..., any, d_input_1]`
+    /// - input_2: `[..., any, d_input_2]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(
+        &self,
+        input_1: Tensor<B, D>,
+        input_2: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let mut dims = input_1.dims();
+        let [d_output, d_input_1, d_input_2] = self.weight.dims();
+        let batch_size = dims[..D - 1].iter().product();
+
+        let input_1 = input_1.reshape([batch_size, d_input_1]);
+        let input_2 = input_2.reshape([batch_size, 1, d_input_2]);
+        let weight = self
+            .weight
+            .val()
+            .swap_dims(0, 1)
+            .reshape([d_input_1, d_output * d_input_2]);
+
+        let output = input_1
+            .matmul(weight)
+            .reshape([batch_size, d_output, d_input_2])
+            .mul(input_2)
+            .sum_dim(2)
+            .reshape([batch_size, d_output]);
+
+        let output = match &self.bias {
+            Some(bias) => output + bias.val().unsqueeze(),
+            None => output,
+        };
+
+        dims[D - 1] = d_output;
+        output.reshape(dims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec::Vec;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn initializer_default() {
+        TestBackend::seed(0);
+
+        let config = BilinearConfig::new(5, 4, 3);
+        let k = sqrt(1.0 / config.d_input_1 as f64) as f32;
+        let bilinear = config.init::<TestBackend>();
+
+        assert_eq!(config.initializer, Initializer::UniformDefault);
+        bilinear.weight.to_data().assert_within_range(-k..k);
+    }
+
+    #[test]
+    fn forward_should_match_manual_computation() {
+        TestBackend::seed(0);
+
+        let [batch_size, d_input_1, d_input_2, d_output] = [2, 3, 4, 2];
+        let bilinear = BilinearConfig::new(d_input_1, d_input_2, d_output).init::<TestBackend>();
+        let input_1 =
+            Tensor::<TestBackend, 2>::random([batch_size, d_input_1], Distribution::Standard);
+        let input_2 =
+            Tensor::<TestBackend, 2>::random([batch_size, d_input_2], Distribution::Standard);
+
+        let output = bilinear.forward(input_1.clone(), input_2.clone());
+
+        let bias = bilinear.bias.as_ref().unwrap().val();
+        let mut expected = Vec::new();
+        for b in 0..batch_size {
+            let a = input_1.clone().index([b..b + 1, 0..d_input_1]);
+            let c = input_2.clone().index([b..b + 1, 0..d_input_2]);
+            let outputs = (0..d_output)
+                .map(|k| {
+                    let weight = bilinear
+                        .weight
+                        .val()
+                        .index([k..k + 1, 0..d_input_1, 0..d_input_2])
+                        .reshape([d_input_1, d_input_2]);
+                    a.clone().matmul(weight).matmul(c.clone().transpose())
+                })
+                .collect();
+            expected.push(Tensor::cat(outputs, 1) + bias.clone().unsqueeze());
+        }
+        let expected = Tensor::cat(expected, 0);
+
+        assert_eq!(output.shape(), expected.shape());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn forward_without_bias() {
+        let bilinear = BilinearConfig::new(2, 2, 1)
+            .with_bias(false)
+            .with_initializer(Initializer::Ones)
+            .init::<TestBackend>();
+        let input_1 = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0]], [[0.0, 1.0]]]);
+        let input_2 = Tensor::<TestBackend, 3>::from_floats([[[3.0, 4.0]], [[5.0, 6.0]]]);
+
+        let output = bilinear.forward(input_1, input_2);
+
+        assert_eq!(output.into_data(), Data::from([[[21.0]], [[11.0]]]));
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Bool, Data, ElementConversion, Shape, Tensor};
+
+/// Collate samples into a single batched tensor along a new first dimension.
+///
+/// The samples can have a variable size along their first dimension, e.g. the sequence length,
+/// and are padded at the end with the given value up to the longest sample. All the other
+/// dimensions should be the same for every sample.
+///
+/// # Returns
+///
+/// A tuple containing the batched tensor and the size of each sample before padding.
+///
+/// # Shapes
+///
+/// - samples: `[length_i, ...]`
+/// - output: `[num_samples, max_length, ...]`
+///
+/// # Panics
+///
+/// If there is no sample, if `D2` isn't `D + 1` or if the other dimensions of the samples differ.
+pub fn collate<B: Backend, const D: usize, const D2: usize, E: ElementConversion + Copy>(
+    samples: Vec<Tensor<B, D>>,
+    pad_value: E,
+) -> (Tensor<B, D2>, Vec<usize>) {
+    assert!(
+        !samples.is_empty(),
+        "Can't collate an empty list of samples"
+    );
+
+    let lengths: Vec<usize> = samples.iter().map(|sample| sample.dims()[0]).collect();
+    let max_length = lengths.iter().copied().max().unwrap_or(0);
+    let (batch, _mask) = pad_to_length(samples, 0, max_length, pad_value);
+
+    (batch, lengths)
+}
+
+/// Pad every tensor along the given dimension up to a fixed length with the given value and
+/// stack them along a new first dimension.
+///
+/// # Returns
+///
+/// A tuple containing the batched tensor and the padding mask, which is `true` for the padded
+/// positions, as expected by the attention modules.
+///
+/// # Shapes
+///
+/// - tensors: `[..., length_i, ...]`
+/// - output: `[num_tensors, ..., length, ...]`
+/// - mask: `[num_tensors, length]`
+///
+/// # Panics
+///
+/// If there is no tensor, if `D2` isn't `D + 1`, if a tensor is longer than `length` along the
+/// dimension or if their other dimensions differ.
+pub fn pad_to_length<B: Backend, const D: usize, const D2: usize, E: ElementConversion + Copy>(
+    tensors: Vec<Tensor<B, D>>,
+    dim: usize,
+    length: usize,
+    pad_value: E,
+) -> (Tensor<B, D2>, Tensor<B, 2, Bool>) {
+    assert!(!tensors.is_empty(), "Can't pad an empty list of tensors");
+    assert_eq!(
+        D + 1,
+        D2,
+        "The batched rank should be the tensor rank plus one"
+    );
+
+    let device = tensors[0].device();
+    let num_tensors = tensors.len();
+    let mut mask = Vec::with_capacity(num_tensors * length);
+
+    let tensors = tensors
+        .into_iter()
+        .map(|tensor| {
+            let mut shape = tensor.shape();
+            let size = shape.dims[dim];
+            assert!(
+                size <= length,
+                "Can't pad a tensor of size {size} to the shorter length {length}"
+            );
+            mask.extend((0..length).map(|position| position >= size));
+
+            let tensor = if size < length {
+                shape.dims[dim] = length - size;
+                let padding = Tensor::zeros_device(shape, &tensor.device()).add_scalar(pad_value);
+
+                Tensor::cat(vec![tensor, padding], dim)
+            } else {
+                tensor
+            };
+
+            tensor.unsqueeze::<D2>()
+        })
+        .collect();
+
+    let mask =
+        Tensor::from_data_device(Data::new(mask, Shape::new([num_tensors, length])), &device);
+
+    (Tensor::cat(tensors, 0), mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn collate_should_pad_variable_length_sequences() {
+        let samples = vec![
+            Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0]),
+            Tensor::<TestBackend, 1>::from_floats([4.0]),
+            Tensor::<TestBackend, 1>::from_floats([5.0, 6.0]),
+        ];
+
+        let (batch, lengths) = collate::<TestBackend, 1, 2, _>(samples, -1.0);
+
+        assert_eq!(lengths, vec![3, 1, 2]);
+        batch.into_data().assert_approx_eq(
+            &Data::from([[1.0, 2.0, 3.0], [4.0, -1.0, -1.0], [5.0, 6.0, -1.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn collate_should_keep_feature_dimensions() {
+        let samples = vec![
+            Tensor::<TestBackend, 2>::ones([2, 4]),
+            Tensor::<TestBackend, 2>::ones([5, 4]),
+        ];
+
+        let (batch, lengths) = collate::<TestBackend, 2, 3, _>(samples, 0.0);
+
+        assert_eq!(lengths, vec![2, 5]);
+        assert_eq!(batch.dims(), [2, 5, 4]);
+        assert_eq!(batch.sum().into_scalar(), 28.0);
+    }
+
+    #[test]
+    fn pad_to_length_should_mask_padded_positions() {
+        let tensors = vec![
+            Tensor::<TestBackend, 1>::from_floats([1.0, 2.0]),
+            Tensor::<TestBackend, 1>::from_floats([3.0, 4.0, 5.0, 6.0]),
+            Tensor::<TestBackend, 1>::from_floats([7.0, 8.0, 9.0]),
+        ];
+
+        let (batch, mask) = pad_to_length::<TestBackend, 1, 2, _>(tensors, 0, 5, 0.0);
+
+        batch.into_data().assert_approx_eq(
+            &Data::from([
+                [1.0, 2.0, 0.0, 0.0, 0.0],
+                [3.0, 4.0, 5.0, 6.0, 0.0],
+                [7.0, 8.0, 9.0, 0.0, 0.0],
+            ]),
+            3,
+        );
+        assert_eq!(
+            mask.into_data(),
+            Data::from([
+                [false, false, true, true, true],
+                [false, false, false, false, true],
+                [false, false, false, true, true],
+            ])
+        );
+    }
+
+    #[test]
+    fn pad_to_length_should_pad_along_the_given_dimension() {
+        let tensors = vec![
+            Tensor::<TestBackend, 2>::ones([3, 2]),
+            Tensor::<TestBackend, 2>::ones([3, 4]),
+        ];
+
+        let (batch, mask) = pad_to_length::<TestBackend, 2, 3, _>(tensors, 1, 4, -1.0);
+
+        assert_eq!(batch.dims(), [2, 3, 4]);
+        // The 6 ones of the first tensor cancel out with its 6 padded values.
+        assert_eq!(batch.sum().into_scalar(), 12.0);
+        assert_eq!(mask.dims(), [2, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pad_to_length_should_panic_when_a_tensor_is_too_long() {
+        let tensors = vec![Tensor::<TestBackend, 1>::ones([6])];
+
+        pad_to_length::<TestBackend, 1, 2, _>(tensors, 0, 5, 0.0);
+    }
+}
@@ -55,15 +55,15 @@ pub enum Conv2dPaddingConfig {
 ///
 /// # Params
 ///
-/// - weight: Tensor of shape [channels_out, channels_in, kernel_size_1, kernel_size_2] initialized from a uniform
-///     distribution `U(-k, k)` where `k = sqrt(1 / channels_in * kernel_size_1 * kernel_size_2)`
+/// - weight: Tensor of shape [channels_out, channels_in / groups, kernel_size_1, kernel_size_2] initialized from a uniform
+///     distribution `U(-k, k)` where `k = sqrt(groups / channels_in * kernel_size_1 * kernel_size_2)`
 ///
 /// - bias:   Tensor of shape [channels_out], initialized from a uniform distribution `U(-k, k)`
-///     where `k = sqrt(1 / channels_in * kernel_size_1 * kernel_size_2)`
+///     where `k = sqrt(groups / channels_in * kernel_size_1 * kernel_size_2)`
 #[derive(Module, Debug)]
 pub struct Conv2d<B: Backend> {
-    weight: Param<Tensor<B, 4>>,
-    bias: Option<Param<Tensor<B, 1>>>,
+    pub(crate) weight: Param<Tensor<B, 4>>,
+    pub(crate) bias: Option<Param<Tensor<B, 1>>>,
     stride: [usize; 2],
     kernel_size: [usize; 2],
     dilation: [usize; 2],
@@ -74,7 +74,8 @@ pub struct Conv2d<B: Backend> {
 impl Conv2dConfig {
     /// Initialize a new [conv2d](Conv2d) module.
     pub fn init<B: Backend>(&self) -> Conv2d<B> {
-        let k = (self.channels[0] * self.kernel_size[0] * self.kernel_size[1]) as f64;
+        let channels_in_per_group = self.channels[0] / self.groups;
+        let k = (channels_in_per_group * self.kernel_size[0] * self.kernel_size[1]) as f64;
         let k = sqrt(1.0 / k);
 
         let initializer = if let Initializer::UniformDefault = self.initializer {
@@ -85,7 +86,7 @@ impl Conv2dConfig {
 
         let weight = initializer.init([
             self.channels[1],
-            self.channels[0],
+            channels_in_per_group,
             self.kernel_size[0],
             self.kernel_size[1],
         ]);
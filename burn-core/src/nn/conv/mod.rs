@@ -1,5 +1,7 @@
 mod conv1d;
 mod conv2d;
+mod separable_conv2d;
 
 pub use conv1d::*;
 pub use conv2d::*;
+pub use separable_conv2d::*;
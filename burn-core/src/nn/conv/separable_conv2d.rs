@@ -0,0 +1,166 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::nn::conv::{Conv2d, Conv2dConfig, Conv2dPaddingConfig};
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [2D separable convolution](SeparableConv2d) layer.
+#[derive(Config, Debug)]
+pub struct SeparableConv2dConfig {
+    /// The number of channels.
+    pub channels: [usize; 2],
+    /// The size of the kernel of the depthwise convolution.
+    pub kernel_size: [usize; 2],
+    /// The stride of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements of the depthwise convolution.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// The padding configuration of the depthwise convolution.
+    #[config(default = "Conv2dPaddingConfig::Valid")]
+    pub padding: Conv2dPaddingConfig,
+    /// If bias should be added to the output of both convolutions.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Applies a 2D depthwise separable convolution over input tensors.
+///
+/// A depthwise convolution, where each input channel is convolved with its own kernel
+/// (`groups == channels_in`), is followed by a pointwise convolution with a kernel of size 1x1
+/// mixing the channels.
+///
+/// # Params
+///
+/// - depthwise: [Conv2d](Conv2d) layer with `channels_in` input and output channels.
+/// - pointwise: [Conv2d](Conv2d) layer with `channels_in` input channels and `channels_out`
+///     output channels.
+#[derive(Module, Debug)]
+pub struct SeparableConv2d<B: Backend> {
+    depthwise: Conv2d<B>,
+    pointwise: Conv2d<B>,
+}
+
+impl SeparableConv2dConfig {
+    /// Initialize a new [separable conv2d](SeparableConv2d) module.
+    pub fn init<B: Backend>(&self) -> SeparableConv2d<B> {
+        SeparableConv2d {
+            depthwise: self.depthwise_config().init(),
+            pointwise: self.pointwise_config().init(),
+        }
+    }
+
+    /// Initialize a new [separable conv2d](SeparableConv2d) module with a
+    /// [record](SeparableConv2dRecord).
+    pub fn init_with<B: Backend>(&self, record: SeparableConv2dRecord<B>) -> SeparableConv2d<B> {
+        SeparableConv2d {
+            depthwise: self.depthwise_config().init_with(record.depthwise),
+            pointwise: self.pointwise_config().init_with(record.pointwise),
+        }
+    }
+
+    fn depthwise_config(&self) -> Conv2dConfig {
+        let [channels_in, _] = self.channels;
+
+        Conv2dConfig::new([channels_in, channels_in], self.kernel_size)
+            .with_stride(self.stride)
+            .with_dilation(self.dilation)
+            .with_groups(channels_in)
+            .with_padding(self.padding.clone())
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+
+    fn pointwise_config(&self) -> Conv2dConfig {
+        Conv2dConfig::new(self.channels, [1, 1])
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> SeparableConv2d<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.depthwise.forward(input);
+        self.pointwise.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec::Vec;
+    use burn_tensor::module::conv2d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Distribution, Shape};
+
+    #[test]
+    fn forward_should_have_output_channels() {
+        let conv = SeparableConv2dConfig::new([3, 8], [3, 3]).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::random([2, 3, 6, 6], Distribution::Standard);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.shape(), Shape::new([2, 8, 4, 4]));
+    }
+
+    #[test]
+    fn forward_should_match_two_stages_composition() {
+        TestBackend::seed(0);
+
+        let [batch_size, channels_in, channels_out] = [2, 3, 4];
+        let conv = SeparableConv2dConfig::new([channels_in, channels_out], [3, 3])
+            .with_padding(Conv2dPaddingConfig::Explicit(1, 1))
+            .init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::random(
+            [batch_size, channels_in, 5, 5],
+            Distribution::Standard,
+        );
+
+        let output = conv.forward(input.clone());
+
+        // Depthwise stage: each channel is convolved independently with its own kernel.
+        let weight = conv.depthwise.weight.val();
+        let bias = conv.depthwise.bias.as_ref().unwrap().val();
+        let channels = (0..channels_in)
+            .map(|c| {
+                conv2d(
+                    input.clone().index([0..batch_size, c..c + 1, 0..5, 0..5]),
+                    weight.clone().index([c..c + 1, 0..1, 0..3, 0..3]),
+                    Some(bias.clone().index([c..c + 1])),
+                    ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+                )
+            })
+            .collect::<Vec<_>>();
+        let depthwise = Tensor::cat(channels, 1);
+
+        // Pointwise stage: 1x1 convolution mixing the channels.
+        let expected = conv2d(
+            depthwise,
+            conv.pointwise.weight.val(),
+            conv.pointwise.bias.as_ref().map(|bias| bias.val()),
+            ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+        );
+
+        assert_eq!(
+            conv.depthwise.weight.shape(),
+            Shape::new([channels_in, 1, 3, 3])
+        );
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
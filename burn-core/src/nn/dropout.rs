@@ -50,6 +50,44 @@ impl Dropout {
     }
 }
 
+/// Monte Carlo dropout: run the model `num_samples` times with dropout active and return the
+/// unbiased variance and the mean of the outputs, in this order like
+/// [var_mean](Tensor::var_mean).
+///
+/// The variance estimates the uncertainty of the model. Since [dropout](Dropout) is only active
+/// on autodiff backends, the model should run on one; the outputs are detached from the graph.
+///
+/// # Panics
+///
+/// If fewer than two samples are requested.
+pub fn mc_dropout_predict<B, F, const D_IN: usize, const D_OUT: usize>(
+    mut model: F,
+    input: Tensor<B, D_IN>,
+    num_samples: usize,
+) -> (Tensor<B, D_OUT>, Tensor<B, D_OUT>)
+where
+    B: Backend,
+    F: FnMut(Tensor<B, D_IN>) -> Tensor<B, D_OUT>,
+{
+    assert!(
+        num_samples > 1,
+        "At least two samples are required to estimate the variance, got {num_samples}"
+    );
+
+    let mut mean = model(input.clone()).detach();
+    let mut m2 = mean.zeros_like();
+
+    // Welford's online algorithm, so that the samples don't need to be kept.
+    for count in 2..=num_samples {
+        let output = model(input.clone()).detach();
+        let delta = output.clone() - mean.clone();
+        mean = mean + delta.clone().div_scalar(count as f32);
+        m2 = m2 + delta * (output - mean.clone());
+    }
+
+    (m2.div_scalar((num_samples - 1) as f32), mean)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,6 +110,42 @@ mod tests {
         assert_ne!(tensor.to_data(), output.to_data());
     }
 
+    #[test]
+    fn mc_dropout_predict_should_average_samples() {
+        let input = Tensor::<TestBackend, 2>::zeros([2, 3]);
+        let mut calls = 0;
+
+        let (var, mean) = mc_dropout_predict(
+            |input: Tensor<TestBackend, 2>| {
+                calls += 1;
+                input.add_scalar(calls as f32)
+            },
+            input,
+            4,
+        );
+
+        // The samples are 1, 2, 3 and 4.
+        assert_eq!(calls, 4);
+        let mean_expected = Tensor::<TestBackend, 2>::ones([2, 3]).mul_scalar(2.5);
+        let var_expected = Tensor::<TestBackend, 2>::ones([2, 3]).mul_scalar(5.0 / 3.0);
+        mean.into_data()
+            .assert_approx_eq(&mean_expected.into_data(), 5);
+        var.into_data()
+            .assert_approx_eq(&var_expected.into_data(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mc_dropout_predict_should_have_variance_with_dropout() {
+        let input = Tensor::<TestADBackend, 2>::ones(Shape::new([10, 10]));
+        let dropout = DropoutConfig::new(0.5).init();
+
+        let (var, mean) = mc_dropout_predict(|input| dropout.forward(input), input, 8);
+
+        assert!(var.sum().into_scalar() > 0.0);
+        assert!(mean.min().into_scalar() >= 0.0);
+    }
+
     #[test]
     fn without_ad_backend_should_not_change_input() {
         let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]));
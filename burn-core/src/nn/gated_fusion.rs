@@ -0,0 +1,138 @@
+use crate as burn;
+
+use alloc::vec;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::{activation, backend::Backend, Tensor};
+
+use super::{Initializer, Linear, LinearConfig};
+
+/// Configuration to create a [GatedFusion](GatedFusion) layer.
+#[derive(Config, Debug)]
+pub struct GatedFusionConfig {
+    /// The size of the first input features.
+    pub d_input_1: usize,
+    /// The size of the second input features.
+    pub d_input_2: usize,
+    /// The size of the common projection and output features.
+    pub d_output: usize,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Fuses two feature vectors, e.g. from different modalities, by projecting them to a common
+/// dimension and taking their gated element-wise (Hadamard) product:
+///
+/// `P1 = I1 W1 + b1`, `P2 = I2 W2 + b2`
+///
+/// `G = sigmoid([P1, P2] Wg + bg)`
+///
+/// `O = G * P1 * P2`
+///
+/// # Params
+///
+/// - projection_1: [Linear](Linear) layer of shape `[d_input_1, d_output]`.
+/// - projection_2: [Linear](Linear) layer of shape `[d_input_2, d_output]`.
+/// - gate: [Linear](Linear) layer of shape `[2 * d_output, d_output]` computing the gate from
+///     the concatenated projections.
+#[derive(Module, Debug)]
+pub struct GatedFusion<B: Backend> {
+    pub(crate) projection_1: Linear<B>,
+    pub(crate) projection_2: Linear<B>,
+    pub(crate) gate: Linear<B>,
+}
+
+impl GatedFusionConfig {
+    /// Initialize a new [gated fusion](GatedFusion) module.
+    pub fn init<B: Backend>(&self) -> GatedFusion<B> {
+        let linear = |d_input| {
+            LinearConfig::new(d_input, self.d_output)
+                .with_initializer(self.initializer.clone())
+                .init()
+        };
+
+        GatedFusion {
+            projection_1: linear(self.d_input_1),
+            projection_2: linear(self.d_input_2),
+            gate: linear(2 * self.d_output),
+        }
+    }
+
+    /// Initialize a new [gated fusion](GatedFusion) module with a [record](GatedFusionRecord).
+    pub fn init_with<B: Backend>(&self, record: GatedFusionRecord<B>) -> GatedFusion<B> {
+        let linear = |d_input| LinearConfig::new(d_input, self.d_output);
+
+        GatedFusion {
+            projection_1: linear(self.d_input_1).init_with(record.projection_1),
+            projection_2: linear(self.d_input_2).init_with(record.projection_2),
+            gate: linear(2 * self.d_output).init_with(record.gate),
+        }
+    }
+}
+
+impl<B: Backend> GatedFusion<B> {
+    /// Applies the forward pass on the input tensors.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: `[..., any, d_input_1]`
+    /// - input_2: `[..., any, d_input_2]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(
+        &self,
+        input_1: Tensor<B, D>,
+        input_2: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let projection_1 = self.projection_1.forward(input_1);
+        let projection_2 = self.projection_2.forward(input_2);
+
+        let gate = self.gate.forward(Tensor::cat(
+            vec![projection_1.clone(), projection_2.clone()],
+            D - 1,
+        ));
+
+        activation::sigmoid(gate) * projection_1 * projection_2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::Param;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn output_should_have_the_common_dimension() {
+        TestBackend::seed(0);
+
+        let fusion = GatedFusionConfig::new(4, 6, 3).init::<TestBackend>();
+        let input_1 = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Standard);
+        let input_2 = Tensor::<TestBackend, 3>::random([2, 5, 6], Distribution::Standard);
+
+        let output = fusion.forward(input_1, input_2);
+
+        assert_eq!(output.dims(), [2, 5, 3]);
+    }
+
+    #[test]
+    fn open_gate_should_give_the_hadamard_product_of_the_projections() {
+        TestBackend::seed(0);
+
+        let mut fusion = GatedFusionConfig::new(4, 6, 3).init::<TestBackend>();
+        // A saturated sigmoid makes the gate the identity, whatever the projections.
+        fusion.gate.weight = Param::from(Tensor::zeros([6, 3]));
+        fusion.gate.bias = Some(Param::from(Tensor::ones([3]).mul_scalar(100.0)));
+        let input_1 = Tensor::<TestBackend, 2>::random([5, 4], Distribution::Standard);
+        let input_2 = Tensor::<TestBackend, 2>::random([5, 6], Distribution::Standard);
+
+        let output = fusion.forward(input_1.clone(), input_2.clone());
+
+        let expected = fusion.projection_1.forward(input_1) * fusion.projection_2.forward(input_2);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -26,6 +26,10 @@ pub enum Initializer {
     XavierUniform(f64),
     /// Fills tensor with values according to the normal version of Xavier Glorot initialization described in [Understanding the difficulty of training deep feedforward neural networks](https://proceedings.mlr.press/v9/glorot10a/glorot10a.pdf)
     XavierNormal(f64),
+    /// Fills tensor with values according to the uniform version of Kaiming He initialization described in [Delving Deep into Rectifiers: Surpassing Human-Level Performance on ImageNet Classification](https://arxiv.org/abs/1502.01852), using the fan in
+    KaimingUniform(f64),
+    /// Fills tensor with values according to the normal version of Kaiming He initialization described in [Delving Deep into Rectifiers: Surpassing Human-Level Performance on ImageNet Classification](https://arxiv.org/abs/1502.01852), using the fan in
+    KaimingNormal(f64),
 }
 
 impl Initializer {
@@ -44,20 +48,90 @@ impl Initializer {
             Self::Zeros => Tensor::<B, D>::zeros(shape),
             Self::XavierUniform(gain) => xavier_uniform(gain, shape),
             Self::XavierNormal(gain) => xavier_normal(gain, shape),
+            Self::KaimingUniform(gain) => {
+                let shape = shape.into();
+                let std = kaiming_std(gain, fan_in(&shape));
+                uniform_bound(sqrt(3.0) * std, shape, &B::Device::default())
+            }
+            Self::KaimingNormal(gain) => {
+                let shape = shape.into();
+                let std = kaiming_std(gain, fan_in(&shape));
+                Tensor::<B, D>::random(shape, Distribution::Normal(0.0, std))
+            }
         }
     }
 }
 
+/// Create a tensor on the given device filled according to the given initializer, using the
+/// provided fan in and fan out instead of computing them from the shape.
+///
+/// This allows to create standalone weight tensors with the same initialization as the ones
+/// of modules, where [UniformDefault](Initializer::UniformDefault) draws values uniformly
+/// between -sqrt(1/fan_in) and sqrt(1/fan_in).
+pub fn random_init<B: Backend, const D: usize, S: Into<Shape<D>>>(
+    shape: S,
+    initializer: &Initializer,
+    fan_in: usize,
+    fan_out: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let shape = shape.into();
+    let std_xavier = |gain: &f64| gain * sqrt(2.0 / (fan_in + fan_out) as f64);
+
+    match initializer {
+        Initializer::Uniform(a, b) => Tensor::<B, D>::random_device(
+            shape,
+            Distribution::Uniform((*a).elem::<B::FloatElem>(), (*b).elem::<B::FloatElem>()),
+            device,
+        ),
+        Initializer::UniformDefault => uniform_bound(sqrt(1.0 / fan_in as f64), shape, device),
+        Initializer::Normal(mean, std) => {
+            Tensor::<B, D>::random_device(shape, Distribution::Normal(*mean, *std), device)
+        }
+        Initializer::Constant(value) => Tensor::<B, D>::zeros_device(shape, device) + *value,
+        Initializer::Ones => Tensor::<B, D>::ones_device(shape, device),
+        Initializer::Zeros => Tensor::<B, D>::zeros_device(shape, device),
+        Initializer::XavierUniform(gain) => {
+            uniform_bound(sqrt(3.0) * std_xavier(gain), shape, device)
+        }
+        Initializer::XavierNormal(gain) => Tensor::<B, D>::random_device(
+            shape,
+            Distribution::Normal(0.0, std_xavier(gain)),
+            device,
+        ),
+        Initializer::KaimingUniform(gain) => {
+            uniform_bound(sqrt(3.0) * kaiming_std(gain, fan_in), shape, device)
+        }
+        Initializer::KaimingNormal(gain) => Tensor::<B, D>::random_device(
+            shape,
+            Distribution::Normal(0.0, kaiming_std(gain, fan_in)),
+            device,
+        ),
+    }
+}
+
+fn uniform_bound<B: Backend, const D: usize>(
+    bound: f64,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    Tensor::<B, D>::random_device(
+        shape,
+        Distribution::Uniform(
+            (-bound).elem::<B::FloatElem>(),
+            bound.elem::<B::FloatElem>(),
+        ),
+        device,
+    )
+}
+
 fn xavier_uniform<B: Backend, const D: usize, S: Into<Shape<D>>>(
     gain: &f64,
     shape: S,
 ) -> Tensor<B, D> {
     let shape = shape.into();
     let a = sqrt(3.0) * xavier_std(gain, &shape);
-    Tensor::<B, D>::random(
-        shape,
-        Distribution::Uniform((-a).elem::<B::FloatElem>(), a.elem::<B::FloatElem>()),
-    )
+    uniform_bound(a, shape, &B::Device::default())
 }
 
 fn xavier_normal<B: Backend, const D: usize, S: Into<Shape<D>>>(
@@ -80,6 +154,26 @@ fn xavier_std<const D: usize>(gain: &f64, shape: &Shape<D>) -> f64 {
     gain * sqrt(2.0 / (fan_sum * receptive_field_size) as f64)
 }
 
+/// Computes the fan in of a weight, either using the linear layout `[d_input, d_output]` or the
+/// convolution layout `[channels_out, channels_in, kernel_size...]`.
+fn fan_in<const D: usize>(shape: &Shape<D>) -> usize {
+    assert!(
+        D >= 2,
+        "Can't compute Kaiming standard deviation on shapes smaller than 2"
+    );
+
+    if D == 2 {
+        return shape.dims[0];
+    }
+
+    let receptive_field_size: usize = shape.dims.iter().skip(2).product();
+    shape.dims[1] * receptive_field_size
+}
+
+fn kaiming_std(gain: &f64, fan_in: usize) -> f64 {
+    gain / sqrt(fan_in as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,4 +304,75 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn initializer_kaiming_normal_init() {
+        TB::seed(0);
+
+        let gain = sqrt(2.0);
+        let (fan_in, fan_out) = (1000, 10);
+        let expected_var = 2.0 / fan_in as f64;
+        let kaiming_normal: Tensor<TB, 2> =
+            Initializer::KaimingNormal(gain).init([fan_in, fan_out]);
+        let actual_var = kaiming_normal
+            .reshape([fan_in * fan_out])
+            .var(0)
+            .into_scalar() as f64;
+
+        assert!(
+            (expected_var - actual_var).abs() <= 0.1 * expected_var,
+            "Expected variance to be {expected_var} += 10%, but got {actual_var}"
+        );
+    }
+
+    #[test]
+    fn initializer_kaiming_fan_in_should_support_conv_layout() {
+        assert_eq!(fan_in(&Shape::new([1000, 10])), 1000);
+        assert_eq!(fan_in(&Shape::new([8, 4, 3, 3])), 36);
+    }
+
+    #[test]
+    fn random_init_kaiming_normal_should_have_expected_variance() {
+        TB::seed(0);
+
+        let (fan_in, fan_out) = (256, 128);
+        let expected_var = 2.0 / fan_in as f64;
+        let tensor: Tensor<TB, 2> = random_init(
+            [fan_in, fan_out],
+            &Initializer::KaimingNormal(sqrt(2.0)),
+            fan_in,
+            fan_out,
+            &Default::default(),
+        );
+
+        let (var, mean) = tensor.reshape([fan_in * fan_out]).var_mean(0);
+        let var: f64 = var.into_scalar().elem();
+        let mean: f64 = mean.into_scalar().elem();
+
+        assert!(
+            (expected_var - var).abs() <= 0.05 * expected_var,
+            "Expected variance to be {expected_var} += 5%, but got {var}"
+        );
+        assert!(
+            mean.abs() <= 0.01,
+            "Expected mean to be 0.0, but got {mean}"
+        );
+    }
+
+    #[test]
+    fn random_init_uniform_default_should_use_fan_in() {
+        TB::seed(0);
+
+        let fan_in = 16;
+        let bound = sqrt(1.0 / fan_in as f64);
+        let tensor: Tensor<TB, 2> = random_init(
+            [4, 8],
+            &Initializer::UniformDefault,
+            fan_in,
+            8,
+            &Default::default(),
+        );
+
+        tensor.into_data().assert_within_range(-bound..bound);
+    }
 }
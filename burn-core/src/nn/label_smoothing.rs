@@ -0,0 +1,62 @@
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Smooth one-hot targets by moving `epsilon` of the probability mass uniformly over the
+/// classes:
+///
+/// `y = (1 - epsilon) * one_hot + epsilon / num_classes`
+///
+/// The classes are along the last dimension.
+///
+/// # Panics
+///
+/// If `epsilon` isn't in the range `[0, 1]` or if `num_classes` is zero.
+pub fn smooth_labels<B: Backend, const D: usize>(
+    one_hot: Tensor<B, D>,
+    epsilon: f64,
+    num_classes: usize,
+) -> Tensor<B, D> {
+    assert!(
+        (0.0..=1.0).contains(&epsilon),
+        "The label smoothing epsilon should be in the range [0, 1], got {epsilon}"
+    );
+    assert!(
+        num_classes > 0,
+        "The number of classes should be greater than zero"
+    );
+
+    one_hot
+        .mul_scalar(1.0 - epsilon)
+        .add_scalar(epsilon / num_classes as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn smooth_labels_should_match_formula() {
+        let one_hot =
+            Tensor::<TestBackend, 2>::from_floats([[0.0, 1.0, 0.0, 0.0], [1.0, 0.0, 0.0, 0.0]]);
+
+        let output = smooth_labels(one_hot, 0.2, 4);
+
+        let expected = Data::from([[0.05, 0.85, 0.05, 0.05], [0.85, 0.05, 0.05, 0.05]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn smooth_labels_should_keep_rows_normalized() {
+        let one_hot = Tensor::<TestBackend, 2>::from_floats([
+            [0.0, 0.0, 1.0],
+            [0.0, 1.0, 0.0],
+            [1.0, 0.0, 0.0],
+        ]);
+
+        let output = smooth_labels(one_hot, 0.1, 3);
+
+        let expected = Data::from([[1.0], [1.0], [1.0]]);
+        output.sum_dim(1).into_data().assert_approx_eq(&expected, 5);
+    }
+}
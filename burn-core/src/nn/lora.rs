@@ -0,0 +1,151 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::{backend::Backend, Tensor};
+
+use libm::sqrt;
+
+use super::{Initializer, Linear, LinearConfig};
+
+/// Configuration to create a [LoraLinear](LoraLinear) layer.
+#[derive(Config, Debug)]
+pub struct LoraLinearConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the output features.
+    pub d_output: usize,
+    /// The rank of the low-rank adapter.
+    pub rank: usize,
+    /// The scaling numerator of the adapter, the low-rank term being scaled by `alpha / rank`.
+    #[config(default = 1.0)]
+    pub alpha: f64,
+    /// If a bias should be applied by the base linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize the base linear parameters
+    #[config(default = "Initializer::UniformDefault")]
+    pub initializer: Initializer,
+}
+
+/// Applies a linear transformation with a frozen base weight and a trainable low-rank adapter,
+/// as described in [LoRA: Low-Rank Adaptation of Large Language Models](https://arxiv.org/abs/2106.09685):
+///
+/// `O = IW + b + (alpha / rank) * IAB`
+///
+/// # Params
+///
+/// - base: The frozen [linear](Linear) layer, which doesn't require gradients.
+///
+/// - lora_a: Matrix of shape `[d_input, rank]` initialized from a uniform distribution:
+///     `U(-k, k)`, where `k = sqrt(1 / d_input)`
+///
+/// - lora_b: Matrix of shape `[rank, d_output]` initialized with zeros, so the adapter initially
+///     doesn't change the output of the base layer.
+#[derive(Module, Debug)]
+pub struct LoraLinear<B: Backend> {
+    pub(crate) base: Linear<B>,
+    pub(crate) lora_a: Param<Tensor<B, 2>>,
+    pub(crate) lora_b: Param<Tensor<B, 2>>,
+    scaling: f64,
+}
+
+impl LoraLinearConfig {
+    /// Initialize a new [lora linear](LoraLinear) module with a new base layer.
+    pub fn init<B: Backend>(&self) -> LoraLinear<B> {
+        let base = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+            .init();
+
+        self.init_with_base(base)
+    }
+
+    /// Initialize a new [lora linear](LoraLinear) module adapting the given base layer, which is
+    /// frozen.
+    pub fn init_with_base<B: Backend>(&self, base: Linear<B>) -> LoraLinear<B> {
+        let k = sqrt(1.0 / self.d_input as f64);
+        let lora_a = Initializer::Uniform(-k, k).init([self.d_input, self.rank]);
+        let lora_b = Initializer::Zeros.init([self.rank, self.d_output]);
+
+        LoraLinear {
+            base: base.no_grad(),
+            lora_a: Param::from(lora_a),
+            lora_b: Param::from(lora_b),
+            scaling: self.alpha / self.rank as f64,
+        }
+    }
+
+    /// Initialize a new [lora linear](LoraLinear) module with a [record](LoraLinearRecord).
+    pub fn init_with<B: Backend>(&self, record: LoraLinearRecord<B>) -> LoraLinear<B> {
+        let base = LinearConfig::new(self.d_input, self.d_output)
+            .with_bias(self.bias)
+            .init_with(record.base);
+
+        LoraLinear {
+            base: base.no_grad(),
+            lora_a: record.lora_a,
+            lora_b: record.lora_b,
+            scaling: self.alpha / self.rank as f64,
+        }
+    }
+}
+
+impl<B: Backend> LoraLinear<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let adapter = input
+            .clone()
+            .matmul(self.lora_a.val().unsqueeze())
+            .matmul(self.lora_b.val().unsqueeze());
+
+        self.base.forward(input) + adapter * self.scaling
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    use crate::TestADBackend;
+
+    #[test]
+    fn zero_initialized_adapter_should_match_base_linear() {
+        TestBackend::seed(0);
+
+        let config = LoraLinearConfig::new(4, 3, 2).with_alpha(8.0);
+        let lora = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::random([5, 4], burn_tensor::Distribution::Standard);
+
+        let output = lora.forward(input.clone());
+        let expected = lora.base.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn only_adapter_parameters_should_be_trainable() {
+        TestADBackend::seed(0);
+
+        let lora = LoraLinearConfig::new(4, 3, 2).init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 2>::ones([5, 4]);
+
+        let grads = lora.forward(input).sum().backward();
+
+        assert!(lora.base.weight.grad(&grads).is_none());
+        assert!(lora.base.bias.as_ref().unwrap().grad(&grads).is_none());
+        assert!(lora.lora_a.grad(&grads).is_some());
+        assert!(lora.lora_b.grad(&grads).is_some());
+    }
+}
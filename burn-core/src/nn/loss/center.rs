@@ -0,0 +1,186 @@
+use crate as burn;
+
+use crate::module::{Module, RunningState};
+use crate::nn::loss::reduction::Reduction;
+
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Calculate the center loss, as described in
+/// [A Discriminative Feature Learning Approach for Deep Face Recognition](https://ydwen.github.io/papers/WenECCV16.pdf).
+///
+/// The loss of each sample is `0.5 * ||embedding - center[target]||^2`, pulling the embeddings
+/// toward the center of their class. The centers aren't learned by the optimizer, but moved
+/// toward the embeddings of their class with [update](CenterLoss::update) after each step. They
+/// are the state of the module, so they are saved and loaded with its record.
+#[derive(Module, Debug)]
+pub struct CenterLoss<B: Backend> {
+    centers: RunningState<Tensor<B, 2>>,
+    rate: f64,
+}
+
+impl<B: Backend> CenterLoss<B> {
+    /// Create the criterion with the centers of every class initialized at the origin and an
+    /// update rate of `0.5`.
+    pub fn new(num_classes: usize, d_model: usize) -> Self {
+        Self {
+            centers: RunningState::new(Tensor::zeros([num_classes, d_model])),
+            rate: 0.5,
+        }
+    }
+
+    /// Set the rate at which the centers move toward the embeddings of their class.
+    ///
+    /// # Panics
+    ///
+    /// If the rate isn't between 0 and 1.
+    pub fn with_rate(mut self, rate: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "The update rate of the centers should be between 0 and 1, got {rate}"
+        );
+        self.rate = rate;
+        self
+    }
+
+    /// The center of every class, with the shape [num_classes, d_model].
+    pub fn centers(&self) -> Tensor<B, 2> {
+        self.centers.value_sync()
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - embeddings: [batch_size, d_model]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        embeddings: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(embeddings, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    ///
+    /// # Shapes
+    ///
+    /// - embeddings: [batch_size, d_model]
+    /// - targets: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        embeddings: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = embeddings.dims();
+        let centers = self.centers().index_select(0, targets);
+
+        embeddings
+            .sub(centers)
+            .powf(2.0)
+            .sum_dim(1)
+            .reshape([batch_size])
+            .mul_scalar(0.5)
+    }
+
+    /// Move the center of every class toward the embeddings of that class.
+    ///
+    /// `center[j] -= rate * sum(center[j] - embedding[i]) / (1 + n_j)`, where the sum is over the
+    /// `n_j` samples of class `j` in the batch, so the centers of absent classes don't move.
+    ///
+    /// # Shapes
+    ///
+    /// - embeddings: [batch_size, d_model]
+    /// - targets: [batch_size]
+    pub fn update(&self, embeddings: Tensor<B, 2>, targets: Tensor<B, 1, Int>) {
+        let centers = self.centers();
+        let [num_classes, _] = centers.dims();
+        let [batch_size, _] = embeddings.dims();
+
+        let differences = centers
+            .clone()
+            .index_select(0, targets.clone())
+            .sub(embeddings.detach());
+        let counts = Tensor::<B, 2>::ones_device([batch_size, 1], &differences.device())
+            .segment_sum(targets.clone(), num_classes);
+        let deltas = differences
+            .segment_sum(targets, num_classes)
+            .div(counts.add_scalar(1.0));
+
+        self.centers
+            .update(centers.sub(deltas.mul_scalar(self.rate)).detach());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    fn embeddings() -> Tensor<TestBackend, 2> {
+        Tensor::from_data(Data::from([[2.0, 0.0], [0.0, 4.0], [4.0, 2.0]]))
+    }
+
+    fn targets() -> Tensor<TestBackend, 1, Int> {
+        Tensor::from_data(Data::from([0, 1, 0]))
+    }
+
+    #[test]
+    fn test_center_loss() {
+        let loss = CenterLoss::<TestBackend>::new(3, 2);
+
+        let loss = loss.forward_no_reduction(embeddings(), targets());
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([2.0, 8.0, 10.0]), 5);
+    }
+
+    #[test]
+    fn test_center_loss_update() {
+        let loss = CenterLoss::<TestBackend>::new(3, 2).with_rate(1.0);
+
+        loss.update(embeddings(), targets());
+
+        // The class 2 has no sample, so its center doesn't move.
+        let expected = Data::from([[2.0, 2.0 / 3.0], [0.0, 2.0], [0.0, 0.0]]);
+        loss.centers().into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_center_loss_decreases_as_embeddings_approach_their_centers() {
+        let loss = CenterLoss::<TestBackend>::new(3, 2);
+        let mut previous = loss
+            .forward(embeddings(), targets(), Reduction::Mean)
+            .into_scalar();
+
+        for _ in 0..5 {
+            loss.update(embeddings(), targets());
+            let current = loss
+                .forward(embeddings(), targets(), Reduction::Mean)
+                .into_scalar();
+
+            assert!(current < previous);
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn test_center_loss_centers_are_saved_in_the_record() {
+        let loss = CenterLoss::<TestBackend>::new(3, 2);
+        loss.update(embeddings(), targets());
+
+        let loaded = CenterLoss::<TestBackend>::new(3, 2).load_record(loss.clone().into_record());
+
+        loaded
+            .centers()
+            .into_data()
+            .assert_approx_eq(&loss.centers().into_data(), 5);
+    }
+}
@@ -0,0 +1,66 @@
+use burn_tensor::{backend::Backend, Bool, Tensor};
+
+/// Average the values at the positions where the mask is true, e.g. to compute a loss only over
+/// the valid targets.
+///
+/// The masked out positions are ignored even when they are `NaN`, and the mean is zero when the
+/// mask has no true position.
+///
+/// # Shapes
+///
+/// - values: `[...dims]`
+/// - mask: `[...dims]`
+pub fn masked_mean<B: Backend, const D: usize>(
+    values: Tensor<B, D>,
+    mask: Tensor<B, D, Bool>,
+) -> Tensor<B, 1> {
+    let weights = values.zeros_like().mask_fill(mask, 1.0);
+    let values = values.mask_fill(weights.clone().equal_elem(0.0), 0.0);
+
+    let count = weights.sum();
+    let count = count.clone().mask_fill(count.equal_elem(0.0), 1.0);
+
+    values.sum().div(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_masked_mean_ignores_masked_positions() {
+        let values = Tensor::<TestBackend, 2>::from_floats([[1.0, f32::NAN], [3.0, 100.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [true, false]]));
+
+        let output = masked_mean(values, mask);
+
+        output.into_data().assert_approx_eq(&Data::from([2.0]), 5);
+    }
+
+    #[test]
+    fn test_masked_mean_matches_manual_mean_over_valid_entries() {
+        let values = Tensor::<TestBackend, 1>::from_floats([0.5, 2.0, -1.0, 4.0, 3.5]);
+        let mask =
+            Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, true, false, true, false]));
+
+        let output = masked_mean(values, mask);
+
+        let expected = (0.5 + 2.0 + 4.0) / 3.0;
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([expected]), 5);
+    }
+
+    #[test]
+    fn test_masked_mean_is_zero_when_mask_is_empty() {
+        let values = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, f32::NAN]);
+        let mask = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([false, false, false]));
+
+        let output = masked_mean(values, mask);
+
+        output.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+    }
+}
@@ -1,6 +1,23 @@
+mod center;
 mod cross_entropy;
+mod masked;
 mod mse;
+mod nll;
+mod poisson_nll;
+mod policy_gradient;
+mod quantile;
 mod reduction;
+mod soft_cross_entropy;
+mod td_huber;
 
+pub use center::*;
 pub use cross_entropy::*;
+pub use masked::*;
 pub use mse::*;
+pub use nll::*;
+pub use poisson_nll::*;
+pub use policy_gradient::*;
+pub use quantile::*;
+pub use reduction::*;
+pub use soft_cross_entropy::*;
+pub use td_huber::*;
@@ -0,0 +1,180 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Calculate the negative log likelihood loss from the input log-probabilities and the targets.
+///
+/// The input is expected to already be normalized, e.g. using
+/// [log_softmax](burn_tensor::activation::log_softmax).
+#[derive(Clone, Debug)]
+pub struct NllLoss<B: Backend> {
+    ignore_index: Option<usize>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for NllLoss<B> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<B: Backend> NllLoss<B> {
+    /// Create the criterion.
+    ///
+    /// Targets equal to `ignore_index` don't contribute to the loss.
+    pub fn new(ignore_index: Option<usize>) -> Self {
+        Self {
+            ignore_index,
+            backend: PhantomData::default(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With the mean reduction, the loss is averaged over the targets that aren't ignored, and is
+    /// zero when every target is ignored.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: [batch_size, num_targets]
+    /// - targets: [batch_size]
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let mask = self.ignore_mask(&targets);
+        let tensor = self.forward_no_reduction(log_probs, targets);
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => match mask {
+                Some(mask) => {
+                    let weights = tensor.ones_like().mask_fill(mask, 0);
+                    // The count is zero when every target is ignored, the mean is then zero.
+                    tensor.sum().safe_div(weights.sum(), 0.0)
+                }
+                None => tensor.mean(),
+            },
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    ///
+    /// Ignored targets have a loss of zero.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: [batch_size, num_targets]
+    /// - targets: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size] = targets.dims();
+
+        let mask = self.ignore_mask(&targets);
+        let tensor = log_probs
+            .gather(1, targets.reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .neg();
+
+        match mask {
+            Some(mask) => tensor.mask_fill(mask, 0),
+            None => tensor,
+        }
+    }
+
+    fn ignore_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
+        self.ignore_index
+            .map(|ignore_index| targets.clone().equal_elem(ignore_index as i64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::loss::CrossEntropyLoss, TestBackend};
+    use burn_tensor::{activation, Data, Distribution};
+
+    #[test]
+    fn test_nll_loss_equals_cross_entropy() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]));
+
+        let loss_1 = CrossEntropyLoss::new(None).forward(logits.clone(), targets.clone());
+        let loss_2 = NllLoss::new(None).forward(
+            activation::log_softmax(logits, 1),
+            targets,
+            Reduction::Auto,
+        );
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 5);
+    }
+
+    #[test]
+    fn test_nll_loss_reductions() {
+        let log_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [-0.5, -1.0, -2.0],
+            [-3.0, -0.2, -1.5],
+            [-1.0, -2.5, -0.1],
+        ]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 2, 1]));
+        let loss = NllLoss::new(None);
+
+        let loss_no_reduction = loss.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss_mean = loss.forward(log_probs.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = loss.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 1.5, 2.5]), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5]), 5);
+        loss_sum.into_data().assert_approx_eq(&Data::from([4.5]), 5);
+    }
+
+    #[test]
+    fn test_nll_loss_with_ignore_index() {
+        let log_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [-0.5, -1.0, -2.0],
+            [-3.0, -0.2, -1.5],
+            [-1.0, -2.5, -0.1],
+        ]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 2, 1]));
+        let loss = NllLoss::new(Some(2));
+
+        let loss_no_reduction = loss.forward_no_reduction(log_probs.clone(), targets.clone());
+        let loss_mean = loss.forward(log_probs.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = loss.forward(log_probs, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 0.0, 2.5]), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([1.5]), 5);
+        loss_sum.into_data().assert_approx_eq(&Data::from([3.0]), 5);
+    }
+
+    #[test]
+    fn test_nll_loss_mean_is_zero_when_every_target_is_ignored() {
+        let log_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [-0.5, -1.0, -2.0],
+            [-3.0, -0.2, -1.5],
+        ]));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 2]));
+        let loss = NllLoss::new(Some(2));
+
+        let loss_mean = loss.forward(log_probs, targets, Reduction::Mean);
+
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 5);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Calculate the Poisson negative log likelihood loss from the input predictions and the targets.
+///
+/// When `log_input` is true, the predictions are the log of the expected rate and the loss is
+/// `exp(pred) - target * pred`. Otherwise, the predictions are the rate itself and the loss is
+/// `pred - target * log(pred + epsilon)`.
+#[derive(Clone, Debug)]
+pub struct PoissonNllLoss<B: Backend> {
+    log_input: bool,
+    epsilon: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for PoissonNllLoss<B> {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl<B: Backend> PoissonNllLoss<B> {
+    /// Create the criterion.
+    pub fn new(log_input: bool) -> Self {
+        Self {
+            log_input,
+            epsilon: 1e-8,
+            backend: PhantomData::default(),
+        }
+    }
+
+    /// Set the value added to the predictions before taking their log when `log_input` is false.
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [...dims]
+    /// - targets: [...dims]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        match self.log_input {
+            true => predictions.clone().exp().sub(targets.mul(predictions)),
+            false => {
+                let log = predictions.clone().add_scalar(self.epsilon).log();
+                predictions.sub(targets.mul(log))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use alloc::vec::Vec;
+    use burn_tensor::{Data, Shape};
+
+    const PREDICTIONS: [f32; 4] = [0.5, 1.0, 2.0, 3.5];
+    const TARGETS: [f32; 4] = [1.0, 0.0, 3.0, 2.0];
+
+    fn reference(log_input: bool) -> Vec<f32> {
+        PREDICTIONS
+            .iter()
+            .zip(TARGETS.iter())
+            .map(|(&pred, &target)| match log_input {
+                true => libm::expf(pred) - target * pred,
+                false => pred - target * libm::logf(pred + 1e-8),
+            })
+            .collect()
+    }
+
+    fn test_poisson_nll_loss(log_input: bool) {
+        let predictions = Tensor::<TestBackend, 1>::from_data(Data::from(PREDICTIONS));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from(TARGETS));
+        let loss = PoissonNllLoss::new(log_input);
+        let expected = reference(log_input);
+
+        let loss_no_reduction = loss.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss_mean = loss.forward(predictions.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = loss.forward(predictions, targets, Reduction::Sum);
+
+        let sum: f32 = expected.iter().sum();
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::new(expected, Shape::new([4])), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([sum / 4.0]), 5);
+        loss_sum.into_data().assert_approx_eq(&Data::from([sum]), 5);
+    }
+
+    #[test]
+    fn test_poisson_nll_loss_log_input() {
+        test_poisson_nll_loss(true);
+    }
+
+    #[test]
+    fn test_poisson_nll_loss_no_log_input() {
+        test_poisson_nll_loss(false);
+    }
+}
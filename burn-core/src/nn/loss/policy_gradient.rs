@@ -0,0 +1,150 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, Int, Tensor};
+
+/// Calculate the policy-gradient (REINFORCE) loss from the policy logits, the chosen actions and
+/// their advantages.
+///
+/// The loss of each sample is `-log_prob(action) * advantage - entropy_coefficient * entropy`,
+/// where the entropy bonus of the policy encourages exploration.
+#[derive(Clone, Debug)]
+pub struct PolicyGradientLoss<B: Backend> {
+    entropy_coefficient: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for PolicyGradientLoss<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> PolicyGradientLoss<B> {
+    /// Create the criterion without entropy bonus.
+    pub fn new() -> Self {
+        Self {
+            entropy_coefficient: 0.0,
+            backend: PhantomData::default(),
+        }
+    }
+
+    /// Set the weight of the entropy bonus subtracted from the loss.
+    pub fn with_entropy_coefficient(mut self, entropy_coefficient: f64) -> Self {
+        self.entropy_coefficient = entropy_coefficient;
+        self
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_actions]
+    /// - actions: [batch_size]
+    /// - advantages: [batch_size]
+    pub fn forward(
+        &self,
+        logits: Tensor<B, 2>,
+        actions: Tensor<B, 1, Int>,
+        advantages: Tensor<B, 1>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, actions, advantages);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: [batch_size, num_actions]
+    /// - actions: [batch_size]
+    /// - advantages: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        logits: Tensor<B, 2>,
+        actions: Tensor<B, 1, Int>,
+        advantages: Tensor<B, 1>,
+    ) -> Tensor<B, 1> {
+        let [batch_size] = actions.dims();
+
+        let log_probs = activation::log_softmax(logits, 1);
+        let entropy = Self::entropy(log_probs.clone());
+        let tensor = log_probs
+            .gather(1, actions.reshape([batch_size, 1]))
+            .reshape([batch_size])
+            .mul(advantages)
+            .neg();
+
+        tensor.sub(entropy.mul_scalar(self.entropy_coefficient))
+    }
+
+    /// The entropy of the policy of each sample, with the shape [batch_size].
+    fn entropy(log_probs: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = log_probs.dims();
+
+        log_probs
+            .clone()
+            .exp()
+            .mul(log_probs)
+            .sum_dim(1)
+            .reshape([batch_size])
+            .neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_policy_gradient_loss_sign_follows_the_advantage() {
+        let logits = Tensor::<TestBackend, 2>::zeros([2, 4]);
+        let actions = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0]));
+        let advantages = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, -1.0]));
+
+        let loss = PolicyGradientLoss::new().forward_no_reduction(logits, actions, advantages);
+
+        // Every action has a log-probability of -ln(4).
+        let log_4 = libm::logf(4.0);
+        loss.into_data()
+            .assert_approx_eq(&Data::from([2.0 * log_4, -log_4]), 5);
+    }
+
+    #[test]
+    fn test_policy_gradient_loss_entropy_bonus_increases_with_flatter_policies() {
+        let peaked = Tensor::<TestBackend, 2>::from_data(Data::from([[5.0, 0.0, 0.0, 0.0]]));
+        let flat = Tensor::<TestBackend, 2>::from_data(Data::from([[0.5, 0.0, 0.0, 0.0]]));
+        let actions = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0]));
+        let advantages = Tensor::<TestBackend, 1>::from_data(Data::from([1.0]));
+
+        let bonus = |logits: Tensor<TestBackend, 2>| {
+            let loss = PolicyGradientLoss::new().forward(
+                logits.clone(),
+                actions.clone(),
+                advantages.clone(),
+                Reduction::Mean,
+            );
+            let loss_with_bonus = PolicyGradientLoss::new()
+                .with_entropy_coefficient(0.1)
+                .forward(logits, actions.clone(), advantages.clone(), Reduction::Mean);
+
+            loss.sub(loss_with_bonus).into_scalar()
+        };
+
+        let bonus_peaked = bonus(peaked);
+        let bonus_flat = bonus(flat);
+
+        assert!(bonus_peaked > 0.0);
+        assert!(bonus_flat > bonus_peaked);
+        // The bonus of the uniform policy is the coefficient times ln(4).
+        let bonus_uniform = bonus(Tensor::zeros([1, 4]));
+        assert!((bonus_uniform - 0.1 * libm::logf(4.0)).abs() < 1e-5);
+    }
+}
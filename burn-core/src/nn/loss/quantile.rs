@@ -0,0 +1,115 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Calculate the quantile (pinball) loss from the input predictions and the targets.
+///
+/// With `error = target - pred`, the loss is `max(q * error, (q - 1) * error)`, penalizing
+/// under-predictions with a weight of `q` and over-predictions with a weight of `1 - q`.
+#[derive(Clone, Debug)]
+pub struct QuantileLoss<B: Backend> {
+    quantile: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> QuantileLoss<B> {
+    /// Create the criterion for the given quantile.
+    ///
+    /// # Panics
+    ///
+    /// If the quantile isn't in the range [0, 1].
+    pub fn new(quantile: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&quantile),
+            "The quantile should be in the range [0, 1], got {quantile}"
+        );
+
+        Self {
+            quantile,
+            backend: PhantomData::default(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [...dims]
+    /// - targets: [...dims]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = targets.sub(predictions);
+        let negative = error
+            .zeros_like()
+            .mask_fill(error.clone().lower_elem(0.0), 1.0);
+
+        // Over-predictions are weighted by `q - 1` instead of `q`.
+        let weighted = error.clone().mul_scalar(self.quantile);
+        weighted.sub(negative.mul(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_quantile_loss_is_asymmetric() {
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 1.0]));
+        let predictions = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 2.0]));
+
+        let loss = QuantileLoss::new(0.9).forward_no_reduction(predictions, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.9, 0.1]), 5);
+    }
+
+    #[test]
+    fn test_quantile_loss_reductions() {
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0, 4.0]));
+        let predictions = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 2.0, 1.0, 5.0]));
+        let loss = QuantileLoss::new(0.25);
+
+        let loss_mean = loss.forward(predictions.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = loss.forward(predictions, targets, Reduction::Sum);
+
+        // Errors are [-1, 0, 2, -1], giving losses of [0.75, 0, 0.5, 0.75].
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5]), 5);
+        loss_sum.into_data().assert_approx_eq(&Data::from([2.0]), 5);
+    }
+
+    #[test]
+    fn test_median_quantile_loss_is_half_mae() {
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, -2.0], [0.5, 3.0]]));
+        let predictions =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[2.5, -1.0], [0.5, 1.0]]));
+
+        let loss = QuantileLoss::new(0.5).forward(predictions, targets, Reduction::Mean);
+
+        // Absolute errors are [1.5, 1.0, 0.0, 2.0], giving a MAE of 1.125.
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.125 / 2.0]), 5);
+    }
+}
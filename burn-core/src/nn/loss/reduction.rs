@@ -1,5 +1,9 @@
+/// The reduction applied on the output of a loss.
 pub enum Reduction {
+    /// Average the loss values.
     Mean,
+    /// Sum the loss values.
     Sum,
+    /// Use the default reduction of the loss.
     Auto,
 }
@@ -0,0 +1,60 @@
+use burn_tensor::{activation, backend::Backend, Tensor};
+
+/// Calculate the cross entropy loss from the input logits and probability-distribution targets.
+///
+/// The classes are along the last dimension, and the loss is averaged over all other dimensions.
+/// Unlike [CrossEntropyLoss](crate::nn::loss::CrossEntropyLoss), the targets don't have to be
+/// one-hot, which is useful for knowledge distillation or mixup.
+///
+/// # Shapes
+///
+/// - logits: [...dims, num_classes]
+/// - target_probs: [...dims, num_classes]
+pub fn soft_cross_entropy<B: Backend, const D: usize>(
+    logits: Tensor<B, D>,
+    target_probs: Tensor<B, D>,
+) -> Tensor<B, 1> {
+    let tensor = activation::log_softmax(logits, D - 1);
+    let tensor = tensor.mul(target_probs).sum_dim(D - 1);
+
+    tensor.mean().neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::loss::CrossEntropyLoss, TestBackend};
+    use burn_tensor::{Data, Distribution, Int};
+
+    #[test]
+    fn test_soft_cross_entropy_equals_hard_labels_with_one_hot_targets() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 3], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 1, 1]));
+        let target_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]));
+
+        let loss_1 = soft_cross_entropy(logits.clone(), target_probs);
+        let loss_2 = CrossEntropyLoss::new(None).forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 5);
+    }
+
+    #[test]
+    fn test_soft_cross_entropy_with_uniform_logits() {
+        let logits = Tensor::<TestBackend, 2>::zeros([2, 4]);
+        let target_probs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.1, 0.2, 0.3, 0.4],
+            [0.7, 0.1, 0.1, 0.1],
+        ]));
+
+        let loss = soft_cross_entropy(logits, target_probs);
+
+        // Every class has a log-probability of -ln(4), and each target sums to one.
+        loss.into_data()
+            .assert_approx_eq(&Data::from([libm::logf(4.0)]), 5);
+    }
+}
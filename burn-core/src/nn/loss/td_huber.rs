@@ -0,0 +1,146 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Calculate the Huber loss between the predicted and target Q-values of a temporal difference
+/// update.
+///
+/// With `error = target - pred`, the loss is `0.5 * error^2` when `|error| <= delta` and
+/// `delta * (|error| - 0.5 * delta)` otherwise, so large TD errors have bounded gradients.
+/// The per-sample losses can be scaled by importance-sampling weights, e.g. when the samples
+/// come from a prioritized replay buffer.
+#[derive(Clone, Debug)]
+pub struct TdHuberLoss<B: Backend> {
+    delta: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> Default for TdHuberLoss<B> {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl<B: Backend> TdHuberLoss<B> {
+    /// Create the criterion with the given transition point between the quadratic and the linear
+    /// regions.
+    ///
+    /// # Panics
+    ///
+    /// If delta isn't strictly positive.
+    pub fn new(delta: f64) -> Self {
+        assert!(
+            delta > 0.0,
+            "The delta of the Huber loss should be strictly positive, got {delta}"
+        );
+
+        Self {
+            delta,
+            backend: PhantomData::default(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [batch_size]
+    /// - targets: [batch_size]
+    /// - weights: [batch_size]
+    pub fn forward(
+        &self,
+        predictions: Tensor<B, 1>,
+        targets: Tensor<B, 1>,
+        weights: Option<Tensor<B, 1>>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(predictions, targets, weights);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing it.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [batch_size]
+    /// - targets: [batch_size]
+    /// - weights: [batch_size]
+    /// - output: [batch_size]
+    pub fn forward_no_reduction(
+        &self,
+        predictions: Tensor<B, 1>,
+        targets: Tensor<B, 1>,
+        weights: Option<Tensor<B, 1>>,
+    ) -> Tensor<B, 1> {
+        let error = targets.sub(predictions).abs();
+
+        // The clamped error is the part of the error that is in the quadratic region.
+        let quadratic = error.clone().clamp_max(self.delta);
+        let linear = error.sub(quadratic.clone());
+        let tensor = quadratic
+            .powf(2.0)
+            .mul_scalar(0.5)
+            .add(linear.mul_scalar(self.delta));
+
+        match weights {
+            Some(weights) => tensor.mul(weights),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_td_huber_loss_delta_controls_the_transition() {
+        let predictions = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 0.0, 0.0, 0.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([0.5, 1.0, 2.0, -3.0]));
+
+        let loss_1 =
+            TdHuberLoss::new(1.0).forward_no_reduction(predictions.clone(), targets.clone(), None);
+        let loss_2 = TdHuberLoss::new(2.0).forward_no_reduction(predictions, targets, None);
+
+        loss_1
+            .into_data()
+            .assert_approx_eq(&Data::from([0.125, 0.5, 1.5, 2.5]), 5);
+        loss_2
+            .into_data()
+            .assert_approx_eq(&Data::from([0.125, 0.5, 2.0, 4.0]), 5);
+    }
+
+    #[test]
+    fn test_td_huber_loss_importance_weights_scale_per_sample_losses() {
+        let predictions = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 0.0, 0.0, 0.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([0.5, 1.0, 2.0, -3.0]));
+        let weights = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 0.0, 0.5]));
+
+        let loss = TdHuberLoss::default();
+        let loss_no_reduction =
+            loss.forward_no_reduction(predictions.clone(), targets.clone(), Some(weights.clone()));
+        let loss_mean = loss.forward(
+            predictions.clone(),
+            targets.clone(),
+            Some(weights.clone()),
+            Reduction::Mean,
+        );
+        let loss_sum = loss.forward(predictions, targets, Some(weights), Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.125, 1.0, 0.0, 1.25]), 5);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.59375]), 5);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([2.375]), 5);
+    }
+}
@@ -3,22 +3,45 @@ pub mod cache;
 pub mod conv;
 pub mod loss;
 pub mod pool;
+pub mod rl;
 pub mod transformer;
 
+mod bilinear;
+mod collate;
 mod dropout;
 mod embedding;
+mod gated_fusion;
 mod gelu;
 mod initializer;
+mod label_smoothing;
 mod linear;
+mod lora;
+mod moe;
 mod norm;
 mod relu;
 mod rnn;
+mod skip;
+mod sliding_window;
+mod temperature_scaling;
+mod token;
+mod upsample;
 
+pub use bilinear::*;
+pub use collate::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use gated_fusion::*;
 pub use gelu::*;
 pub use initializer::*;
+pub use label_smoothing::*;
 pub use linear::*;
+pub use lora::*;
+pub use moe::*;
 pub use norm::*;
 pub use relu::*;
 pub use rnn::*;
+pub use skip::*;
+pub use sliding_window::*;
+pub use temperature_scaling::*;
+pub use token::*;
+pub use upsample::*;
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Int, Tensor};
+
+/// Keep only the `k` highest gate values along the given dimension and renormalize them.
+///
+/// This is the routing function used by sparse mixture-of-experts layers, where each token is
+/// only dispatched to the `k` experts with the highest gate values.
+///
+/// # Returns
+///
+/// A tuple containing the sparse gate weights, having the same shape as the given gates where
+/// the non-selected values are set to zero and the selected ones sum to one, and the indices of
+/// the selected experts sorted by decreasing gate value, of size `k` along the given dimension.
+///
+/// # Panics
+///
+/// If `k` is zero or higher than the number of experts.
+pub fn top_k_gate<B: Backend, const D: usize>(
+    gates: Tensor<B, D>,
+    k: usize,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D, Int>) {
+    let num_experts = gates.dims()[dim];
+    assert!(
+        k > 0 && k <= num_experts,
+        "k should be between 1 and the number of experts ({num_experts}), got {k}"
+    );
+
+    let device = gates.device();
+    let mut selected = Tensor::<B, D>::zeros_device(gates.shape(), &device);
+    let mut remaining = gates.clone().detach();
+    let mut indices = Vec::with_capacity(k);
+
+    for _ in 0..k {
+        let index = remaining.clone().argmax(dim);
+        let ones = Tensor::ones_device(index.shape(), &device);
+
+        selected = selected.scatter(dim, index.clone(), ones);
+        remaining = remaining.mask_fill(selected.clone().equal_elem(1.0), f32::NEG_INFINITY);
+        indices.push(index);
+    }
+
+    let sparse = gates.mul(selected);
+    let norm = sparse.clone().sum_dim(dim);
+
+    (sparse.div(norm), Tensor::cat(indices, dim))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_top_k_gate() {
+        let gates = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.1, 0.3, 0.1, 0.5],
+            [0.2, 0.6, 0.15, 0.05],
+        ]));
+
+        let (sparse, indices) = top_k_gate(gates, 2, 1);
+
+        let num_nonzero = sparse.clone().greater_elem(0.0).into_int().sum_dim(1);
+        assert_eq!(num_nonzero.into_data(), Data::from([[2], [2]]));
+        sparse
+            .clone()
+            .sum_dim(1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 3);
+        sparse.into_data().assert_approx_eq(
+            &Data::from([[0.0, 0.375, 0.0, 0.625], [0.25, 0.75, 0.0, 0.0]]),
+            3,
+        );
+        assert_eq!(indices.into_data(), Data::from([[3, 1], [1, 0]]));
+    }
+}
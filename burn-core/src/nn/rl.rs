@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+
+use burn_tensor::{
+    backend::Backend, Bool, Data, Distribution, ElementConversion, Int, Shape, Tensor,
+};
+
+/// Compute the [generalized advantage estimation](https://arxiv.org/abs/1506.02438) of a
+/// trajectory.
+///
+/// With `delta_t = r_t + gamma * V_{t+1} * (1 - done_t) - V_t`, the advantages are accumulated
+/// in reverse time as `A_t = delta_t + gamma * lambda * (1 - done_t) * A_{t+1}`, so nothing is
+/// propagated across the end of an episode. The values have one more step than the rewards,
+/// the last one being the bootstrap value of the state following the trajectory.
+///
+/// # Shapes
+///
+/// - rewards: `[batch_size, num_steps]`
+/// - values: `[batch_size, num_steps + 1]`
+/// - dones: `[batch_size, num_steps]`
+/// - output: `[batch_size, num_steps]`
+///
+/// # Panics
+///
+/// If the values don't have one more step than the rewards.
+pub fn gae<B: Backend>(
+    rewards: Tensor<B, 2>,
+    values: Tensor<B, 2>,
+    dones: Tensor<B, 2, Bool>,
+    gamma: f64,
+    lambda: f64,
+) -> Tensor<B, 2> {
+    let [batch_size, num_steps] = rewards.dims();
+    assert_eq!(
+        values.dims(),
+        [batch_size, num_steps + 1],
+        "The values should have one more step than the rewards"
+    );
+
+    let not_dones = rewards.ones_like().mask_fill(dones, 0.0);
+    let next_values = values.clone().index([0..batch_size, 1..num_steps + 1]);
+    let values = values.index([0..batch_size, 0..num_steps]);
+    let deltas = rewards
+        .add(next_values.mul(not_dones.clone()).mul_scalar(gamma))
+        .sub(values);
+
+    let mut advantage = Tensor::zeros_device([batch_size, 1], &deltas.device());
+    let mut advantages = Vec::with_capacity(num_steps);
+
+    for step in (0..num_steps).rev() {
+        let delta = deltas.clone().index([0..batch_size, step..step + 1]);
+        let not_done = not_dones.clone().index([0..batch_size, step..step + 1]);
+
+        advantage = delta.add(advantage.mul(not_done).mul_scalar(gamma * lambda));
+        advantages.push(advantage.clone());
+    }
+
+    advantages.reverse();
+    Tensor::cat(advantages, 1)
+}
+
+/// Sample a random minibatch of indices in `[0, n)`, e.g. the transitions of a replay buffer of
+/// size `n`, to be used with [select](Tensor::index_select) or [gather](Tensor::gather).
+///
+/// Without replacement, the indices are all different.
+///
+/// # Panics
+///
+/// If `n` is zero, or if `batch_size` is larger than `n` when sampling without replacement.
+pub fn sample_indices<B: Backend>(
+    n: usize,
+    batch_size: usize,
+    replacement: bool,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    assert!(n > 0, "Can't sample indices from an empty range");
+
+    if !replacement {
+        assert!(
+            batch_size <= n,
+            "Can't sample {batch_size} indices out of {n} without replacement"
+        );
+
+        // Sorting random keys gives a uniformly random permutation.
+        return Tensor::<B, 1>::random([n], Distribution::Standard)
+            .to_device(device)
+            .argsort(0, false)
+            .index([0..batch_size]);
+    }
+
+    let samples = Tensor::<B, 1>::random([batch_size], Distribution::Standard).into_data();
+    let indices: Vec<i64> = samples
+        .value
+        .into_iter()
+        .map(|sample| usize::min((sample.elem::<f64>() * n as f64) as usize, n - 1) as i64)
+        .collect();
+
+    Tensor::from_data_device(
+        Data::new(indices, Shape::new([batch_size])).convert(),
+        device,
+    )
+}
+
+/// Compute the sampling probabilities and the importance-sampling weights of a
+/// [prioritized replay buffer](https://arxiv.org/abs/1511.05952) from the priorities of its
+/// transitions.
+///
+/// The probabilities are `P_i = p_i^alpha / sum(p^alpha)`, and the weights `(N * P_i)^(-beta)`
+/// are normalized by their maximum, so they only scale the updates down.
+///
+/// # Shapes
+///
+/// - priorities: `[n]`
+/// - output: (`[n]`, `[n]`)
+pub fn prioritized_weights<B: Backend>(
+    priorities: Tensor<B, 1>,
+    alpha: f64,
+    beta: f64,
+) -> (Tensor<B, 1>, Tensor<B, 1>) {
+    let [n] = priorities.dims();
+
+    let priorities = priorities.powf(alpha as f32);
+    let probabilities = priorities.clone().div(priorities.sum());
+
+    let weights = probabilities
+        .clone()
+        .mul_scalar(n as f64)
+        .powf(-beta as f32);
+    let weights = weights.clone().div(weights.max());
+
+    (probabilities, weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn gae_should_match_hand_computed_advantages() {
+        let rewards =
+            Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0, 1.0], [0.0, 0.0, 2.0]]));
+        let values = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.5, 0.5, 0.5, 0.5],
+            [1.0, 2.0, 3.0, 4.0],
+        ]));
+        let dones = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, true, false],
+            [false, false, false],
+        ]));
+
+        let advantages = gae(rewards, values, dones, 0.9, 0.8);
+
+        // The deltas are [0.95, 0.5, 0.95] and [0.8, 0.7, 2.6], accumulated with a factor of
+        // 0.72, which is reset after the end of the episode of the first trajectory.
+        advantages
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.31, 0.5, 0.95], [2.65184, 2.572, 2.6]]), 4);
+    }
+
+    #[test]
+    fn gae_with_zero_lambda_should_give_td_errors() {
+        let rewards = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, -1.0]]));
+        let values = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0, 3.0]]));
+        let dones = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, true]]));
+
+        let advantages = gae(rewards, values, dones, 0.5, 0.0);
+
+        advantages
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.5, -2.0]]), 4);
+    }
+
+    #[test]
+    fn sample_indices_without_replacement_should_be_unique() {
+        let device = Default::default();
+
+        let indices = sample_indices::<TestBackend>(10, 10, false, &device);
+
+        // A permutation of the range is sorted back into the range.
+        let sorted = indices.sort(0, false);
+        assert_eq!(
+            sorted.into_data(),
+            Data::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9])
+        );
+
+        let indices = sample_indices::<TestBackend>(10, 4, false, &device).into_data();
+        assert_eq!(indices.shape.dims, [4]);
+        for (i, index) in indices.value.iter().enumerate() {
+            assert!((0..10).contains(index));
+            assert!(!indices.value[..i].contains(index));
+        }
+    }
+
+    #[test]
+    fn sample_indices_with_replacement_should_be_in_range() {
+        let device = Default::default();
+
+        let indices = sample_indices::<TestBackend>(5, 100, true, &device).into_data();
+
+        assert_eq!(indices.shape.dims, [100]);
+        assert!(indices.value.iter().all(|index| (0..5).contains(index)));
+    }
+
+    #[test]
+    fn prioritized_weights_should_match_formula() {
+        let priorities = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0, 4.0]));
+
+        let (probabilities, weights) = prioritized_weights(priorities, 1.0, 1.0);
+
+        let sum = probabilities.clone().sum().into_scalar();
+        assert!((sum - 1.0).abs() < 1e-6);
+        probabilities
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1, 0.2, 0.3, 0.4]), 5);
+        // The weights `1 / (4 * P_i)` are normalized by the weight of the lowest priority.
+        weights
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 0.5, 1.0 / 3.0, 0.25]), 5);
+    }
+
+    #[test]
+    fn prioritized_weights_with_zero_alpha_should_be_uniform() {
+        let priorities = Tensor::<TestBackend, 1>::from_data(Data::from([0.5, 8.0, 2.0]));
+
+        let (probabilities, weights) = prioritized_weights(priorities, 0.0, 0.4);
+
+        probabilities
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0 / 3.0; 3]), 5);
+        weights
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0; 3]), 5);
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::vec;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Concatenate an upsampled tensor with its skip connection along the channel dimension.
+///
+/// This is the merge step used by U-Net decoders. When the skip connection is spatially larger
+/// than the upsampled tensor, it is center-cropped to the upsampled height and width before the
+/// concatenation, the extra pixel of odd differences being removed from the bottom and right.
+///
+/// # Shapes
+///
+/// - upsampled: `[batch_size, channels_upsampled, height, width]`
+/// - skip: `[batch_size, channels_skip, height_skip, width_skip]`
+/// - output: `[batch_size, channels_upsampled + channels_skip, height, width]`
+///
+/// # Panics
+///
+/// If the batch sizes differ or if the skip connection is spatially smaller than the upsampled
+/// tensor.
+pub fn concat_skip<B: Backend>(upsampled: Tensor<B, 4>, skip: Tensor<B, 4>) -> Tensor<B, 4> {
+    let [batch_size, _, height, width] = upsampled.dims();
+    let [batch_size_skip, channels_skip, height_skip, width_skip] = skip.dims();
+
+    assert_eq!(
+        batch_size, batch_size_skip,
+        "The skip connection batch size ({batch_size_skip}) should match the upsampled batch size \
+         ({batch_size})"
+    );
+    assert!(
+        height_skip >= height && width_skip >= width,
+        "The skip connection spatial size ({height_skip}, {width_skip}) should be at least the \
+         upsampled spatial size ({height}, {width})"
+    );
+
+    let skip = if height_skip == height && width_skip == width {
+        skip
+    } else {
+        let top = (height_skip - height) / 2;
+        let left = (width_skip - width) / 2;
+
+        skip.index([
+            0..batch_size,
+            0..channels_skip,
+            top..top + height,
+            left..left + width,
+        ])
+    };
+
+    Tensor::cat(vec![upsampled, skip], 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn concat_skip_should_center_crop_larger_skip() {
+        let upsampled = Tensor::<TestBackend, 4>::zeros([1, 1, 2, 2]);
+        let skip = Tensor::<TestBackend, 4>::from_floats([[[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]]]);
+
+        let output = concat_skip(upsampled, skip);
+
+        assert_eq!(output.dims(), [1, 2, 2, 2]);
+        let expected = Tensor::<TestBackend, 4>::from_floats([[
+            [[0.0, 0.0], [0.0, 0.0]],
+            [[1.0, 2.0], [4.0, 5.0]],
+        ]]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn concat_skip_should_concat_matching_sizes() {
+        let upsampled = Tensor::<TestBackend, 4>::ones([2, 3, 4, 4]);
+        let skip = Tensor::<TestBackend, 4>::zeros([2, 5, 4, 4]);
+
+        let output = concat_skip(upsampled, skip);
+
+        assert_eq!(output.dims(), [2, 8, 4, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn concat_skip_should_panic_when_skip_is_smaller() {
+        let upsampled = Tensor::<TestBackend, 4>::zeros([1, 1, 4, 4]);
+        let skip = Tensor::<TestBackend, 4>::zeros([1, 1, 3, 4]);
+
+        concat_skip(upsampled, skip);
+    }
+}
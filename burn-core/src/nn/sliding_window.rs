@@ -0,0 +1,154 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use burn_tensor::{backend::Backend, Data, Shape, Tensor};
+
+/// Apply a sequence model on a long sequence using overlapping windows of a fixed size.
+///
+/// A window starts every `stride` positions, and a last window is aligned with the end of the
+/// sequence so that every position is covered. The outputs of the windows are stitched back
+/// together with [cat](Tensor::cat), and averaged where the windows overlap.
+///
+/// # Shapes
+///
+/// - input: `[batch_size, seq_length, d_input]`
+/// - model: `[batch_size, window, d_input]` -> `[batch_size, window, d_output]`
+/// - output: `[batch_size, seq_length, d_output]`
+///
+/// # Panics
+///
+/// If the window or the stride is zero, if the stride is larger than the window, or if the model
+/// doesn't keep the sequence length of the window.
+pub fn sliding_window_infer<B, F>(
+    mut model: F,
+    input: Tensor<B, 3>,
+    window: usize,
+    stride: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+    F: FnMut(Tensor<B, 3>) -> Tensor<B, 3>,
+{
+    assert!(
+        window > 0 && stride > 0 && stride <= window,
+        "The stride {stride} should be strictly positive and not larger than the window {window}"
+    );
+
+    let [batch_size, seq_length, _] = input.dims();
+
+    if seq_length <= window {
+        return model(input);
+    }
+
+    let last = seq_length - window;
+    let mut starts: Vec<usize> = (0..=last).step_by(stride).collect();
+    if starts.last() != Some(&last) {
+        starts.push(last);
+    }
+
+    let device = input.device();
+    let mut output: Option<Tensor<B, 3>> = None;
+    let mut counts: Vec<f32> = vec![0.0; seq_length];
+
+    for start in starts {
+        let end = start + window;
+        let window_output = model(input.clone().index([0..batch_size, start..end]));
+        let [_, window_length, d_output] = window_output.dims();
+
+        assert_eq!(
+            window_length, window,
+            "The model should keep the sequence length of the window"
+        );
+
+        let mut parts = Vec::with_capacity(3);
+        if start > 0 {
+            parts.push(Tensor::zeros_device([batch_size, start, d_output], &device));
+        }
+        parts.push(window_output);
+        if end < seq_length {
+            let padding = [batch_size, seq_length - end, d_output];
+            parts.push(Tensor::zeros_device(padding, &device));
+        }
+        let padded = Tensor::cat(parts, 1);
+
+        output = Some(match output {
+            Some(output) => output + padded,
+            None => padded,
+        });
+        counts[start..end]
+            .iter_mut()
+            .for_each(|count| *count += 1.0);
+    }
+
+    let counts = Tensor::<B, 1>::from_data_device(
+        Data::new(counts, Shape::new([seq_length])).convert(),
+        &device,
+    );
+
+    output.unwrap() / counts.reshape([1, seq_length, 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn sliding_window_infer_should_cover_the_whole_sequence() {
+        let input = Tensor::<TestBackend, 3>::random([2, 7, 3], Distribution::Standard);
+
+        let output = sliding_window_infer(|window| window, input.clone(), 3, 2);
+
+        output.into_data().assert_approx_eq(&input.into_data(), 5);
+    }
+
+    #[test]
+    fn sliding_window_infer_should_average_overlaps() {
+        let input = Tensor::<TestBackend, 3>::zeros([1, 6, 1]);
+        let mut calls = 0;
+
+        // Windows start at 0, 2 and 3, each outputting its call index.
+        let output = sliding_window_infer(
+            |window: Tensor<TestBackend, 3>| {
+                let output = window.add_scalar(calls as f32);
+                calls += 1;
+                output
+            },
+            input,
+            3,
+            2,
+        );
+
+        assert_eq!(calls, 3);
+        let expected = Data::from([[[0.0], [0.0], [0.5], [1.5], [1.5], [2.0]]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn sliding_window_infer_should_run_once_on_short_sequences() {
+        let input = Tensor::<TestBackend, 3>::random([1, 2, 4], Distribution::Standard);
+        let mut calls = 0;
+
+        let output = sliding_window_infer(
+            |window| {
+                calls += 1;
+                window
+            },
+            input.clone(),
+            3,
+            1,
+        );
+
+        assert_eq!(calls, 1);
+        output.into_data().assert_approx_eq(&input.into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sliding_window_infer_should_panic_with_stride_larger_than_window() {
+        let input = Tensor::<TestBackend, 3>::zeros([1, 6, 1]);
+
+        sliding_window_infer(|window| window, input, 2, 3);
+    }
+}
@@ -0,0 +1,171 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::{activation, backend::Backend, Int, Tensor};
+
+use libm::log;
+
+/// Configuration to create a [TemperatureScaling](TemperatureScaling) layer.
+#[derive(Config, Debug)]
+pub struct TemperatureScalingConfig {
+    /// The temperature before calibration.
+    #[config(default = 1.0)]
+    pub initial_temperature: f64,
+    /// The learning rate of the gradient descent used during calibration.
+    #[config(default = 0.1)]
+    pub learning_rate: f64,
+    /// The number of gradient descent steps used during calibration.
+    #[config(default = 100)]
+    pub num_steps: usize,
+}
+
+/// Calibrates the probabilities of a classifier by dividing its logits by a single learnable
+/// temperature before the softmax, as described in the paper
+/// [On Calibration of Modern Neural Networks](https://arxiv.org/abs/1706.04599).
+///
+/// The temperature is stored as its logarithm, so that it stays strictly positive.
+///
+/// # Params
+///
+/// - log_temperature: Vector of size `1`.
+#[derive(Module, Debug)]
+pub struct TemperatureScaling<B: Backend> {
+    log_temperature: Param<Tensor<B, 1>>,
+    learning_rate: f64,
+    num_steps: usize,
+}
+
+impl TemperatureScalingConfig {
+    /// Initialize a new [temperature scaling](TemperatureScaling) module.
+    ///
+    /// # Panics
+    ///
+    /// If the initial temperature isn't strictly positive.
+    pub fn init<B: Backend>(&self) -> TemperatureScaling<B> {
+        assert!(
+            self.initial_temperature > 0.0,
+            "The initial temperature should be strictly positive, got {}",
+            self.initial_temperature
+        );
+
+        let log_temperature = Tensor::from_floats([log(self.initial_temperature) as f32]);
+
+        TemperatureScaling {
+            log_temperature: Param::from(log_temperature),
+            learning_rate: self.learning_rate,
+            num_steps: self.num_steps,
+        }
+    }
+}
+
+impl<B: Backend> TemperatureScaling<B> {
+    /// Applies the forward pass on the input logits.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[..., num_classes]`
+    /// - output: `[..., num_classes]`
+    pub fn forward<const D: usize>(&self, logits: Tensor<B, D>) -> Tensor<B, D> {
+        logits / self.temperature().unsqueeze()
+    }
+
+    /// The current temperature.
+    pub fn temperature(&self) -> Tensor<B, 1> {
+        self.log_temperature.val().exp()
+    }
+
+    /// Fit the temperature by minimizing the negative log likelihood of the targets, usually on a
+    /// validation set.
+    ///
+    /// The gradient with respect to the log temperature is computed analytically, so the
+    /// calibration doesn't require an autodiff backend.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_classes]`
+    /// - targets: `[batch_size]`
+    pub fn calibrate(self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Self {
+        let [batch_size, num_classes] = logits.dims();
+        let device = logits.device();
+        let logits = logits.set_require_grad(false);
+        let targets = Tensor::zeros_device([batch_size, num_classes], &device).scatter(
+            1,
+            targets.reshape([batch_size, 1]),
+            Tensor::ones_device([batch_size, 1], &device),
+        );
+        let mut log_temperature = self.log_temperature.val().set_require_grad(false);
+
+        for _ in 0..self.num_steps {
+            let scaled = logits.clone() / log_temperature.clone().exp().unsqueeze();
+            let probs = activation::softmax(scaled.clone(), 1);
+
+            // With z = x / exp(s), dNLL/ds = -mean_i sum_j (softmax(z)_ij - y_ij) * z_ij
+            let grad = (probs - targets.clone())
+                .mul(scaled)
+                .sum()
+                .div_scalar(batch_size as f32)
+                .neg();
+            log_temperature = log_temperature - grad.mul_scalar(self.learning_rate);
+        }
+
+        Self {
+            log_temperature: Param::new(self.log_temperature.id, log_temperature.require_grad()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLoss;
+    use crate::TestBackend;
+
+    #[test]
+    fn initial_temperature_should_not_change_logits() {
+        let scaling = TemperatureScalingConfig::new().init::<TestBackend>();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0]]);
+
+        let output = scaling.forward(logits.clone());
+
+        output.into_data().assert_approx_eq(&logits.into_data(), 5);
+    }
+
+    #[test]
+    fn forward_should_divide_logits_by_temperature() {
+        let scaling = TemperatureScalingConfig::new()
+            .with_initial_temperature(2.0)
+            .init::<TestBackend>();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[1.0, -2.0, 3.0]]);
+
+        let output = scaling.forward(logits);
+
+        let expected = Tensor::<TestBackend, 2>::from_floats([[0.5, -1.0, 1.5]]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn calibrate_should_reduce_nll_of_overconfident_logits() {
+        let scaling = TemperatureScalingConfig::new().init::<TestBackend>();
+        // Confident predictions that are only right 3 times out of 4.
+        let logits = Tensor::<TestBackend, 2>::from_floats([
+            [10.0, 0.0],
+            [0.0, 10.0],
+            [10.0, 0.0],
+            [0.0, 10.0],
+        ]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 1, 1]);
+        let loss = CrossEntropyLoss::new(None);
+
+        let nll_before = loss.forward(scaling.forward(logits.clone()), targets.clone());
+        let scaling = scaling.calibrate(logits.clone(), targets.clone());
+        let nll_after = loss.forward(scaling.forward(logits), targets);
+
+        assert!(scaling.temperature().into_scalar() > 1.0);
+        assert!(nll_after.into_scalar() < nll_before.into_scalar());
+    }
+}
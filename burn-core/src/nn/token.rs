@@ -0,0 +1,64 @@
+use alloc::vec;
+
+use burn_tensor::{backend::Backend, Tensor};
+
+/// Prepend a single token, such as the learnable class token of vision transformers, to every
+/// sequence of the batch.
+///
+/// # Shapes
+///
+/// - sequence: `[batch_size, seq_length, d_model]`
+/// - token: `[1, d_model]`
+/// - output: `[batch_size, seq_length + 1, d_model]`
+///
+/// # Panics
+///
+/// If the token isn't a single token of the sequence model size.
+pub fn prepend_token<B: Backend>(sequence: Tensor<B, 3>, token: Tensor<B, 2>) -> Tensor<B, 3> {
+    let [batch_size, _, d_model] = sequence.dims();
+    let [num_tokens, d_token] = token.dims();
+
+    assert!(
+        num_tokens == 1 && d_token == d_model,
+        "The token shape [{num_tokens}, {d_token}] should be [1, {d_model}]"
+    );
+
+    let token = token.reshape([1, 1, d_model]).repeat(0, batch_size);
+
+    Tensor::cat(vec![token, sequence], 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn prepend_token_should_add_the_token_at_the_front() {
+        let sequence = Tensor::<TestBackend, 3>::random([2, 4, 3], Distribution::Standard);
+        let token = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0]]);
+
+        let output = prepend_token(sequence.clone(), token.clone());
+
+        assert_eq!(output.dims(), [2, 5, 3]);
+        let token = token.reshape([1, 1, 3]).into_data();
+        for batch in 0..2 {
+            let first = output.clone().index([batch..batch + 1, 0..1]);
+            let rest = output.clone().index([batch..batch + 1, 1..5]);
+            let sequence = sequence.clone().index([batch..batch + 1]);
+
+            first.into_data().assert_approx_eq(&token, 3);
+            rest.into_data().assert_approx_eq(&sequence.into_data(), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn prepend_token_should_panic_with_mismatched_size() {
+        let sequence = Tensor::<TestBackend, 3>::zeros([2, 4, 3]);
+        let token = Tensor::<TestBackend, 2>::zeros([1, 2]);
+
+        prepend_token(sequence, token);
+    }
+}
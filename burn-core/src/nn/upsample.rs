@@ -0,0 +1,134 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::ops::InterpolateMode;
+use crate::tensor::Tensor;
+use burn_tensor::module::interpolate;
+
+/// Configuration to create an [upsampling](Upsample) layer.
+///
+/// Exactly one of `scale_factor` or `size` must be provided.
+#[derive(Config)]
+pub struct UpsampleConfig {
+    /// The multiplier applied to the height and width of the input.
+    pub scale_factor: Option<[f64; 2]>,
+    /// The explicit output height and width.
+    pub size: Option<[usize; 2]>,
+    /// The interpolation mode.
+    #[config(default = "UpsampleMode::Nearest")]
+    pub mode: UpsampleMode,
+}
+
+/// Interpolation mode for upsampling [config](UpsampleConfig).
+#[derive(Module, Config, Debug)]
+pub enum UpsampleMode {
+    /// Nearest neighbor interpolation.
+    Nearest,
+    /// Bilinear interpolation.
+    Bilinear,
+}
+
+impl UpsampleMode {
+    fn interpolate_mode(&self) -> InterpolateMode {
+        match self {
+            UpsampleMode::Nearest => InterpolateMode::Nearest,
+            UpsampleMode::Bilinear => InterpolateMode::Bilinear,
+        }
+    }
+}
+
+/// Upsamples the spatial dimensions of the input tensors using
+/// [interpolation](burn_tensor::module::interpolate).
+#[derive(Module, Debug, Clone)]
+pub struct Upsample {
+    scale_factor: Option<[f64; 2]>,
+    size: Option<[usize; 2]>,
+    mode: UpsampleMode,
+}
+
+impl UpsampleConfig {
+    /// Initialize a new [upsample](Upsample) module.
+    pub fn init(&self) -> Upsample {
+        assert!(
+            self.scale_factor.is_some() != self.size.is_some(),
+            "Exactly one of scale_factor or size must be provided to upsample"
+        );
+
+        Upsample {
+            scale_factor: self.scale_factor,
+            size: self.size,
+            mode: self.mode.clone(),
+        }
+    }
+}
+
+impl Upsample {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [_batch_size, _channels, height_in, width_in] = input.dims();
+        let output_size = self.output_size(height_in, width_in);
+
+        interpolate(input, output_size, self.mode.interpolate_mode())
+    }
+
+    fn output_size(&self, height_in: usize, width_in: usize) -> [usize; 2] {
+        match (self.size, self.scale_factor) {
+            (Some(size), _) => size,
+            (None, Some([scale_height, scale_width])) => [
+                libm::floor(height_in as f64 * scale_height) as usize,
+                libm::floor(width_in as f64 * scale_width) as usize,
+            ],
+            (None, None) => panic!("Upsample requires either a scale factor or a size"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn scale_factor_should_match_interpolate() {
+        let input = Tensor::<TestBackend, 4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        for (mode, interpolate_mode) in [
+            (UpsampleMode::Nearest, InterpolateMode::Nearest),
+            (UpsampleMode::Bilinear, InterpolateMode::Bilinear),
+        ] {
+            let upsample = UpsampleConfig::new()
+                .with_scale_factor(Some([2.0, 2.0]))
+                .with_mode(mode)
+                .init();
+
+            let output = upsample.forward(input.clone());
+            let expected = interpolate(input.clone(), [4, 4], interpolate_mode);
+
+            assert_eq!(output.dims(), [1, 1, 4, 4]);
+            output.to_data().assert_approx_eq(&expected.to_data(), 3);
+        }
+    }
+
+    #[test]
+    fn size_should_override_input_shape() {
+        let input = Tensor::<TestBackend, 4>::ones([2, 3, 2, 2]);
+        let upsample = UpsampleConfig::new().with_size(Some([3, 5])).init();
+
+        let output = upsample.forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 3, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_without_scale_factor_or_size() {
+        UpsampleConfig::new().init();
+    }
+}
@@ -0,0 +1,200 @@
+use burn_tensor::{backend::Backend, Data, ElementConversion, Shape, Tensor};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+/// Error that can occur when saving or loading a tensor as CSV.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The tensor doesn't have 2 dimensions or doesn't have any column.
+    InvalidShape(String),
+    /// A value can't be parsed as a float or a row doesn't have the expected number of columns.
+    InvalidContent(String),
+    /// Error while reading or writing the file.
+    IOError(std::io::Error),
+}
+
+impl core::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(format!("{self:?}").as_str())
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<std::io::Error> for CsvError {
+    fn from(error: std::io::Error) -> Self {
+        CsvError::IOError(error)
+    }
+}
+
+/// Save a `[rows, cols]` tensor as a CSV file, each value being written with the given number
+/// of decimals and separated by the given delimiter.
+///
+/// # Errors
+///
+/// If the tensor doesn't have 2 dimensions, if it doesn't have any column or if the file can't
+/// be written.
+pub fn save_csv<B, const D: usize, P>(
+    tensor: &Tensor<B, D>,
+    path: P,
+    delimiter: char,
+    precision: usize,
+) -> Result<(), CsvError>
+where
+    B: Backend,
+    P: AsRef<Path>,
+{
+    if D != 2 {
+        return Err(CsvError::InvalidShape(format!(
+            "Only 2D tensors can be saved as CSV, got a tensor of shape {:?}",
+            tensor.dims()
+        )));
+    }
+
+    let data = tensor.to_data();
+    let cols = data.shape.dims[1];
+
+    if cols == 0 {
+        return Err(CsvError::InvalidShape(format!(
+            "Tensors without columns can't be saved as CSV, got a tensor of shape {:?}",
+            tensor.dims()
+        )));
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    for row in data.value.chunks(cols) {
+        let line = row
+            .iter()
+            .map(|value| format!("{:.precision$}", value.elem::<f64>()))
+            .collect::<Vec<_>>()
+            .join(&delimiter.to_string());
+
+        writeln!(writer, "{line}")?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Load a `[rows, cols]` tensor from a CSV file where values are separated by the given
+/// delimiter.
+///
+/// # Errors
+///
+/// If the file can't be read, if a value isn't a float or if the rows don't all have the same
+/// number of columns.
+pub fn load_csv<B, P>(path: P, delimiter: char) -> Result<Tensor<B, 2>, CsvError>
+where
+    B: Backend,
+    P: AsRef<Path>,
+{
+    let reader = BufReader::new(File::open(path)?);
+    let mut values = Vec::new();
+    let mut rows = 0;
+    let mut cols = None;
+
+    for line in reader.lines() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut num_values = 0;
+        for item in line.split(delimiter) {
+            let value = item.trim().parse::<f64>().map_err(|err| {
+                CsvError::InvalidContent(format!(
+                    "Unable to parse '{item}' on row {rows} as a float: {err}"
+                ))
+            })?;
+
+            values.push(value.elem::<B::FloatElem>());
+            num_values += 1;
+        }
+
+        match cols {
+            None => cols = Some(num_values),
+            Some(cols) if cols != num_values => {
+                return Err(CsvError::InvalidContent(format!(
+                    "Row {rows} has {num_values} columns, but {cols} were expected"
+                )));
+            }
+            Some(_) => {}
+        }
+
+        rows += 1;
+    }
+
+    let shape = Shape::new([rows, cols.unwrap_or(0)]);
+
+    Ok(Tensor::from_data(Data::new(values, shape)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NdArrayBackend;
+
+    type TestTensor<const D: usize> = Tensor<NdArrayBackend<f32>, D>;
+
+    /// Unique path in the temporary directory, so that concurrent test runs don't collide.
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("burn_test_csv_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn should_round_trip_2d_tensor() {
+        let path = temp_path("round_trip.csv");
+        let tensor = TestTensor::from_floats([[1.0, -2.5, 3.25], [4.0, 5.125, -6.0]]);
+
+        save_csv(&tensor, &path, ',', 4).unwrap();
+        let loaded: TestTensor<2> = load_csv(&path, ',').unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.shape(), tensor.shape());
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_custom_delimiter() {
+        let path = temp_path("tab.tsv");
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        save_csv(&tensor, &path, '\t', 2).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        let loaded: TestTensor<2> = load_csv(&path, '\t').unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(content.lines().next(), Some("1.00\t2.00"));
+        loaded.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_error_with_non_2d_tensor() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+
+        let path = temp_path("1d.csv");
+
+        let result = save_csv(&tensor, &path, ',', 4);
+
+        assert!(matches!(result, Err(CsvError::InvalidShape(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn should_error_with_tensor_without_columns() {
+        let tensor = TestTensor::<2>::zeros([3, 0]);
+
+        let path = temp_path("no_columns.csv");
+
+        let result = save_csv(&tensor, &path, ',', 4);
+
+        assert!(matches!(result, Err(CsvError::InvalidShape(_))));
+        assert!(!path.exists());
+    }
+}
@@ -1,6 +1,6 @@
 use burn_tensor::Element;
-use libm::{exp, log, log1p, pow, sqrt};
-use libm::{expf, log1pf, logf, powf, sqrtf};
+use libm::{ceil, exp, fabs, floor, log, log10, log1p, log2, pow, rint, sqrt};
+use libm::{ceilf, expf, fabsf, floorf, log10f, log1pf, log2f, logf, powf, rintf, sqrtf};
 use ndarray::LinalgScalar;
 
 pub(crate) trait FloatNdArrayElement: NdArrayElement + LinalgScalar
@@ -25,9 +25,17 @@ pub(crate) trait ExpElement {
     fn exp_elem(self) -> Self;
     fn log_elem(self) -> Self;
     fn log1p_elem(self) -> Self;
+    fn log2_elem(self) -> Self;
+    fn log10_elem(self) -> Self;
     fn powf_elem(self, value: f32) -> Self;
     fn powi_elem(self, value: i32) -> Self;
+    fn pow_elem(self, exponent: Self) -> Self;
     fn sqrt_elem(self) -> Self;
+    fn abs_elem(self) -> Self;
+    fn floor_elem(self) -> Self;
+    fn ceil_elem(self) -> Self;
+    /// Rounds to the nearest integer, ties being rounded to the nearest even integer.
+    fn round_elem(self) -> Self;
 }
 
 impl FloatNdArrayElement for f64 {}
@@ -36,7 +44,8 @@ impl FloatNdArrayElement for f32 {}
 macro_rules! make_elem {
     (
         double
-        $ty:ty
+        $ty:ty,
+        abs $abs:expr
     ) => {
         impl NdArrayElement for $ty {}
 
@@ -56,6 +65,16 @@ macro_rules! make_elem {
                 log1p(self as f64) as $ty
             }
 
+            #[inline(always)]
+            fn log2_elem(self) -> Self {
+                log2(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn log10_elem(self) -> Self {
+                log10(self as f64) as $ty
+            }
+
             #[inline(always)]
             fn powf_elem(self, value: f32) -> Self {
                 pow(self as f64, value.into()) as $ty
@@ -72,15 +91,41 @@ macro_rules! make_elem {
                 val
             }
 
+            #[inline(always)]
+            fn pow_elem(self, exponent: Self) -> Self {
+                pow(self as f64, exponent as f64) as $ty
+            }
+
             #[inline(always)]
             fn sqrt_elem(self) -> Self {
                 sqrt(self as f64) as $ty
             }
+
+            #[inline(always)]
+            fn abs_elem(self) -> Self {
+                $abs(self)
+            }
+
+            #[inline(always)]
+            fn floor_elem(self) -> Self {
+                floor(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn ceil_elem(self) -> Self {
+                ceil(self as f64) as $ty
+            }
+
+            #[inline(always)]
+            fn round_elem(self) -> Self {
+                rint(self as f64) as $ty
+            }
         }
     };
     (
         single
-        $ty:ty
+        $ty:ty,
+        abs $abs:expr
     ) => {
         impl NdArrayElement for $ty {}
 
@@ -100,6 +145,16 @@ macro_rules! make_elem {
                 log1pf(self as f32) as $ty
             }
 
+            #[inline(always)]
+            fn log2_elem(self) -> Self {
+                log2f(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn log10_elem(self) -> Self {
+                log10f(self as f32) as $ty
+            }
+
             #[inline(always)]
             fn powf_elem(self, value: f32) -> Self {
                 powf(self as f32, value.into()) as $ty
@@ -116,18 +171,45 @@ macro_rules! make_elem {
                 val
             }
 
+            #[inline(always)]
+            fn pow_elem(self, exponent: Self) -> Self {
+                powf(self as f32, exponent as f32) as $ty
+            }
+
             #[inline(always)]
             fn sqrt_elem(self) -> Self {
                 sqrtf(self as f32) as $ty
             }
+
+            #[inline(always)]
+            fn abs_elem(self) -> Self {
+                $abs(self)
+            }
+
+            #[inline(always)]
+            fn floor_elem(self) -> Self {
+                floorf(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn ceil_elem(self) -> Self {
+                ceilf(self as f32) as $ty
+            }
+
+            #[inline(always)]
+            fn round_elem(self) -> Self {
+                rintf(self as f32) as $ty
+            }
         }
     };
 }
 
-make_elem!(double f64);
-make_elem!(double i64);
+// Integers are negated exactly instead of going through floats, the absolute value of the minimum
+// value wrapping to itself.
+make_elem!(double f64, abs fabs);
+make_elem!(double i64, abs i64::wrapping_abs);
 
-make_elem!(single f32);
-make_elem!(single i32);
-make_elem!(single i16);
-make_elem!(single u8);
+make_elem!(single f32, abs fabsf);
+make_elem!(single i32, abs i32::wrapping_abs);
+make_elem!(single i16, abs i16::wrapping_abs);
+make_elem!(single u8, abs |elem: u8| elem);
@@ -11,14 +11,20 @@ extern crate derive_new;
 extern crate blas_src;
 
 mod backend;
+#[cfg(feature = "std")]
+mod csv;
 mod element;
 mod ops;
 mod parallel;
 mod sharing;
+mod sparse;
 mod tensor;
 
 pub use backend::*;
+#[cfg(feature = "std")]
+pub use csv::*;
 pub(crate) use sharing::*;
+pub use sparse::*;
 pub(crate) use tensor::*;
 
 extern crate alloc;
@@ -14,4 +14,23 @@ impl<E: FloatNdArrayElement> ActivationOps<NdArrayBackend<E>> for NdArrayBackend
 
         NdArrayTensor::new(array)
     }
+
+    fn sigmoid<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let zero = 0.elem::<E>();
+        let one = 1.elem::<E>();
+        // Only the exponential of negative values is computed, which can't overflow.
+        let array = tensor
+            .array
+            .mapv_into(|elem| {
+                if elem >= zero {
+                    one / (one + (zero - elem).exp_elem())
+                } else {
+                    let exp = elem.exp_elem();
+                    exp / (one + exp)
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
 }
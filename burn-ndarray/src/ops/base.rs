@@ -1,3 +1,4 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use burn_tensor::Data;
 use burn_tensor::ElementConversion;
@@ -5,15 +6,18 @@ use core::cmp::Ordering;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
 use ndarray::Array2;
+use ndarray::ArrayView1;
 
 use burn_tensor::Shape;
 use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
+use ndarray::Slice;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
-use crate::ops::macros::{keepdim, mean_dim, sum_dim};
+use crate::ops::macros::{keepdim, mean_dim, prod_dim, sum_dim};
 use crate::{reshape, tensor::NdArrayTensor};
 
 pub struct NdArrayOps<E> {
@@ -63,6 +67,36 @@ where
         )
     }
 
+    pub fn squeeze<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D2> {
+        let dims = tensor.shape().dims;
+        assert_eq!(
+            dims[dim], 1,
+            "Can't squeeze dimension {dim} because its size is not 1"
+        );
+
+        let mut new_dims = [0; D2];
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim..].copy_from_slice(&dims[dim + 1..]);
+
+        Self::reshape(tensor, Shape::new(new_dims))
+    }
+
+    pub fn unsqueeze<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D2> {
+        let dims = tensor.shape().dims;
+
+        let mut new_dims = [1; D2];
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim + 1..].copy_from_slice(&dims[dim..]);
+
+        Self::reshape(tensor, Shape::new(new_dims))
+    }
+
     pub fn cat<const D: usize>(
         tensors: Vec<NdArrayTensor<E, D>>,
         dim: usize,
@@ -76,6 +110,14 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn repeat<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<E, D> {
+        Self::cat(vec![tensor; times], dim)
+    }
+
     fn to_slice_args<const D1: usize, const D2: usize>(
         indexes: [Range<usize>; D2],
     ) -> [SliceInfoElem; D1] {
@@ -164,6 +206,55 @@ where
         NdArrayTensor { array }
     }
 
+    pub fn powf_tensor<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let shape: Vec<usize> = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(lhs, rhs)| usize::max(*lhs, *rhs))
+            .collect();
+        let lhs = lhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The lhs tensor should be broadcastable");
+        let rhs = rhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The rhs tensor should be broadcastable");
+
+        let array = Zip::from(lhs)
+            .and(rhs)
+            .map_collect(|&lhs, &rhs| lhs.pow_elem(rhs))
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    /// Maps negative values to `-1`, positive values to `1`, and zeros and NaN to `0`.
+    pub fn sign<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let zero = 0.elem();
+        let one = 1.elem();
+        let minus_one = (-1).elem();
+        let array = tensor
+            .array
+            .mapv_into(|elem| {
+                if elem > zero {
+                    one
+                } else if elem < zero {
+                    minus_one
+                } else {
+                    zero
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     pub fn div_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
         let array = lhs.array / rhs;
         let array = array.into_shared();
@@ -181,6 +272,12 @@ where
         NdArrayTensor::from_data(data)
     }
 
+    pub fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        let one: E = 1.elem();
+        let data = Data::from([tensor.array.fold(one, |acc, elem| acc * *elem)]);
+        NdArrayTensor::from_data(data)
+    }
+
     pub fn mean_dim<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -208,6 +305,71 @@ where
         }
     }
 
+    pub fn prod_dim<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        match D {
+            1 => keepdim!(0, dim, tensor, prod),
+            2 => keepdim!(1, dim, tensor, prod),
+            3 => keepdim!(2, dim, tensor, prod),
+            4 => keepdim!(3, dim, tensor, prod),
+            5 => keepdim!(4, dim, tensor, prod),
+            6 => keepdim!(5, dim, tensor, prod),
+            _ => panic!("Dim not supported {D}"),
+        }
+    }
+
+    /// Computes the sample variance along the dimension, dividing by `N - 1`.
+    pub fn var<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let n = tensor.shape().dims[dim] - 1;
+        Self::var_with_n(tensor, dim, n)
+    }
+
+    /// Computes the population variance along the dimension, dividing by `N`.
+    pub fn var_bias<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let n = tensor.shape().dims[dim];
+        Self::var_with_n(tensor, dim, n)
+    }
+
+    pub fn std<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let array = Self::var(tensor, dim).array.mapv_into(|a| a.sqrt_elem());
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn std_bias<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        let array = Self::var_bias(tensor, dim)
+            .array
+            .mapv_into(|a| a.sqrt_elem());
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    fn var_with_n<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        n: usize,
+    ) -> NdArrayTensor<E, D> {
+        let mean = Self::mean_dim(tensor.clone(), dim);
+        let centered = Self::sub(tensor, mean);
+        let squared = centered.array.mapv_into(|a| a * a).into_shared();
+
+        Self::div_scalar(
+            Self::sum_dim(NdArrayTensor::new(squared), dim),
+            (n as i64).elem(),
+        )
+    }
+
+    /// Selects the elements at the given indexes along the dimension.
+    ///
+    /// Panics when an index is out of bounds.
     pub fn gather<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
@@ -245,6 +407,9 @@ where
         output
     }
 
+    /// Adds the values to the elements at the given indexes along the dimension.
+    ///
+    /// Panics when an index is out of bounds.
     pub fn scatter<const D: usize>(
         dim: usize,
         mut tensor: NdArrayTensor<E, D>,
@@ -298,17 +463,20 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        let mut array = tensor.array.into_owned();
 
-        NdArrayTensor::new(array)
+        // Values are replaced instead of being multiplied by zero, so that `NaN` and infinite
+        // values are scattered and masked as well.
+        Zip::from(&mut array)
+            .and_broadcast(&mask.array)
+            .and_broadcast(&source.array)
+            .for_each(|elem, &mask, &source| {
+                if mask {
+                    *elem = source;
+                }
+            });
+
+        NdArrayTensor::new(array.into_shared())
     }
 
     pub fn mask_fill<const D: usize>(
@@ -316,17 +484,19 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul) + mask_add;
+        let mut array = tensor.array.into_owned();
 
-        NdArrayTensor::new(array)
+        // Values are replaced instead of being multiplied by zero, so that `NaN` and infinite
+        // values are masked as well.
+        Zip::from(&mut array)
+            .and_broadcast(&mask.array)
+            .for_each(|elem, &mask| {
+                if mask {
+                    *elem = value;
+                }
+            });
+
+        NdArrayTensor::new(array.into_shared())
     }
 
     fn gather_batch_size<const D: usize>(
@@ -383,14 +553,79 @@ where
         tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<i64, D> {
-        arg(tensor, dim, cmp_min)
+        arg(tensor, dim, cmp_max)
     }
 
     pub fn argmin<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
     ) -> NdArrayTensor<i64, D> {
-        arg(tensor, dim, cmp_max)
+        arg(tensor, dim, cmp_min)
+    }
+
+    pub fn max_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        extreme(tensor, dim, cmp_max)
+    }
+
+    pub fn min_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        extreme(tensor, dim, cmp_min)
+    }
+
+    pub fn max_dim_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        extreme_with_indexes(tensor, dim, cmp_max)
+    }
+
+    pub fn min_dim_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        extreme_with_indexes(tensor, dim, cmp_min)
+    }
+
+    pub fn sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<E, D> {
+        Self::sort_with_indexes(tensor, dim, descending).0
+    }
+
+    pub fn argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        Self::sort_with_indexes(tensor, dim, descending).1
+    }
+
+    pub fn sort_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let cmp = match descending {
+            true => cmp_max::<E>,
+            false => cmp_min::<E>,
+        };
+
+        sort_lanes(tensor, dim, |a, b| cmp_nan_last(a, b, cmp))
+    }
+
+    pub fn topk<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        let (values, indexes) = sort_lanes(tensor, dim, |a, b| cmp_nan_last(a, b, cmp_max));
+        let slice = Slice::from(0..k);
+
+        let values = values.array.slice_axis(Axis(dim), slice).to_shared();
+        let indexes = indexes.array.slice_axis(Axis(dim), slice).to_shared();
+
+        (NdArrayTensor::new(values), NdArrayTensor::new(indexes))
     }
 }
 
@@ -400,54 +635,138 @@ fn arg<E: NdArrayElement, F, const D: usize>(
     cmp: F,
 ) -> NdArrayTensor<i64, D>
 where
-    F: Fn(&f64, &f64) -> Ordering,
+    F: Fn(&E, &E) -> Ordering,
+{
+    let array = tensor
+        .array
+        .map_axis(Axis(dim), |lane| arg_lane(&lane, &cmp) as i64)
+        .insert_axis(Axis(dim));
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+fn extreme<E: NdArrayElement, F, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+    cmp: F,
+) -> NdArrayTensor<E, D>
+where
+    F: Fn(&E, &E) -> Ordering,
 {
-    let mut shape = tensor.shape();
-    let batch_size = shape.dims[dim];
-    let mut end = shape.dims[dim];
-
-    let mut values = tensor.array.into_iter().collect::<Vec<_>>();
-    let mut start = 0;
-    let mut output = Vec::new();
-
-    while end <= values.len() {
-        let data_dim = &mut values[start..end];
-        let mut sorted: Vec<f64> = data_dim.iter().map(|a| a.elem()).collect();
-        sorted.sort_by(&cmp);
-
-        let max = sorted[0];
-
-        let data_dim = &mut values[start..end];
-        let mut index: i64 = 0;
-        for elem in data_dim {
-            let as_float: f64 = elem.elem();
-            if as_float == max {
-                break;
+    let array = tensor
+        .array
+        .map_axis(Axis(dim), |lane| lane[arg_lane(&lane, &cmp)])
+        .insert_axis(Axis(dim));
+
+    NdArrayTensor::new(array.into_shared())
+}
+
+/// Computes the target values and their indexes with a single pass over each lane.
+fn extreme_with_indexes<E: NdArrayElement, F, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+    cmp: F,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>)
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    let targets = tensor.array.map_axis(Axis(dim), |lane| {
+        let index = arg_lane(&lane, &cmp);
+        (lane[index], index as i64)
+    });
+    let values = targets.map(|(value, _)| *value).insert_axis(Axis(dim));
+    let indexes = targets.map(|(_, index)| *index).insert_axis(Axis(dim));
+
+    (
+        NdArrayTensor::new(values.into_shared()),
+        NdArrayTensor::new(indexes.into_shared()),
+    )
+}
+
+/// Sorts every lane along the dimension and keeps track of the original indexes.
+///
+/// The sort is stable, so equal elements keep their original order, matching the first
+/// occurrence convention of [arg_lane].
+fn sort_lanes<E: NdArrayElement, F, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+    cmp: F,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>)
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    let mut values = tensor.array.into_owned();
+    let mut indexes = values.map(|_| 0_i64);
+
+    Zip::from(values.lanes_mut(Axis(dim)))
+        .and(indexes.lanes_mut(Axis(dim)))
+        .for_each(|mut lane, mut lane_indexes| {
+            let mut order: Vec<usize> = (0..lane.len()).collect();
+            order.sort_by(|a, b| cmp(&lane[*a], &lane[*b]));
+
+            let sorted: Vec<E> = order.iter().map(|index| lane[*index]).collect();
+
+            for (position, (value, index)) in sorted.into_iter().zip(order).enumerate() {
+                lane[position] = value;
+                lane_indexes[position] = index as i64;
             }
-            index += 1;
+        });
+
+    (
+        NdArrayTensor::new(values.into_shared()),
+        NdArrayTensor::new(indexes.into_shared()),
+    )
+}
+
+/// Returns the index of the target element of the lane in a single pass.
+///
+/// The target is only replaced when an element is strictly ordered before it, so the first
+/// occurrence is kept on ties.
+fn arg_lane<E, F>(lane: &ArrayView1<E>, cmp: &F) -> usize
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    let mut target = &lane[0];
+    let mut target_index = 0;
+
+    for (index, elem) in lane.iter().enumerate().skip(1) {
+        if cmp(elem, target) == Ordering::Less {
+            target = elem;
+            target_index = index;
         }
-        output.push(index);
-        start += batch_size;
-        end += batch_size;
     }
-    shape.dims[dim] = 1;
-    NdArrayTensor::from_data(Data::new(output, shape))
+
+    target_index
 }
 
-fn cmp_max(a: &f64, b: &f64) -> Ordering {
-    if a < b {
+/// Orders the greater values first, so that the maximum is the target.
+fn cmp_max<E: PartialOrd>(a: &E, b: &E) -> Ordering {
+    if a > b {
         return Ordering::Less;
-    } else if a > b {
+    } else if a < b {
         return Ordering::Greater;
     }
     Ordering::Equal
 }
 
-fn cmp_min(a: &f64, b: &f64) -> Ordering {
-    if a > b {
+/// Orders the lower values first, so that the minimum is the target.
+fn cmp_min<E: PartialOrd>(a: &E, b: &E) -> Ordering {
+    if a < b {
         return Ordering::Less;
-    } else if a < b {
+    } else if a > b {
         return Ordering::Greater;
     }
     Ordering::Equal
 }
+
+/// Orders the `NaN` values last and the other values with the given comparison, which is a total
+/// order suited for sorting.
+fn cmp_nan_last<E: PartialOrd>(a: &E, b: &E, cmp: fn(&E, &E) -> Ordering) -> Ordering {
+    // `NaN` is the only value that can't be compared with itself.
+    match (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => cmp(a, b),
+    }
+}
@@ -57,6 +57,20 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         NdArrayOps::reshape(tensor, shape)
     }
 
+    fn bool_squeeze<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<bool, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<bool, D2> {
+        NdArrayOps::squeeze(tensor, dim)
+    }
+
+    fn bool_unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<bool, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<bool, D2> {
+        NdArrayOps::unsqueeze(tensor, dim)
+    }
+
     fn bool_index<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<bool, D1>,
         indexes: [Range<usize>; D2],
@@ -100,6 +114,14 @@ impl<E: FloatNdArrayElement> BoolTensorOps<NdArrayBackend<E>> for NdArrayBackend
         NdArrayOps::cat(tensors, dim)
     }
 
+    fn bool_repeat<const D: usize>(
+        tensor: NdArrayTensor<bool, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<bool, D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
+
     fn bool_equal<const D: usize>(
         lhs: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
         rhs: <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
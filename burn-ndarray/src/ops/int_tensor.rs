@@ -5,7 +5,7 @@ use burn_tensor::ops::IntTensorOps;
 use core::ops::Range;
 
 // Current crate
-use crate::element::FloatNdArrayElement;
+use crate::element::{ExpElement, FloatNdArrayElement};
 use crate::NdArrayDevice;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
 
@@ -51,6 +51,20 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayOps::reshape(tensor, shape)
     }
 
+    fn int_squeeze<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<i64, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D2> {
+        NdArrayOps::squeeze(tensor, dim)
+    }
+
+    fn int_unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<i64, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D2> {
+        NdArrayOps::unsqueeze(tensor, dim)
+    }
+
     fn int_index<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<i64, D1>,
         indexes: [Range<usize>; D2],
@@ -103,6 +117,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayOps::cat(tensors, dim)
     }
 
+    fn int_repeat<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
+
     fn int_equal<const D: usize>(
         lhs: NdArrayTensor<i64, D>,
         rhs: NdArrayTensor<i64, D>,
@@ -244,6 +266,16 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         Self::int_mul_scalar(tensor, -1)
     }
 
+    fn int_abs<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        let array = tensor.array.mapv_into(|a| a.abs_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn int_sign<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::sign(tensor)
+    }
+
     fn int_zeros<const D: usize>(
         shape: Shape<D>,
         device: &<NdArrayBackend<E> as Backend>::Device,
@@ -269,6 +301,17 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn int_prod<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
+        NdArrayMathOps::prod(tensor)
+    }
+
+    fn int_prod_dim<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::prod_dim(tensor, dim)
+    }
+
     fn int_mean<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
         NdArrayMathOps::mean(tensor)
     }
@@ -326,4 +369,36 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::argmin(tensor, dim)
     }
+
+    fn int_argsort<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::argsort(tensor, dim, descending)
+    }
+
+    fn int_sort<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::sort(tensor, dim, descending)
+    }
+
+    fn int_sort_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indexes(tensor, dim, descending)
+    }
+
+    fn int_topk<const D: usize>(
+        tensor: NdArrayTensor<i64, D>,
+        k: usize,
+        dim: usize,
+    ) -> (NdArrayTensor<i64, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::topk(tensor, k, dim)
+    }
 }
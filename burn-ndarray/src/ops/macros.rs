@@ -21,9 +21,22 @@ macro_rules! keepdim {
         shape.dims[$dim] = 1;
         NdArrayOps::reshape(tensor, shape)
     }};
+    (
+        $D:expr,
+        $dim:expr,
+        $self:expr,
+        prod
+    ) => {{
+        let tensor: NdArrayTensor<E, $D> = prod_dim($self.clone(), $dim);
+        let mut shape = $self.shape();
+        shape.dims[$dim] = 1;
+        NdArrayOps::reshape(tensor, shape)
+    }};
 }
 
 pub(crate) use keepdim;
+
+use burn_tensor::ElementConversion;
 use ndarray::Axis;
 
 use crate::{element::NdArrayElement, tensor::NdArrayTensor};
@@ -45,3 +58,16 @@ pub(crate) fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
 
     NdArrayTensor { array }
 }
+
+pub(crate) fn prod_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
+    tensor: NdArrayTensor<E, D1>,
+    dim: usize,
+) -> NdArrayTensor<E, D2> {
+    let one: E = 1.elem();
+    let array = tensor
+        .array
+        .fold_axis(Axis(dim), one, |acc, elem| *acc * *elem)
+        .into_shared();
+
+    NdArrayTensor { array }
+}
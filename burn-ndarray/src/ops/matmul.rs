@@ -8,6 +8,20 @@ pub(crate) fn matmul<E, const D: usize>(
     lhs: NdArrayTensor<E, D>,
     rhs: NdArrayTensor<E, D>,
 ) -> NdArrayTensor<E, D>
+where
+    E: FloatNdArrayElement,
+{
+    matmul_transposed(lhs, rhs, false, false)
+}
+
+/// Matrix multiplication where each operand can be read as transposed, using a transposed
+/// view of its matrices instead of copying them.
+pub(crate) fn matmul_transposed<E, const D: usize>(
+    lhs: NdArrayTensor<E, D>,
+    rhs: NdArrayTensor<E, D>,
+    transpose_lhs: bool,
+    transpose_rhs: bool,
+) -> NdArrayTensor<E, D>
 where
     E: FloatNdArrayElement,
 {
@@ -17,28 +31,40 @@ where
     let lhs = reshape(lhs);
     let rhs = reshape(rhs);
 
-    let [batch_size_lhs, m, _] = lhs.shape().dims;
-    let [batch_size_rhs, _, n] = rhs.shape().dims;
+    let [batch_size_lhs, _, _] = lhs.shape().dims;
+    let [batch_size_rhs, _, _] = rhs.shape().dims;
 
     let mut shape_out = match batch_size_lhs > batch_size_rhs {
         true => shape_ori_lhs,
         false => shape_ori_rhs,
     };
+
+    let out = general_matmul(lhs, rhs, transpose_lhs, transpose_rhs);
+    let [_, m, n] = out.shape().dims;
+
     shape_out.dims[D - 2] = m;
     shape_out.dims[D - 1] = n;
 
-    let out = general_matmul(lhs, rhs);
-
     NdArrayBackend::<E>::reshape(out, shape_out)
 }
 
 fn general_matmul<E: FloatNdArrayElement>(
     lhs: NdArrayTensor<E, 3>,
     rhs: NdArrayTensor<E, 3>,
+    transpose_lhs: bool,
+    transpose_rhs: bool,
 ) -> NdArrayTensor<E, 3> {
     run_par!(|| {
-        let [batch_size_lhs, m, _] = lhs.shape().dims;
-        let [batch_size_rhs, k, n] = rhs.shape().dims;
+        let [batch_size_lhs, rows_lhs, cols_lhs] = lhs.shape().dims;
+        let [batch_size_rhs, rows_rhs, cols_rhs] = rhs.shape().dims;
+        let m = match transpose_lhs {
+            true => cols_lhs,
+            false => rows_lhs,
+        };
+        let n = match transpose_rhs {
+            true => rows_rhs,
+            false => cols_rhs,
+        };
         let batch_size = usize::max(batch_size_rhs, batch_size_lhs);
 
         if batch_size_lhs > batch_size && batch_size_lhs != 1 {
@@ -55,8 +81,14 @@ fn general_matmul<E: FloatNdArrayElement>(
         let mut out_array = ndarray::Array3::<E>::zeros((batch_size, m, n));
         let unsafe_shared_out_array = UnsafeSharedRef::new(&mut out_array);
 
-        let lhs_array = lhs.array.into_shape((batch_size_lhs, m, k)).unwrap();
-        let rhs_array = rhs.array.into_shape((batch_size_rhs, k, n)).unwrap();
+        let lhs_array = lhs
+            .array
+            .into_shape((batch_size_lhs, rows_lhs, cols_lhs))
+            .unwrap();
+        let rhs_array = rhs
+            .array
+            .into_shape((batch_size_rhs, rows_rhs, cols_rhs))
+            .unwrap();
 
         iter_par!(0, batch_size).for_each(|b| {
             let lhs_slice = match batch_size_lhs == 1 {
@@ -67,6 +99,14 @@ fn general_matmul<E: FloatNdArrayElement>(
                 true => rhs_array.slice(s!(0, .., ..)),
                 false => rhs_array.slice(s!(b, .., ..)),
             };
+            let lhs_slice = match transpose_lhs {
+                true => lhs_slice.reversed_axes(),
+                false => lhs_slice,
+            };
+            let rhs_slice = match transpose_rhs {
+                true => rhs_slice.reversed_axes(),
+                false => rhs_slice,
+            };
 
             unsafe {
                 let mut out_slice = unsafe_shared_out_array.get().slice_mut(s!(b, .., ..));
@@ -3,18 +3,23 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+use super::{
+    matmul::{matmul, matmul_transposed},
+    NdArrayMathOps, NdArrayOps,
+};
 use crate::element::FloatNdArrayElement;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
 use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
+use burn_tensor::ops::{AdamUpdateOptions, RmsPropUpdateOptions, SgdMomentumUpdateOptions};
 use burn_tensor::Distribution;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{acos, asin, atan, cos, cosh, erf, powf, sin, sinh, tanh};
+use ndarray::{Axis, Zip};
 
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
@@ -128,10 +133,77 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         matmul(lhs, rhs)
     }
 
+    fn matmul_transpose_a<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        matmul_transposed(lhs, rhs, true, false)
+    }
+
+    fn matmul_transpose_b<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        matmul_transposed(lhs, rhs, false, true)
+    }
+
     fn neg<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         Self::mul_scalar(tensor, (-1f32).elem::<E>())
     }
 
+    fn abs<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.abs_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn sign<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::sign(tensor)
+    }
+
+    fn clamp_min<const D: usize>(tensor: NdArrayTensor<E, D>, min: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|elem| match elem < min {
+                true => min,
+                false => elem,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp_max<const D: usize>(tensor: NdArrayTensor<E, D>, max: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|elem| match elem > max {
+                true => max,
+                false => elem,
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn clamp<const D: usize>(tensor: NdArrayTensor<E, D>, min: E, max: E) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|elem| {
+                // The upper bound is applied first, to match `clamp_min(clamp_max(x))`.
+                let elem = match elem > max {
+                    true => max,
+                    false => elem,
+                };
+                match elem < min {
+                    true => min,
+                    false => elem,
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn swap_dims<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim1: usize,
@@ -143,6 +215,24 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn permute<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor.array.permuted_axes(axes.as_slice());
+
+        NdArrayTensor::new(array)
+    }
+
+    fn flip<const D: usize>(tensor: NdArrayTensor<E, D>, dims: &[usize]) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array;
+        for dim in dims {
+            array.invert_axis(Axis(*dim));
+        }
+
+        NdArrayTensor::new(array)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -150,6 +240,34 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayOps::reshape(tensor, shape)
     }
 
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D2> {
+        NdArrayOps::squeeze(tensor, dim)
+    }
+
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D2> {
+        NdArrayOps::unsqueeze(tensor, dim)
+    }
+
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D1>,
+        shape: Shape<D2>,
+    ) -> NdArrayTensor<E, D2> {
+        let array = tensor
+            .array
+            .broadcast(shape.dims.as_slice())
+            .expect("The tensor should be broadcastable to the shape")
+            .to_owned()
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn gather<const D: usize>(
         dim: usize,
         tensor: NdArrayTensor<E, D>,
@@ -317,6 +435,30 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn var<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::var(tensor, dim)
+    }
+
+    fn var_bias<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::var_bias(tensor, dim)
+    }
+
+    fn std<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::std(tensor, dim)
+    }
+
+    fn std_bias<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::std_bias(tensor, dim)
+    }
+
+    fn prod<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        NdArrayMathOps::prod(tensor)
+    }
+
+    fn prod_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::prod_dim(tensor, dim)
+    }
+
     fn to_full_precision<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<f32, D> {
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
 
@@ -337,6 +479,60 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayMathOps::argmin(tensor, dim)
     }
 
+    fn max_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::max_dim(tensor, dim)
+    }
+
+    fn min_dim<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::min_dim(tensor, dim)
+    }
+
+    fn max_dim_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::max_dim_with_indexes(tensor, dim)
+    }
+
+    fn min_dim_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::min_dim_with_indexes(tensor, dim)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::argsort(tensor, dim, descending)
+    }
+
+    fn sort<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indexes<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        descending: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::sort_with_indexes(tensor, dim, descending)
+    }
+
+    fn topk<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        k: usize,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<i64, D>) {
+        NdArrayMathOps::topk(tensor, k, dim)
+    }
+
     fn exp<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor.array.mapv_into(|a| a.exp_elem()).into_shared();
 
@@ -355,6 +551,18 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn log2<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.log2_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn log10<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.log10_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn powf<const D: usize>(tensor: NdArrayTensor<E, D>, value: f32) -> NdArrayTensor<E, D> {
         let array = if value == 2.0 {
             // Happens often and is faster.
@@ -373,12 +581,43 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn powf_tensor<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::powf_tensor(lhs, rhs)
+    }
+
     fn sqrt<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor.array.mapv_into(|a| a.sqrt_elem()).into_shared();
 
         NdArrayTensor::new(array)
     }
 
+    fn recip<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| 1.elem::<E>() / a).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn floor<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.floor_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn ceil<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.ceil_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn round<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.mapv_into(|a| a.round_elem()).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn cos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor
             .array
@@ -406,6 +645,51 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn atan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| atan(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn asin<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| asin(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn acos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| acos(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn sinh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| sinh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn cosh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| cosh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn erf<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor
             .array
@@ -418,4 +702,115 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
+
+    fn repeat<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+        times: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayOps::repeat(tensor, dim, times)
+    }
+
+    fn adam_update<const D: usize>(
+        param: NdArrayTensor<E, D>,
+        grad: NdArrayTensor<E, D>,
+        moment_1: NdArrayTensor<E, D>,
+        moment_2: NdArrayTensor<E, D>,
+        options: &AdamUpdateOptions,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D>,
+    ) {
+        let time = options.time as f32;
+        let beta_1: E = options.beta_1.elem();
+        let beta_2: E = options.beta_2.elem();
+        let factor_1: E = (1.0 - options.beta_1).elem();
+        let factor_2: E = (1.0 - options.beta_2).elem();
+        let correction_1: E = (1.0 - powf(options.beta_1, time)).elem();
+        let correction_2: E = (1.0 - powf(options.beta_2, time)).elem();
+        let epsilon: E = options.epsilon.elem();
+        let lr: E = options.lr.elem();
+
+        let mut param = param.array;
+        let mut moment_1 = moment_1.array;
+        let mut moment_2 = moment_2.array;
+
+        Zip::from(&mut param)
+            .and(&mut moment_1)
+            .and(&mut moment_2)
+            .and(&grad.array)
+            .for_each(|param, moment_1, moment_2, &grad| {
+                *moment_1 = beta_1 * *moment_1 + factor_1 * grad;
+                *moment_2 = beta_2 * *moment_2 + factor_2 * grad * grad;
+
+                let moment_1_corrected = *moment_1 / correction_1;
+                let moment_2_corrected = *moment_2 / correction_2;
+
+                *param =
+                    *param - lr * moment_1_corrected / (moment_2_corrected.sqrt_elem() + epsilon);
+            });
+
+        (
+            NdArrayTensor::new(param),
+            NdArrayTensor::new(moment_1),
+            NdArrayTensor::new(moment_2),
+        )
+    }
+
+    fn rmsprop_update<const D: usize>(
+        param: NdArrayTensor<E, D>,
+        grad: NdArrayTensor<E, D>,
+        square_avg: NdArrayTensor<E, D>,
+        options: &RmsPropUpdateOptions,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let alpha: E = options.alpha.elem();
+        let factor: E = (1.0 - options.alpha).elem();
+        let epsilon: E = options.epsilon.elem();
+        let lr: E = options.lr.elem();
+
+        let mut param = param.array;
+        let mut square_avg = square_avg.array;
+
+        Zip::from(&mut param)
+            .and(&mut square_avg)
+            .and(&grad.array)
+            .for_each(|param, square_avg, &grad| {
+                *square_avg = alpha * *square_avg + factor * grad * grad;
+                *param = *param - lr * grad / (square_avg.sqrt_elem() + epsilon);
+            });
+
+        (NdArrayTensor::new(param), NdArrayTensor::new(square_avg))
+    }
+
+    fn sgd_momentum_update<const D: usize>(
+        param: NdArrayTensor<E, D>,
+        grad: NdArrayTensor<E, D>,
+        velocity: NdArrayTensor<E, D>,
+        options: &SgdMomentumUpdateOptions,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let decay: E = (1.0 - options.lr * options.weight_decay).elem();
+        let momentum: E = options.momentum.elem();
+        let lr: E = options.lr.elem();
+        let nesterov = options.nesterov;
+
+        let mut param = param.array;
+        let mut velocity = velocity.array;
+
+        Zip::from(&mut param)
+            .and(&mut velocity)
+            .and(&grad.array)
+            .for_each(|param, velocity, &grad| {
+                *velocity = momentum * *velocity + grad;
+
+                let update = match nesterov {
+                    true => grad + momentum * *velocity,
+                    false => *velocity,
+                };
+
+                *param = decay * *param - lr * update;
+            });
+
+        (NdArrayTensor::new(param), NdArrayTensor::new(velocity))
+    }
 }
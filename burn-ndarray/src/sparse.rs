@@ -0,0 +1,250 @@
+use burn_tensor::{backend::Backend, ElementConversion, Int, Shape, Tensor};
+
+/// A sparse `[rows, cols]` matrix in the coordinate (COO) format, only storing the indices and
+/// values of its non-zero elements.
+///
+/// Only the public tensor API is used, so the matrix works with any backend. It lives in this
+/// crate until sparse tensors get first-class support in `burn-tensor`.
+#[derive(Debug, Clone)]
+pub struct SparseTensor<B: Backend> {
+    rows: Tensor<B, 1, Int>,
+    cols: Tensor<B, 1, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape<2>,
+}
+
+impl<B: Backend> SparseTensor<B> {
+    /// Create a sparse matrix from the `[row, col]` indices of its non-zero elements and their
+    /// values. Values sharing the same index are summed.
+    ///
+    /// # Shapes
+    ///
+    /// - indices: [num_nonzeros, 2]
+    /// - values: [num_nonzeros]
+    ///
+    /// # Panics
+    ///
+    /// If the number of indices and values differ or if an index is out of bounds.
+    pub fn new<S: Into<Shape<2>>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        let shape = shape.into();
+        let [num_nonzeros, num_coordinates] = indices.dims();
+        let [num_values] = values.dims();
+
+        assert_eq!(
+            num_coordinates, 2,
+            "Indices should have the shape [num_nonzeros, 2]"
+        );
+        assert_eq!(
+            num_nonzeros, num_values,
+            "Got {num_nonzeros} indices for {num_values} values"
+        );
+
+        let data = indices.to_data();
+        for index in data.value.chunks(2) {
+            let row = index[0].elem::<i64>();
+            let col = index[1].elem::<i64>();
+
+            assert!(
+                row >= 0 && col >= 0 && row < shape.dims[0] as i64 && col < shape.dims[1] as i64,
+                "Index [{row}, {col}] is out of bounds for a sparse matrix of shape {:?}",
+                shape.dims
+            );
+        }
+
+        if num_nonzeros == 0 {
+            let device = values.device();
+
+            return Self {
+                rows: Tensor::empty_device([0], &device),
+                cols: Tensor::empty_device([0], &device),
+                values,
+                shape,
+            };
+        }
+
+        let rows = indices
+            .clone()
+            .index([0..num_nonzeros, 0..1])
+            .reshape([num_nonzeros]);
+        let cols = indices
+            .index([0..num_nonzeros, 1..2])
+            .reshape([num_nonzeros]);
+
+        Self {
+            rows,
+            cols,
+            values,
+            shape,
+        }
+    }
+
+    /// The shape of the sparse matrix.
+    pub fn shape(&self) -> Shape<2> {
+        self.shape.clone()
+    }
+
+    /// The number of stored elements.
+    pub fn num_nonzeros(&self) -> usize {
+        let [num_nonzeros] = self.values.dims();
+        num_nonzeros
+    }
+
+    /// Materialize the sparse matrix as a dense tensor.
+    pub fn to_dense(&self) -> Tensor<B, 2> {
+        let [num_rows, num_cols] = self.shape.dims;
+
+        if self.num_nonzeros() == 0 {
+            return Tensor::zeros_device([num_rows, num_cols], &self.values.device());
+        }
+
+        let indexes = self
+            .rows
+            .clone()
+            .mul_scalar(num_cols as i64)
+            .add(self.cols.clone());
+
+        Tensor::zeros_device([num_rows * num_cols], &self.values.device())
+            .index_select_assign(0, indexes, self.values.clone())
+            .reshape([num_rows, num_cols])
+    }
+}
+
+/// Multiply a sparse matrix with a dense one, without materializing the sparse matrix.
+///
+/// Each non-zero element `sparse[i, j]` adds `sparse[i, j] * dense[j, ..]` to the row `i` of the
+/// output, so the cost grows with the number of non-zero elements instead of the size of the
+/// sparse matrix.
+///
+/// # Shapes
+///
+/// - sparse: [m, k]
+/// - dense: [k, n]
+/// - output: [m, n]
+///
+/// # Panics
+///
+/// If the number of columns of the sparse matrix isn't the number of rows of the dense one.
+pub fn sparse_matmul<B: Backend>(sparse: &SparseTensor<B>, dense: Tensor<B, 2>) -> Tensor<B, 2> {
+    let [num_rows, inner] = sparse.shape.dims;
+    let [inner_dense, num_cols] = dense.dims();
+
+    assert_eq!(
+        inner,
+        inner_dense,
+        "Can't multiply a sparse matrix of shape {:?} with a dense matrix of shape {:?}",
+        sparse.shape.dims,
+        [inner_dense, num_cols]
+    );
+
+    let device = dense.device();
+
+    if sparse.num_nonzeros() == 0 {
+        return Tensor::zeros_device([num_rows, num_cols], &device);
+    }
+
+    let contributions = dense
+        .index_select(0, sparse.cols.clone())
+        .mul(sparse.values.clone().reshape([sparse.num_nonzeros(), 1]));
+
+    Tensor::zeros_device([num_rows, num_cols], &device).index_select_assign(
+        0,
+        sparse.rows.clone(),
+        contributions,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NdArrayBackend;
+    use burn_tensor::Data;
+
+    type TestBackend = NdArrayBackend<f32>;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+    type TestTensorInt<const D: usize> = Tensor<TestBackend, D, Int>;
+
+    fn sparse() -> SparseTensor<TestBackend> {
+        let indices = TestTensorInt::from_data(Data::from([[0, 1], [1, 0], [1, 3], [2, 2]]));
+        let values = TestTensor::from_data(Data::from([2.0, -1.0, 3.0, 0.5]));
+
+        SparseTensor::new(indices, values, [3, 4])
+    }
+
+    #[test]
+    fn should_convert_to_dense() {
+        let dense = sparse().to_dense();
+
+        dense.into_data().assert_approx_eq(
+            &Data::from([
+                [0.0, 2.0, 0.0, 0.0],
+                [-1.0, 0.0, 0.0, 3.0],
+                [0.0, 0.0, 0.5, 0.0],
+            ]),
+            5,
+        );
+    }
+
+    #[test]
+    fn should_match_dense_matmul() {
+        let sparse = sparse();
+        let dense = TestTensor::from_data(Data::from([
+            [1.0, 2.0],
+            [-3.0, 0.5],
+            [4.0, 1.0],
+            [0.0, -2.0],
+        ]));
+
+        let output = sparse_matmul(&sparse, dense.clone());
+        let expected = sparse.to_dense().matmul(dense);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn should_sum_duplicated_indices() {
+        let indices = TestTensorInt::from_data(Data::from([[0, 0], [0, 0], [1, 1]]));
+        let values = TestTensor::from_data(Data::from([1.0, 2.0, 4.0]));
+        let sparse = SparseTensor::new(indices, values, [2, 2]);
+        let dense = TestTensor::from_data(Data::from([[1.0, 1.0], [0.5, -1.0]]));
+
+        let output = sparse_matmul(&sparse, dense);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[3.0, 3.0], [2.0, -4.0]]), 5);
+    }
+
+    #[test]
+    fn should_support_empty_sparse_matrix() {
+        let indices = TestTensorInt::empty([0, 2]);
+        let values = TestTensor::empty([0]);
+        let sparse = SparseTensor::new(indices, values, [3, 4]);
+        let dense = TestTensor::ones([4, 2]);
+
+        let output = sparse_matmul(&sparse, dense);
+
+        assert_eq!(sparse.num_nonzeros(), 0);
+        sparse
+            .to_dense()
+            .into_data()
+            .assert_approx_eq(&TestTensor::zeros([3, 4]).into_data(), 5);
+        output
+            .into_data()
+            .assert_approx_eq(&TestTensor::zeros([3, 2]).into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_an_index_is_out_of_bounds() {
+        let indices = TestTensorInt::from_data(Data::from([[0, 4]]));
+        let values = TestTensor::from_data(Data::from([1.0]));
+
+        SparseTensor::new(indices, values, [3, 4]);
+    }
+}
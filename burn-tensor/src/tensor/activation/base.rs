@@ -1,7 +1,6 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
 use crate::{check, Tensor};
-use crate::{ElementPrecision, Precision};
 
 /// Applies the rectified linear unit function.
 pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
@@ -50,17 +49,15 @@ pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize)
 
 /// Applies the sigmoid function.
 pub fn sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
-    log_sigmoid(tensor).exp()
+    Tensor::from_primitive(B::sigmoid(tensor.primitive))
 }
 
 /// Applies the log sigmoid function.
+///
+/// `log_sigmoid(x) = log(sigmoid(x)) = -softplus(-x)`
+///
+/// The computation is numerically stable, so large positive inputs give `0` and large negative
+/// inputs give `x` without overflowing.
 pub fn log_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
-    match B::FloatElem::precision() {
-        Precision::Half => {
-            let tensor_full = tensor.to_full_precision();
-            let tensor_tmp = tensor_full.neg().exp().add_scalar(1.0_f32).log().neg();
-            Tensor::from_full_precision(tensor_tmp)
-        }
-        _ => tensor.neg().exp().add_scalar(1.0_f32).log().neg(),
-    }
+    Tensor::from_primitive(B::log_sigmoid(tensor.primitive))
 }
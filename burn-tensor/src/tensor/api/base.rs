@@ -56,6 +56,57 @@ where
         Tensor::new(K::reshape::<D, D2>(self.primitive, shape))
     }
 
+    /// Reshape the tensor to have the given shape specification, where one dimension can be
+    /// inferred from the number of elements of the tensor.
+    ///
+    /// Each value of the specification is interpreted as follows:
+    ///
+    /// - `-1`: the size of the dimension is inferred from the remaining dimensions, at most one
+    ///   dimension can be inferred.
+    /// - `0`: the size of the dimension is copied from the same dimension of the current tensor.
+    /// - Any positive value is used as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 4]));
+    ///
+    ///     // The first dimension is inferred as 6.
+    ///     let reshaped: Tensor::<B, 2> = tensor.reshape_infer([-1, 4]);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If more than one dimension is inferred, if a value is lower than `-1`, if a copied
+    /// dimension doesn't exist in the current tensor or if the number of elements can't be
+    /// divided by the other dimensions.
+    pub fn reshape_infer<const D2: usize>(self, spec: [i64; D2]) -> Tensor<B, D2, K> {
+        let original = self.shape();
+        check!(TensorCheck::reshape_infer(&original, &spec));
+
+        let mut dims = [1; D2];
+        let mut inferred = None;
+
+        for (i, value) in spec.iter().enumerate() {
+            match *value {
+                -1 => inferred = Some(i),
+                0 => dims[i] = original.dims[i],
+                _ => dims[i] = *value as usize,
+            }
+        }
+
+        if let Some(i) = inferred {
+            dims[i] = original.num_elements() / dims.iter().product::<usize>();
+        }
+
+        self.reshape(dims)
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -145,13 +196,7 @@ where
     pub fn squeeze<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
         check!(TensorCheck::squeeze::<D2>(dim, &self.shape().dims));
 
-        let current_dims = self.shape().dims;
-        let mut new_dims: [usize; D2] = [0; D2];
-
-        new_dims[..dim].copy_from_slice(&current_dims[..dim]);
-        new_dims[dim..].copy_from_slice(&current_dims[dim + 1..]);
-
-        Tensor::new(K::reshape::<D, D2>(self.primitive, new_dims.into()))
+        Tensor::new(K::squeeze::<D, D2>(self.primitive, dim))
     }
 
     /// Unsqueeze the current tensor. Create new dimensions to fit the given size.
@@ -186,6 +231,33 @@ where
         self.reshape(shape)
     }
 
+    /// Insert a dimension of size 1 at the given position, the following dimensions being
+    /// shifted by one.
+    ///
+    /// # Panics
+    ///
+    /// If the output doesn't have exactly one more dimension, or if the position is higher than
+    /// the current number of dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([3, 4]));
+    ///     let tensor = tensor.unsqueeze_dim::<3>(1);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [3, 1, 4] }
+    /// }
+    /// ```
+    pub fn unsqueeze_dim<const D2: usize>(self, dim: usize) -> Tensor<B, D2, K> {
+        check!(TensorCheck::unsqueeze_dim::<D, D2>(dim));
+
+        Tensor::new(K::unsqueeze_dim::<D, D2>(self.primitive, dim))
+    }
+
     /// Returns a tensor containing the elements selected from the given ranges.
     ///
     /// # Panics
@@ -283,13 +355,50 @@ where
 
     /// Repeat the tensor along the given dimension.
     ///
+    /// The tensor is concatenated with itself `times` along the dimension, so its size becomes
+    /// `size * times`.
+    ///
     /// # Panics
     ///
-    /// If the selected dimension more than one item.
+    /// If the dimension exceeds the rank of the tensor or if `times` is zero.
     pub fn repeat(self, dim: usize, times: usize) -> Self {
+        check!(TensorCheck::repeat::<D>(dim, times));
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
+    /// Split the tensor into `chunks` pieces of nearly equal size along the given dimension.
+    ///
+    /// The sizes of the pieces differ by at most one, the first ones being the largest. When the
+    /// size of the dimension is smaller than `chunks`, each piece has a size of one.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension exceeds the rank of the tensor or if `chunks` is zero.
+    pub fn chunk(self, chunks: usize, dim: usize) -> Vec<Self> {
+        check!(TensorCheck::chunk::<D>(chunks, dim));
+
+        K::chunk(self.primitive, chunks, dim)
+            .into_iter()
+            .map(Self::new)
+            .collect()
+    }
+
+    /// Split the tensor into pieces of size `split_size` along the given dimension.
+    ///
+    /// The last piece is smaller when the size of the dimension isn't divisible by `split_size`.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension exceeds the rank of the tensor or if `split_size` is zero.
+    pub fn split(self, split_size: usize, dim: usize) -> Vec<Self> {
+        check!(TensorCheck::split::<D>(split_size, dim));
+
+        K::split(self.primitive, split_size, dim)
+            .into_iter()
+            .map(Self::new)
+            .collect()
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -313,6 +422,67 @@ where
             dim,
         ))
     }
+
+    /// Stacks the tensors vertically, i.e. along the first dimension.
+    ///
+    /// One dimensional tensors of shape `[N]` are first promoted to `[1, N]`, so stacking
+    /// vectors creates a matrix. The resulting rank `D2` should be `2` for vectors and `D`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the resulting rank is invalid or if the tensors can't be concatenated.
+    pub fn vstack<const D2: usize>(tensors: Vec<Self>) -> Tensor<B, D2, K> {
+        check!(TensorCheck::vstack::<D, D2>());
+
+        let tensors = tensors
+            .into_iter()
+            .map(|tensor| tensor.unsqueeze::<D2>())
+            .collect();
+
+        Tensor::cat(tensors, 0)
+    }
+
+    /// Stacks the tensors horizontally, i.e. along the second dimension, or along the first
+    /// dimension for one dimensional tensors.
+    ///
+    /// # Panics
+    ///
+    /// If the tensors can't be concatenated.
+    pub fn hstack(tensors: Vec<Self>) -> Self {
+        let dim = if D == 1 { 0 } else { 1 };
+
+        Self::cat(tensors, dim)
+    }
+
+    /// Splits the tensor into overlapping windows of the given size along the first dimension,
+    /// each window starting `stride` elements after the previous one.
+    ///
+    /// The trailing elements that don't fill a complete window are dropped, so a tensor of shape
+    /// `[T, F]` results in a tensor of shape `[num_windows, size, F]` where
+    /// `num_windows = (T - size) / stride + 1`. The resulting rank `D2` should be `D + 1`.
+    ///
+    /// # Panics
+    ///
+    /// If the size or stride is zero, if the size is greater than the first dimension or if the
+    /// resulting rank is invalid.
+    pub fn windows<const D2: usize>(self, size: usize, stride: usize) -> Tensor<B, D2, K> {
+        let shape = self.shape();
+        check!(TensorCheck::windows::<D, D2>(&shape, size, stride));
+
+        let num_windows = (shape.dims[0] - size) / stride + 1;
+        let windows = (0..num_windows)
+            .map(|window| {
+                let start = window * stride;
+                let mut ranges = shape.dims.map(|dim| 0..dim);
+                ranges[0] = start..start + size;
+
+                self.clone().index(ranges).unsqueeze::<D2>()
+            })
+            .collect();
+
+        Tensor::cat(windows, 0)
+    }
 }
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -409,6 +579,14 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         tensor: Self::Primitive<D1>,
         shape: Shape<D2>,
     ) -> Self::Primitive<D2>;
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2>;
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2>;
     fn index<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         indexes: [Range<usize>; D2],
@@ -433,6 +611,16 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         dim: usize,
         times: usize,
     ) -> Self::Primitive<D>;
+    fn chunk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>>;
+    fn split<const D: usize>(
+        tensor: Self::Primitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>>;
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D>;
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
@@ -460,6 +648,20 @@ impl<B: Backend> BasicOps<B> for Float {
         B::reshape(tensor, shape)
     }
 
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::squeeze(tensor, dim)
+    }
+
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::unsqueeze_dim(tensor, dim)
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         indexes: [Range<usize>; D2],
@@ -505,6 +707,22 @@ impl<B: Backend> BasicOps<B> for Float {
         B::repeat(tensor, dim, times)
     }
 
+    fn chunk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::chunk(tensor, chunks, dim)
+    }
+
+    fn split<const D: usize>(
+        tensor: Self::Primitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::split(tensor, split_size, dim)
+    }
+
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::cat(vectors, dim)
     }
@@ -534,6 +752,20 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_reshape(tensor, shape)
     }
 
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_squeeze(tensor, dim)
+    }
+
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::int_unsqueeze_dim(tensor, dim)
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         indexes: [Range<usize>; D2],
@@ -579,6 +811,22 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_repeat(tensor, dim, times)
     }
 
+    fn chunk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::int_chunk(tensor, chunks, dim)
+    }
+
+    fn split<const D: usize>(
+        tensor: Self::Primitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::int_split(tensor, split_size, dim)
+    }
+
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -608,6 +856,20 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_reshape(tensor, shape)
     }
 
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::bool_squeeze(tensor, dim)
+    }
+
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        dim: usize,
+    ) -> Self::Primitive<D2> {
+        B::bool_unsqueeze_dim(tensor, dim)
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: Self::Primitive<D1>,
         indexes: [Range<usize>; D2],
@@ -653,6 +915,22 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_repeat(tensor, dim, times)
     }
 
+    fn chunk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::bool_chunk(tensor, chunks, dim)
+    }
+
+    fn split<const D: usize>(
+        tensor: Self::Primitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<Self::Primitive<D>> {
+        B::bool_split(tensor, split_size, dim)
+    }
+
     fn equal<const D: usize>(
         lhs: Self::Primitive<D>,
         rhs: Self::Primitive<D>,
@@ -49,6 +49,57 @@ impl TensorCheck {
             .binary_ops_ew_shape(ops, &lhs.shape(), &rhs.shape())
     }
 
+    pub(crate) fn safe_div<B: Backend, const D: usize>(
+        numerator: &Tensor<B, D>,
+        denominator: &Tensor<B, D>,
+    ) -> Self {
+        let mut check =
+            Self::Ok.binary_ops_device("Safe Div", &numerator.device(), &denominator.device());
+
+        if numerator.shape() != denominator.shape() {
+            check = check.register(
+                "Safe Div",
+                TensorError::new("The numerator and the denominator should have the same shape.")
+                    .details(format!(
+                        "Numerator shape {:?}, denominator shape {:?}.",
+                        numerator.shape().dims,
+                        denominator.shape().dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn optim_update<B: Backend, const D: usize>(
+        ops: &str,
+        param: &Tensor<B, D>,
+        states: &[&Tensor<B, D>],
+    ) -> Self {
+        let mut check = Self::Ok;
+        let shape = param.shape();
+
+        for state in states {
+            check = check.binary_ops_device(ops, &param.device(), &state.device());
+
+            if state.shape() != shape {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The optimizer state tensors should have the same shape as the parameter.",
+                    )
+                    .details(format!(
+                        "Parameter shape {:?}, state shape {:?}.",
+                        shape.dims,
+                        state.shape().dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn into_scalar<const D: usize>(shape: &Shape<D>) -> Self {
         let mut check = Self::Ok;
 
@@ -80,6 +131,84 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn repeat<const D: usize>(dim: usize, times: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Repeat", dim);
+
+        if times == 0 {
+            check = check.register(
+                "Repeat",
+                TensorError::new("Can't repeat a tensor zero times."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn chunk<const D: usize>(chunks: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Chunk", dim);
+
+        if chunks == 0 {
+            check = check.register(
+                "Chunk",
+                TensorError::new("Can't split a tensor into zero chunks."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn split<const D: usize>(split_size: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Split", dim);
+
+        if split_size == 0 {
+            check = check.register(
+                "Split",
+                TensorError::new("Can't split a tensor into pieces of size zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn expand<const D1: usize, const D2: usize>(
+        original: &Shape<D1>,
+        target: &Shape<D2>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 < D1 {
+            check = check.register(
+                "Expand",
+                TensorError::new("Can't expand a tensor to a shape with fewer dimensions.")
+                    .details(format!(
+                        "Current shape: {:?}, target shape: {:?}.",
+                        original.dims, target.dims
+                    )),
+            );
+
+            return check;
+        }
+
+        for (dim, size) in original.dims.iter().enumerate() {
+            let size_target = target.dims[D2 - D1 + dim];
+
+            if *size != 1 && *size != size_target {
+                check = check.register(
+                    "Expand",
+                    TensorError::new(
+                        "Can only expand the dimensions of size 1 or matching the target size.",
+                    )
+                    .details(format!(
+                        "Current shape: {:?}, target shape: {:?}.",
+                        original.dims, target.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn reshape<const D1: usize, const D2: usize>(
         original: &Shape<D1>,
         target: &Shape<D2>,
@@ -99,6 +228,77 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn reshape_infer<const D1: usize, const D2: usize>(
+        original: &Shape<D1>,
+        spec: &[i64; D2],
+    ) -> Self {
+        let mut check = Self::Ok;
+        let mut num_inferred = 0;
+        let mut known_elements = 1;
+
+        for (i, value) in spec.iter().enumerate() {
+            match *value {
+                -1 => num_inferred += 1,
+                0 if i >= D1 => {
+                    check = check.register(
+                        "Reshape",
+                        TensorError::new("Can't copy a dimension that doesn't exist.").details(
+                            format!(
+                                "Dimension '{i}' is copied, but the current shape is {:?}.",
+                                original.dims
+                            ),
+                        ),
+                    );
+                }
+                0 => known_elements *= original.dims[i],
+                value if value < -1 => {
+                    check = check.register(
+                        "Reshape",
+                        TensorError::new("Invalid value in the shape specification.").details(
+                            format!(
+                                "Only -1, 0 and positive values are supported, got {value} at \
+                                dimension '{i}'."
+                            ),
+                        ),
+                    );
+                }
+                value => known_elements *= value as usize,
+            }
+        }
+
+        if num_inferred > 1 {
+            check = check.register(
+                "Reshape",
+                TensorError::new("Only one dimension can be inferred.")
+                    .details(format!("Ambiguous shape specification: {spec:?}.")),
+            );
+        }
+
+        if let Self::Ok = check {
+            let num_elements = original.num_elements();
+            let is_valid = match num_inferred {
+                0 => known_elements == num_elements,
+                _ => known_elements != 0 && num_elements.is_multiple_of(known_elements),
+            };
+
+            if !is_valid {
+                check = check.register(
+                    "Reshape",
+                    TensorError::new(
+                        "The shape specification is incompatible with the number of elements of \
+                        the current tensor.",
+                    )
+                    .details(format!(
+                        "Current shape: {:?}, shape specification: {spec:?}.",
+                        original.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn flatten<const D1: usize, const D2: usize>(
         start_dim: usize,
         end_dim: usize,
@@ -164,15 +364,371 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn unsqueeze_dim<const D1: usize, const D2: usize>(dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                "Unsqueeze Dim",
+                TensorError::new(format!(
+                    "Can only insert one dimension, got dim {D2}, expected {}",
+                    D1 + 1
+                )),
+            );
+        }
+
+        if dim > D1 {
+            check = check.register(
+                "Unsqueeze Dim",
+                TensorError::new(format!(
+                    "Can't insert a dimension at position {dim} of a tensor with {D1} dimensions"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn vstack<const D1: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+        let expected = usize::max(D1, 2);
+
+        if D2 != expected {
+            check = check.register(
+                "Vstack",
+                TensorError::new(format!(
+                    "Can't vstack tensors with ({D1}) dimensions into a tensor with ({D2}) \
+                    dimensions, the resulting tensor should have ({expected}) dimensions."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn windows<const D1: usize, const D2: usize>(
+        shape: &Shape<D1>,
+        size: usize,
+        stride: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D1 + 1 {
+            check = check.register(
+                "Windows",
+                TensorError::new(format!(
+                    "Can't create windows of a tensor with ({D1}) dimensions into a tensor with \
+                    ({D2}) dimensions, the resulting tensor should have ({}) dimensions.",
+                    D1 + 1
+                )),
+            );
+        }
+
+        if size == 0 || stride == 0 {
+            check = check.register(
+                "Windows",
+                TensorError::new("The window size and stride should be greater than zero.")
+                    .details(format!("Got size ({size}) and stride ({stride}).")),
+            );
+        }
+
+        if size > shape.dims[0] {
+            check = check.register(
+                "Windows",
+                TensorError::new(
+                    "The window size can't be greater than the size of the first dimension.",
+                )
+                .details(format!(
+                    "Got size ({size}) for a first dimension of size ({}).",
+                    shape.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
-        if dim1 > D || dim2 > D {
+        for dim in [dim1, dim2] {
+            if dim >= D {
+                check = check.register(
+                    "Swap Dims",
+                    TensorError::new(format!("swap_dims: axis {dim} out of range for rank {D}"))
+                        .details(format!(
+                            "Swap dims ({dim1}, {dim2}) on tensor with ({D}) dimensions."
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn cross<const D: usize>(lhs: &Shape<D>, rhs: &Shape<D>, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Cross", dim);
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        if lhs != rhs {
+            check = check.register(
+                "Cross",
+                TensorError::new("The provided tensors should have the same shape.").details(
+                    format!("Lhs shape {:?}, rhs shape {:?}.", lhs.dims, rhs.dims),
+                ),
+            );
+        }
+
+        if lhs.dims[dim] != 3 {
+            check = check.register(
+                "Cross",
+                TensorError::new(format!(
+                    "The cross product dimension should have a size of 3, got {}.",
+                    lhs.dims[dim]
+                ))
+                .details(format!("Dimension '{dim}' of shape {:?}.", lhs.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad_reflect<const D: usize>(
+        shape: &Shape<D>,
+        pads: &[(usize, usize); D],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (dim, (before, after)) in pads.iter().enumerate() {
+            let size = shape.dims[dim];
+
+            if *before >= size || *after >= size {
+                check = check.register(
+                    "Pad Reflect",
+                    TensorError::new(
+                        "The padding can't be greater than the size of the dimension minus one.",
+                    )
+                    .details(format!(
+                        "Padding ({before}, {after}) on dimension '{dim}' of size ({size})."
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn dropout(prob: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if !(0.0..1.0).contains(&prob) {
+            check = check.register(
+                "Dropout",
+                TensorError::new("The dropout probability should be in the range [0, 1).")
+                    .details(format!("Got probability ({prob}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn random_crop<const D: usize>(
+        shape: &Shape<D>,
+        crop_height: usize,
+        crop_width: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                "Random Crop",
+                TensorError::new(format!(
+                    "Can't crop a tensor with ({D}) dimensions, at least two dimensions are \
+                    required."
+                )),
+            );
+
+            return check;
+        }
+
+        let [height, width] = [shape.dims[D - 2], shape.dims[D - 1]];
+
+        if crop_height > height || crop_width > width {
+            check = check.register(
+                "Random Crop",
+                TensorError::new("The crop can't be larger than the last two dimensions.").details(
+                    format!(
+                        "Got crop [{crop_height}, {crop_width}] for dimensions [{height}, \
+                        {width}]."
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn permute<const D: usize>(axes: &[usize; D]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, axis) in axes.iter().enumerate() {
+            if *axis >= D || axes[..i].contains(axis) {
+                check = check.register(
+                    "Permute",
+                    TensorError::new("The axes should be a permutation of the dimensions.")
+                        .details(format!("Tensor rank: '{D}', given axes: {axes:?}.")),
+                );
+
+                break;
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, dim) in dims.iter().enumerate() {
+            if *dim >= D {
+                check = check.register(
+                    "Flip",
+                    TensorError::new("Given dimension is higher than the tensor rank.")
+                        .details(format!("Tensor rank: '{D}', given dimension: '{dim}'.")),
+                );
+            }
+
+            if dims[..i].contains(dim) {
+                check = check.register(
+                    "Flip",
+                    TensorError::new(format!("The axis ({dim}) is flipped more than once."))
+                        .details(format!("Flipped axes: {dims:?}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn random_flip<const D: usize>(prob: f64, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Random Flip", dim);
+
+        if !(0.0..=1.0).contains(&prob) {
+            check = check.register(
+                "Random Flip",
+                TensorError::new("The flip probability should be in the range [0, 1].")
+                    .details(format!("Got probability ({prob}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn color_jitter(brightness: (f64, f64), contrast: (f64, f64)) -> Self {
+        let mut check = Self::Ok;
+
+        for (name, (min, max)) in [("brightness", brightness), ("contrast", contrast)] {
+            if min > max {
+                check = check.register(
+                    "Color Jitter",
+                    TensorError::new(format!(
+                        "The {name} range should have its minimum lower than its maximum."
+                    ))
+                    .details(format!("Got range ({min}, {max}).")),
+                );
+            }
+        }
+
+        if contrast.0 < 0.0 {
+            check = check.register(
+                "Color Jitter",
+                TensorError::new("The contrast factor can't be negative.").details(format!(
+                    "Got contrast range ({}, {}).",
+                    contrast.0, contrast.1
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn patchify(shape: &Shape<4>, patch_size: usize) -> Self {
+        let mut check = Self::Ok;
+        let [_, _, height, width] = shape.dims;
+
+        if patch_size == 0 {
+            check = check.register(
+                "Patchify",
+                TensorError::new("The patch size should be greater than zero."),
+            );
+        } else if height % patch_size != 0 || width % patch_size != 0 {
+            check = check.register(
+                "Patchify",
+                TensorError::new("The spatial dimensions should be divisible by the patch size.")
+                    .details(format!(
+                        "Got patch size ({patch_size}) for spatial dimensions [{height}, \
+                        {width}]."
+                    )),
+            );
+        }
+
+        check
+    }
+
+    #[cfg(feature = "linalg")]
+    pub(crate) fn pca(num_samples: usize, num_features: usize, n_components: usize) -> Self {
+        let mut check = Self::Ok;
+        let max_components = usize::min(num_samples, num_features);
+
+        if n_components > max_components {
             check = check.register(
-                "Swap Dims",
-                TensorError::new("The swap dimensions must be smaller than the tensor dimension")
+                "PCA",
+                TensorError::new("Can't compute more components than samples or features.")
                     .details(format!(
-                        "Swap dims ({dim1}, {dim2}) on tensor with ({D}) dimensions."
+                        "Requested {n_components} components, but the samples have at most \
+                         {max_components} with shape [{num_samples}, {num_features}].",
+                    )),
+            );
+        }
+
+        check
+    }
+
+    #[cfg(feature = "linalg")]
+    pub(crate) fn square(ops: &str, shape: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+        let [rows, cols] = shape.dims;
+
+        if rows != cols {
+            check = check.register(
+                ops,
+                TensorError::new("The matrix should be square.")
+                    .details(format!("Matrix shape [{rows}, {cols}].")),
+            );
+        }
+
+        check
+    }
+
+    #[cfg(feature = "linalg")]
+    pub(crate) fn solve<B: Backend>(lhs: &Tensor<B, 2>, rhs: &Tensor<B, 2>) -> Self {
+        let mut check = Self::square("Solve", &lhs.shape()).binary_ops_device(
+            "Solve",
+            &lhs.device(),
+            &rhs.device(),
+        );
+        let (lhs_shape, rhs_shape) = (lhs.shape(), rhs.shape());
+
+        if lhs_shape.dims[0] != rhs_shape.dims[0] {
+            check = check.register(
+                "Solve",
+                TensorError::new("The right hand side should have as many rows as the matrix.")
+                    .details(format!(
+                        "Matrix shape {:?}, right hand side shape {:?}.",
+                        lhs_shape.dims, rhs_shape.dims
                     )),
             );
         }
@@ -188,13 +744,23 @@ impl TensorCheck {
 
         check = check.binary_ops_device("Matmul", &lhs.device(), &rhs.device());
 
-        if D < 2 {
-            return check;
-        }
-
         let shape_lhs = lhs.shape();
         let shape_rhs = rhs.shape();
 
+        if D == 1 {
+            if shape_lhs.dims[0] != shape_rhs.dims[0] {
+                check = check.register(
+                    "Matmul",
+                    TensorError::new(format!(
+                        "The vectors of a dot product should have the same size, but got {} and {}.",
+                        shape_lhs.dims[0], shape_rhs.dims[0]
+                    )),
+                );
+            }
+
+            return check;
+        }
+
         let dim_lhs = shape_lhs.dims[D - 1];
         let dim_rhs = shape_rhs.dims[D - 2];
 
@@ -214,6 +780,47 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn matmul_transposed<B: Backend, const D: usize>(
+        ops: &str,
+        lhs: &Tensor<B, D>,
+        rhs: &Tensor<B, D>,
+        transpose_lhs: bool,
+        transpose_rhs: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        check = check.binary_ops_device(ops, &lhs.device(), &rhs.device());
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new("Transposed matmul requires tensors of at least 2 dimensions.")
+                    .details(format!("Got tensors of {D} dimension.")),
+            );
+        }
+
+        let shape_lhs = lhs.shape();
+        let shape_rhs = rhs.shape();
+
+        let dim_lhs = shape_lhs.dims[if transpose_lhs { D - 2 } else { D - 1 }];
+        let dim_rhs = shape_rhs.dims[if transpose_rhs { D - 1 } else { D - 2 }];
+
+        if dim_lhs != dim_rhs {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The inner dimension of matmul should be the same, but got {dim_lhs} and {dim_rhs}."
+                ))
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn cat<B: Backend, const D: usize, K: BasicOps<B>>(
         tensors: &[Tensor<B, D, K>],
         dim: usize,
@@ -432,6 +1039,28 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn segment<const D: usize>(
+        ops: &str,
+        shape: &Shape<D>,
+        shape_segment_ids: &Shape<1>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.dims[0] != shape_segment_ids.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new("There should be one segment id per row of the tensor.").details(
+                    format!(
+                        "Number of rows: '{}', number of segment ids: '{}'.",
+                        shape.dims[0], shape_segment_ids.dims[0]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn index_select<const D: usize>(dim: usize) -> Self {
         Self::check_index_select_basic::<D>(Self::Ok, "index_select", dim)
     }
@@ -509,6 +1138,71 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn topk<const D: usize>(k: usize, dim: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::aggregate_dim::<D>("Topk", dim);
+
+        if dim < D && k > shape.dims[dim] {
+            check = check.register(
+                "Topk",
+                TensorError::new("Can't select more elements than the size of the dimension.")
+                    .details(format!(
+                        "Selecting ({k}) elements on axis ({dim}) of size ({}).",
+                        shape.dims[dim]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks aggregate dimensions such as mean and sum over multiple dimensions.
+    pub(crate) fn aggregate_dims<const D1: usize, const D2: usize>(
+        ops: &str,
+        dims: &[usize],
+        keepdim: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, dim) in dims.iter().enumerate() {
+            if *dim >= D1 {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "Can't aggregate a tensor with ({D1}) dimensions on axis ({dim})"
+                    )),
+                );
+            }
+
+            if dims[..i].contains(dim) {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!("The axis ({dim}) is aggregated more than once."))
+                        .details(format!("Aggregated axes: {dims:?}.")),
+                );
+            }
+        }
+
+        let expected = match keepdim {
+            true => D1,
+            false => D1.saturating_sub(dims.len()),
+        };
+
+        if D2 != expected {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The resulting tensor should have ({expected}) dimensions, got ({D2})."
+                ))
+                .details(format!(
+                    "Aggregating axes {dims:?} of a tensor with ({D1}) dimensions with \
+                    keepdim={keepdim}."
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occured, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -666,6 +1360,38 @@ mod tests {
         ));
     }
 
+    #[test]
+    #[should_panic]
+    fn reshape_infer_ambiguous_spec() {
+        check!(TensorCheck::reshape_infer(
+            &Shape::new([2, 3, 4]),
+            &[-1, -1]
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn reshape_infer_not_divisible_spec() {
+        check!(TensorCheck::reshape_infer(&Shape::new([2, 3, 4]), &[-1, 5]));
+    }
+
+    #[test]
+    fn reshape_infer_valid_spec() {
+        check!(TensorCheck::reshape_infer(&Shape::new([2, 3, 4]), &[0, -1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_dims: axis 3 out of range for rank 2")]
+    fn swap_dims_out_of_range() {
+        check!(TensorCheck::swap_dims::<2>(0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_dims: axis 2 out of range for rank 2")]
+    fn swap_dims_equal_to_rank() {
+        check!(TensorCheck::swap_dims::<2>(2, 1));
+    }
+
     #[test]
     #[should_panic]
     fn index_range_exceed_dimension() {
@@ -1,13 +1,14 @@
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
-use crate::backend::ADBackend;
+use crate::backend::{ADBackend, CheckpointBackend};
 use crate::check;
 use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
+use crate::tensor::ops::{AdamUpdateOptions, RmsPropUpdateOptions, SgdMomentumUpdateOptions};
 use crate::tensor::stats;
-use crate::tensor::{Data, Distribution, Shape};
-use crate::Tensor;
+use crate::tensor::{Data, Distribution, ElementConversion, Shape};
+use crate::{Int, Tensor};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -53,6 +54,20 @@ where
         Self::new(B::log(self.primitive))
     }
 
+    /// Applies element wise base 2 log operation.
+    ///
+    /// `y = log2(x)`
+    pub fn log2(self) -> Self {
+        Self::new(B::log2(self.primitive))
+    }
+
+    /// Applies element wise base 10 log operation.
+    ///
+    /// `y = log10(x)`
+    pub fn log10(self) -> Self {
+        Self::new(B::log10(self.primitive))
+    }
+
     /// Applies the natural logarithm of one plus the input tensor, element-wise.
     ///
     /// `y = log(x+1)`
@@ -74,11 +89,74 @@ where
         Self::new(B::powf(self.primitive, value))
     }
 
+    /// Applies element wise power operation with the exponents of another tensor.
+    ///
+    /// `y = x^a`
+    pub fn powf_tensor(self, exponent: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Powf", &self, &exponent));
+
+        Self::new(B::powf_tensor(self.primitive, exponent.primitive))
+    }
+
+    /// Clamps every element of the tensor in the range `[min, max]`.
+    ///
+    /// `y = min(max(x, min), max)`
+    ///
+    /// The upper bound is applied first, so every element is clamped to `min` when `min > max`.
+    pub fn clamp<E: ElementConversion>(self, min: E, max: E) -> Self {
+        Self::new(B::clamp(self.primitive, min.elem(), max.elem()))
+    }
+
+    /// Clamps every element of the tensor to be greater or equal to `min`.
+    ///
+    /// `y = max(x, min)`
+    pub fn clamp_min<E: ElementConversion>(self, min: E) -> Self {
+        Self::new(B::clamp_min(self.primitive, min.elem()))
+    }
+
+    /// Clamps every element of the tensor to be lower or equal to `max`.
+    ///
+    /// `y = min(x, max)`
+    pub fn clamp_max<E: ElementConversion>(self, max: E) -> Self {
+        Self::new(B::clamp_max(self.primitive, max.elem()))
+    }
+
     /// Applies element wise root square operation.
     pub fn sqrt(self) -> Self {
         Self::new(B::sqrt(self.primitive))
     }
 
+    /// Applies element wise reciprocal operation.
+    ///
+    /// `y = 1 / x`
+    ///
+    /// The reciprocal of zero is infinite, with the sign of the zero.
+    pub fn recip(self) -> Self {
+        Self::new(B::recip(self.primitive))
+    }
+
+    /// Applies element wise floor operation, rounding down to the nearest integer.
+    ///
+    /// `y = floor(x)`
+    pub fn floor(self) -> Self {
+        Self::new(B::floor(self.primitive))
+    }
+
+    /// Applies element wise ceil operation, rounding up to the nearest integer.
+    ///
+    /// `y = ceil(x)`
+    pub fn ceil(self) -> Self {
+        Self::new(B::ceil(self.primitive))
+    }
+
+    /// Applies element wise round operation, rounding to the nearest integer.
+    ///
+    /// Ties are rounded to the nearest even integer (banker's rounding), so `0.5` and `-0.5`
+    /// become `0`, while `1.5` and `2.5` both become `2`.
+    pub fn round(self) -> Self {
+        Self::new(B::round(self.primitive))
+    }
+
     /// Applies element wise cosine operation.
     pub fn cos(self) -> Self {
         Self::new(B::cos(self.primitive))
@@ -94,6 +172,35 @@ where
         Self::new(B::tanh(self.primitive))
     }
 
+    /// Applies element wise arctangent operation.
+    pub fn atan(self) -> Self {
+        Self::new(B::atan(self.primitive))
+    }
+
+    /// Applies element wise arcsine operation.
+    ///
+    /// Values outside of `[-1, 1]` give `NaN`.
+    pub fn asin(self) -> Self {
+        Self::new(B::asin(self.primitive))
+    }
+
+    /// Applies element wise arccosine operation.
+    ///
+    /// Values outside of `[-1, 1]` give `NaN`.
+    pub fn acos(self) -> Self {
+        Self::new(B::acos(self.primitive))
+    }
+
+    /// Applies element wise hyperbolic sine operation.
+    pub fn sinh(self) -> Self {
+        Self::new(B::sinh(self.primitive))
+    }
+
+    /// Applies element wise hyperbolic cosine operation.
+    pub fn cosh(self) -> Self {
+        Self::new(B::cosh(self.primitive))
+    }
+
     /// Create a tensor from floats (f32).
     ///
     /// # Example
@@ -174,26 +281,502 @@ where
         Self::new(B::swap_dims(self.primitive, dim1, dim2))
     }
 
+    /// Permute the dimensions, the dimension `i` of the output being the dimension `axes[i]` of
+    /// the current tensor, e.g. `[0, 3, 1, 2]` from channels last to channels first.
+    ///
+    /// # Panics
+    ///
+    /// If the axes aren't a permutation of the dimensions of the tensor.
+    pub fn permute(self, axes: [usize; D]) -> Self {
+        check!(TensorCheck::permute::<D>(&axes));
+        Self::new(B::permute(self.primitive, axes))
+    }
+
+    /// Reverses the order of the elements along each of the given dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension exceeds the rank of the tensor or is given more than once.
+    pub fn flip(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(dims));
+        Self::new(B::flip(self.primitive, dims))
+    }
+
+    /// Broadcasts the tensor to the given shape, e.g. from `[1, N]` to `[M, N]`.
+    ///
+    /// The dimensions of size 1 are repeated to match the target shape. When the target shape has
+    /// more dimensions, the tensor is aligned with its trailing dimensions and the new dimensions
+    /// are added at the front.
+    ///
+    /// # Panics
+    ///
+    /// If the target shape has fewer dimensions, or if a dimension that isn't of size 1 doesn't
+    /// match the target shape.
+    pub fn expand<const D2: usize, S: Into<Shape<D2>>>(self, shape: S) -> Tensor<B, D2> {
+        let shape = shape.into();
+        check!(TensorCheck::expand(&self.shape(), &shape));
+
+        Tensor::new(B::expand(self.primitive, shape))
+    }
+
     /// Applies the matrix multiplication operation.
     ///
     /// `C = AB`
     ///
+    /// When both tensors are vectors, the dot product is computed and returned as a tensor with
+    /// a single element. See [vecmat](Tensor::vecmat) and [matvec](Tensor::matvec) to
+    /// multiply a vector with a matrix.
+    ///
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
     pub fn matmul(self, other: Self) -> Self {
         check!(TensorCheck::matmul(&self, &other));
+
+        if D == 1 {
+            return self.mul(other).sum().reshape([1; D]);
+        }
+
         Self::new(B::matmul(self.primitive, other.primitive))
     }
 
+    /// Applies the matrix multiplication operation on the transposed current tensor and the
+    /// other tensor, without materializing the transposed tensor when the backend supports it.
+    ///
+    /// `C = A^T x B`
+    ///
+    /// # Panics
+    ///
+    /// If the tensors have less than 2 dimensions or don't have a compatible shape.
+    pub fn matmul_transpose_a(self, other: Self) -> Self {
+        check!(TensorCheck::matmul_transposed(
+            "Matmul Transpose A",
+            &self,
+            &other,
+            true,
+            false
+        ));
+
+        Self::new(B::matmul_transpose_a(self.primitive, other.primitive))
+    }
+
+    /// Applies the matrix multiplication operation on the current tensor and the transposed
+    /// other tensor, without materializing the transposed tensor when the backend supports it.
+    ///
+    /// `C = A x B^T`
+    ///
+    /// # Panics
+    ///
+    /// If the tensors have less than 2 dimensions or don't have a compatible shape.
+    pub fn matmul_transpose_b(self, other: Self) -> Self {
+        check!(TensorCheck::matmul_transposed(
+            "Matmul Transpose B",
+            &self,
+            &other,
+            false,
+            true
+        ));
+
+        Self::new(B::matmul_transpose_b(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise division operation, returning the fill value where the denominator
+    /// is zero.
+    ///
+    /// `y = self / denominator` if `denominator != 0`, otherwise `y = fill`
+    ///
+    /// The zero values of the denominator are replaced before the division, so no `NaN` or
+    /// infinite value is created, neither in the output nor in the gradients.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape.
+    pub fn safe_div<E: ElementConversion>(self, denominator: Self, fill: E) -> Self {
+        check!(TensorCheck::safe_div(&self, &denominator));
+
+        let mask = denominator.clone().equal_elem(0.0);
+        let denominator = denominator.mask_fill(mask.clone(), 1.0);
+
+        self.div(denominator).mask_fill(mask, fill)
+    }
+
+    /// Pads the tensor by reflecting its values along each dimension, without repeating the
+    /// values on the edges.
+    ///
+    /// The padding is given as `(before, after)` for each dimension, so padding `[1, 2, 3]` by
+    /// `[(2, 2)]` results in `[3, 2, 1, 2, 3, 2, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// If a padding is greater than the size of its dimension minus one.
+    pub fn pad_reflect(self, pads: [(usize, usize); D]) -> Self {
+        check!(TensorCheck::pad_reflect(&self.shape(), &pads));
+
+        Self::new(B::pad_reflect(self.primitive, pads))
+    }
+
+    /// Aggregate all elements in the tensor with the sum operation, using a compensated
+    /// summation to reduce the accumulated floating point error.
+    ///
+    /// This is slower than [sum](Tensor::sum) and isn't differentiable, but is more accurate when
+    /// summing many values of different magnitudes.
+    pub fn sum_kahan(self) -> Tensor<B, 1> {
+        Tensor::new(B::sum_kahan(self.primitive))
+    }
+
+    /// Randomly zeroes elements of the tensor with the given probability using a random
+    /// generator created from the given seed, scaling the kept elements by `1 / (1 - prob)`.
+    ///
+    /// Unlike [random](Tensor::random), the global backend seed isn't used nor modified, so two
+    /// calls with the same seed always produce the same mask.
+    ///
+    /// # Panics
+    ///
+    /// If the probability isn't in the range `[0, 1)`.
+    pub fn dropout_seeded(self, prob: f64, seed: u64) -> Self {
+        check!(TensorCheck::dropout(prob));
+
+        Self::new(B::dropout_seeded(self.primitive, prob, seed))
+    }
+
+    /// Crops a random window of size `[crop_height, crop_width]` from the last two dimensions of
+    /// the tensor, e.g. the spatial dimensions of a `[batch_size, channels, height, width]` image.
+    ///
+    /// The crop position is sampled from the backend random generator on the given device.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions or if the crop is larger than the tensor.
+    pub fn random_crop(self, crop_height: usize, crop_width: usize, device: &B::Device) -> Self {
+        check!(TensorCheck::random_crop::<D>(
+            &self.shape(),
+            crop_height,
+            crop_width
+        ));
+
+        Self::new(B::random_crop(
+            self.primitive,
+            crop_height,
+            crop_width,
+            device,
+        ))
+    }
+
+    /// Reverses the order of the elements along the given dimension with probability `prob`,
+    /// e.g. a horizontal flip of a `[batch_size, channels, height, width]` image with `dim = 3`.
+    ///
+    /// The decision is sampled from the backend random generator on the given device.
+    ///
+    /// # Panics
+    ///
+    /// If the probability isn't in the range `[0, 1]` or if the dimension is out of bounds.
+    pub fn random_flip(self, prob: f64, dim: usize, device: &B::Device) -> Self {
+        check!(TensorCheck::random_flip::<D>(prob, dim));
+
+        Self::new(B::random_flip(self.primitive, prob, dim, device))
+    }
+
+    /// Adjusts the brightness and contrast of an image with values in `[0, 1]`.
+    ///
+    /// The brightness factor is added to every value, then the contrast factor scales the values
+    /// around the mean of the whole tensor, the result being clamped to `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// If the contrast factor is negative.
+    pub fn adjust_brightness_contrast(self, brightness: f64, contrast: f64) -> Self {
+        check!(TensorCheck::color_jitter(
+            (brightness, brightness),
+            (contrast, contrast)
+        ));
+
+        Self::new(B::adjust_brightness_contrast(
+            self.primitive,
+            brightness,
+            contrast,
+        ))
+    }
+
+    /// Randomly adjusts the brightness and contrast of an image with values in `[0, 1]`, see
+    /// [adjust_brightness_contrast](Tensor::adjust_brightness_contrast).
+    ///
+    /// Both factors are sampled uniformly in their given `(min, max)` range from the backend
+    /// random generator on the given device.
+    ///
+    /// # Panics
+    ///
+    /// If a range has its minimum greater than its maximum or if the contrast can be negative.
+    pub fn color_jitter(
+        self,
+        brightness: (f64, f64),
+        contrast: (f64, f64),
+        device: &B::Device,
+    ) -> Self {
+        check!(TensorCheck::color_jitter(brightness, contrast));
+
+        Self::new(B::color_jitter(
+            self.primitive,
+            brightness,
+            contrast,
+            device,
+        ))
+    }
+
+    /// Applies a single fused [Adam](https://arxiv.org/pdf/1412.6980.pdf) optimization step on
+    /// the current tensor, considered as the parameter to optimize.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter, first moment and second moment.
+    ///
+    /// # Panics
+    ///
+    /// If the gradient or the moments don't have the same shape as the parameter.
+    pub fn adam_update(
+        self,
+        grad: Self,
+        moment_1: Self,
+        moment_2: Self,
+        options: &AdamUpdateOptions,
+    ) -> (Self, Self, Self) {
+        check!(TensorCheck::optim_update(
+            "Adam Update",
+            &self,
+            &[&grad, &moment_1, &moment_2]
+        ));
+
+        let (param, moment_1, moment_2) = B::adam_update(
+            self.primitive,
+            grad.primitive,
+            moment_1.primitive,
+            moment_2.primitive,
+            options,
+        );
+
+        (Self::new(param), Self::new(moment_1), Self::new(moment_2))
+    }
+
+    /// Applies a single fused RMSprop optimization step on the current tensor, considered as the
+    /// parameter to optimize.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter and running average of the squared gradient.
+    ///
+    /// # Panics
+    ///
+    /// If the gradient or the running average don't have the same shape as the parameter.
+    pub fn rmsprop_update(
+        self,
+        grad: Self,
+        square_avg: Self,
+        options: &RmsPropUpdateOptions,
+    ) -> (Self, Self) {
+        check!(TensorCheck::optim_update(
+            "RMSprop Update",
+            &self,
+            &[&grad, &square_avg]
+        ));
+
+        let (param, square_avg) = B::rmsprop_update(
+            self.primitive,
+            grad.primitive,
+            square_avg.primitive,
+            options,
+        );
+
+        (Self::new(param), Self::new(square_avg))
+    }
+
+    /// Applies a single fused SGD with momentum optimization step on the current tensor,
+    /// considered as the parameter to optimize.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter and velocity.
+    ///
+    /// # Panics
+    ///
+    /// If the gradient or the velocity don't have the same shape as the parameter.
+    pub fn sgd_momentum_update(
+        self,
+        grad: Self,
+        velocity: Self,
+        options: &SgdMomentumUpdateOptions,
+    ) -> (Self, Self) {
+        check!(TensorCheck::optim_update(
+            "SGD Momentum Update",
+            &self,
+            &[&grad, &velocity]
+        ));
+
+        let (param, velocity) =
+            B::sgd_momentum_update(self.primitive, grad.primitive, velocity.primitive, options);
+
+        (Self::new(param), Self::new(velocity))
+    }
+
+    /// Computes the cross product of 3-dimensional vectors along the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape or if the given dimension doesn't have a
+    /// size of 3.
+    pub fn cross(self, other: Self, dim: usize) -> Self {
+        check!(TensorCheck::cross(&self.shape(), &other.shape(), dim));
+
+        Self::new(B::cross(self.primitive, other.primitive, dim))
+    }
+
+    /// Aggregate all elements along the given *dimensions* in the tensor with the sum operation.
+    ///
+    /// When `keepdim` is true, the reduced dimensions are kept with a size of one and `D2`
+    /// should be equal to `D`, otherwise they are removed and `D2` should be equal to `D`
+    /// minus the number of reduced dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is repeated, exceeds the tensor rank or if `D2` is invalid.
+    pub fn sum_dims<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2> {
+        check!(TensorCheck::aggregate_dims::<D, D2>("Sum", dims, keepdim));
+
+        let tensor = Self::new(B::sum_dims(self.primitive, dims));
+        let shape = tensor.shape();
+        tensor.reshape(Self::aggregated_dims::<D2>(&shape, dims))
+    }
+
+    /// Aggregate all elements along the given *dimensions* in the tensor with the mean operation.
+    ///
+    /// When `keepdim` is true, the reduced dimensions are kept with a size of one and `D2`
+    /// should be equal to `D`, otherwise they are removed and `D2` should be equal to `D`
+    /// minus the number of reduced dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is repeated, exceeds the tensor rank or if `D2` is invalid.
+    pub fn mean_dims<const D2: usize>(self, dims: &[usize], keepdim: bool) -> Tensor<B, D2> {
+        check!(TensorCheck::aggregate_dims::<D, D2>("Mean", dims, keepdim));
+
+        let tensor = Self::new(B::mean_dims(self.primitive, dims));
+        let shape = tensor.shape();
+        tensor.reshape(Self::aggregated_dims::<D2>(&shape, dims))
+    }
+
+    /// Sum the rows of the tensor sharing the same segment id, producing a tensor whose first
+    /// dimension has a size of `num_segments`.
+    ///
+    /// `output[s, ..] = sum(input[i, ..] for i where segment_ids[i] == s)`
+    ///
+    /// Segment ids should be lower than `num_segments`, the result is unspecified otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the number of segment ids isn't the size of the first dimension of the tensor.
+    pub fn segment_sum(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        check!(TensorCheck::segment::<D>(
+            "Segment Sum",
+            &self.shape(),
+            &segment_ids.shape()
+        ));
+
+        Self::new(B::segment_sum(
+            self.primitive,
+            segment_ids.primitive,
+            num_segments,
+        ))
+    }
+
+    /// Average the rows of the tensor sharing the same segment id, producing a tensor whose first
+    /// dimension has a size of `num_segments`.
+    ///
+    /// `output[s, ..] = mean(input[i, ..] for i where segment_ids[i] == s)`
+    ///
+    /// Empty segments are filled with zeros.
+    ///
+    /// Segment ids should be lower than `num_segments`, the result is unspecified otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the number of segment ids isn't the size of the first dimension of the tensor.
+    pub fn segment_mean(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        check!(TensorCheck::segment::<D>(
+            "Segment Mean",
+            &self.shape(),
+            &segment_ids.shape()
+        ));
+
+        Self::new(B::segment_mean(
+            self.primitive,
+            segment_ids.primitive,
+            num_segments,
+        ))
+    }
+
+    /// Take the maximum of the rows of the tensor sharing the same segment id, producing a tensor
+    /// whose first dimension has a size of `num_segments`.
+    ///
+    /// `output[s, ..] = max(input[i, ..] for i where segment_ids[i] == s)`
+    ///
+    /// Empty segments are filled with zeros.
+    ///
+    /// Segment ids should be lower than `num_segments`, the result is unspecified otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the number of segment ids isn't the size of the first dimension of the tensor.
+    pub fn segment_max(self, segment_ids: Tensor<B, 1, Int>, num_segments: usize) -> Self {
+        check!(TensorCheck::segment::<D>(
+            "Segment Max",
+            &self.shape(),
+            &segment_ids.shape()
+        ));
+
+        Self::new(B::segment_max(
+            self.primitive,
+            segment_ids.primitive,
+            num_segments,
+        ))
+    }
+
+    fn aggregated_dims<const D2: usize>(shape: &Shape<D>, dims: &[usize]) -> [usize; D2] {
+        if D2 == D {
+            let mut new_dims = [1; D2];
+            new_dims.copy_from_slice(&shape.dims[..D2]);
+            return new_dims;
+        }
+
+        let kept: Vec<usize> = shape
+            .dims
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !dims.contains(i))
+            .map(|(_, size)| *size)
+            .collect();
+
+        let mut new_dims = [1; D2];
+        new_dims.copy_from_slice(&kept);
+        new_dims
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
-        stats::var(self, dim)
+        Self::new(B::var(self.primitive, dim))
     }
 
     /// Calculate the variance along the given dimension without applying the Bessel’s correction.
     pub fn var_bias(self, dim: usize) -> Self {
-        stats::var_bias(self, dim)
+        Self::new(B::var_bias(self.primitive, dim))
+    }
+
+    /// Calculate the standard deviation along the given dimension.
+    pub fn std(self, dim: usize) -> Self {
+        Self::new(B::std(self.primitive, dim))
+    }
+
+    /// Calculate the standard deviation along the given dimension without applying the Bessel’s
+    /// correction.
+    pub fn std_bias(self, dim: usize) -> Self {
+        Self::new(B::std_bias(self.primitive, dim))
     }
 
     /// Calculate the variance along the given dimension and also returns the mean.
@@ -213,7 +796,17 @@ where
     /// Create a random tensor of the given shape where each element is sampled from the given
     /// distribution.
     pub fn random<S: Into<Shape<D>>>(shape: S, distribution: Distribution<B::FloatElem>) -> Self {
-        let tensor = B::random(shape.into(), distribution, &B::Device::default());
+        Self::random_device(shape, distribution, &B::Device::default())
+    }
+
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution.
+    pub fn random_device<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution<B::FloatElem>,
+        device: &B::Device,
+    ) -> Self {
+        let tensor = B::random(shape.into(), distribution, device);
         Self::new(tensor)
     }
 
@@ -235,6 +828,23 @@ where
         Self::new(B::detach(self.primitive))
     }
 
+    /// Straight-through estimator.
+    ///
+    /// Returns the quantized values during the forward pass, while the gradients are passed
+    /// unchanged to the current tensor during the backward pass, as if the quantization was
+    /// the identity function.
+    ///
+    /// `y = x + detach(quantized - x)`
+    pub fn straight_through(self, quantized: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "Straight Through",
+            &self,
+            &quantized
+        ));
+
+        self.clone().add(quantized.sub(self).detach())
+    }
+
     /// Mark the tensor to keep gradients during the backward pass.
     /// This function does nothing when autodiff is not enabled.
     pub fn require_grad(self) -> Self {
@@ -260,6 +870,204 @@ where
     }
 }
 
+impl<B: Backend> Tensor<B, 1> {
+    /// Applies the vector-matrix multiplication operation.
+    ///
+    /// The vector of shape `[M]` is treated as a matrix of shape `[1, M]`, and the resulting
+    /// matrix of shape `[1, N]` is returned as a vector of shape `[N]`.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the vector doesn't match the first dimension of the matrix.
+    pub fn vecmat(self, matrix: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [_, size_out] = matrix.dims();
+
+        self.unsqueeze::<2>().matmul(matrix).reshape([size_out])
+    }
+}
+
+impl<B: Backend> Tensor<B, 2> {
+    /// Applies the matrix-vector multiplication operation.
+    ///
+    /// The vector of shape `[N]` is treated as a matrix of shape `[N, 1]`, and the resulting
+    /// matrix of shape `[M, 1]` is returned as a vector of shape `[M]`.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the vector doesn't match the last dimension of the matrix.
+    pub fn matvec(self, vector: Tensor<B, 1>) -> Tensor<B, 1> {
+        let [size_out, _] = self.dims();
+        let [size_in] = vector.dims();
+
+        self.matmul(vector.reshape([size_in, 1]))
+            .reshape([size_out])
+    }
+}
+
+/// Linear algebra operations.
+///
+/// These operations are computed on the host in `f64`, so gradients aren't tracked through
+/// them with an autodiff backend.
+#[cfg(feature = "linalg")]
+impl<B: Backend> Tensor<B, 2> {
+    /// Computes the singular value decomposition `A = U diag(S) V^T` of the matrix.
+    ///
+    /// With `k = min(rows, cols)`, returns `U` of shape `[rows, k]`, the singular values `S` of
+    /// shape `[k]` sorted in descending order, and `V` of shape `[cols, k]`.
+    pub fn svd(self) -> (Tensor<B, 2>, Tensor<B, 1>, Tensor<B, 2>) {
+        let (u, s, v) = B::svd(self.primitive);
+
+        (Tensor::new(u), Tensor::new(s), Tensor::new(v))
+    }
+
+    /// Computes the reduced QR decomposition `A = Q R` of the matrix.
+    ///
+    /// With `k = min(rows, cols)`, returns `Q` of shape `[rows, k]` with orthonormal columns and
+    /// the upper triangular `R` of shape `[k, cols]` with a non-negative diagonal.
+    pub fn qr(self) -> (Tensor<B, 2>, Tensor<B, 2>) {
+        let (q, r) = B::qr(self.primitive);
+
+        (Tensor::new(q), Tensor::new(r))
+    }
+
+    /// Projects the samples of shape `[num_samples, num_features]` on their `n_components`
+    /// principal components, returning a tensor of shape `[num_samples, n_components]`.
+    ///
+    /// The samples are centered, and the principal components are the right singular vectors of
+    /// the centered samples, sorted by decreasing explained variance. With an autodiff backend,
+    /// gradients only flow through the projection.
+    ///
+    /// # Panics
+    ///
+    /// If more components than `min(num_samples, num_features)` are requested.
+    pub fn pca(self, n_components: usize) -> Tensor<B, 2> {
+        let [num_samples, num_features] = self.dims();
+
+        check!(TensorCheck::pca(num_samples, num_features, n_components));
+
+        let centered = self.clone() - self.mean_dim(0);
+        let (_, _, v) = centered.clone().svd();
+
+        centered.matmul(v.index([0..num_features, 0..n_components]))
+    }
+
+    /// Computes the eigendecomposition `A = V diag(L) V^T` of the symmetric matrix.
+    ///
+    /// Returns the eigenvalues `L` of shape `[n]` sorted in ascending order, and the eigenvectors
+    /// as the columns of `V` of shape `[n, n]`. Only symmetric matrices are supported, which
+    /// isn't verified.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    pub fn symeig(self) -> (Tensor<B, 1>, Tensor<B, 2>) {
+        check!(TensorCheck::square("Symeig", &self.shape()));
+
+        let (eigenvalues, eigenvectors) = B::symeig(self.primitive);
+
+        (Tensor::new(eigenvalues), Tensor::new(eigenvectors))
+    }
+
+    /// Solves the linear system `A X = B` where `A` is this square matrix of shape `[n, n]`,
+    /// returning `X` with the same shape `[n, k]` as `B`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square, if the number of rows of `B` isn't `n`, or if the matrix is
+    /// singular.
+    pub fn solve(self, rhs: Tensor<B, 2>) -> Tensor<B, 2> {
+        check!(TensorCheck::solve(&self, &rhs));
+
+        Tensor::new(B::solve(self.primitive, rhs.primitive))
+    }
+
+    /// Computes the inverse of the square matrix.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square or is singular.
+    pub fn inverse(self) -> Tensor<B, 2> {
+        check!(TensorCheck::square("Inverse", &self.shape()));
+
+        Tensor::new(B::inverse(self.primitive))
+    }
+
+    /// Computes the lower triangular Cholesky factor `L` of the symmetric positive definite
+    /// matrix, such that `A = L L^T`.
+    ///
+    /// Only symmetric matrices are supported, which isn't verified.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square or isn't positive definite.
+    pub fn cholesky(self) -> Tensor<B, 2> {
+        check!(TensorCheck::square("Cholesky", &self.shape()));
+
+        Tensor::new(B::cholesky(self.primitive))
+    }
+
+    /// Computes the determinant of the square matrix, returning a tensor of shape `[1]`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    pub fn det(self) -> Tensor<B, 1> {
+        check!(TensorCheck::square("Det", &self.shape()));
+
+        Tensor::new(B::det(self.primitive))
+    }
+
+    /// Computes the natural logarithm of the determinant of the square matrix, returning a tensor
+    /// of shape `[1]`.
+    ///
+    /// Unlike `det().log()`, the result doesn't overflow for large matrices. It is NaN when the
+    /// determinant is negative, and negative infinity when the matrix is singular.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't square.
+    pub fn logdet(self) -> Tensor<B, 1> {
+        check!(TensorCheck::square("Logdet", &self.shape()));
+
+        Tensor::new(B::logdet(self.primitive))
+    }
+}
+
+impl<B: Backend> Tensor<B, 4> {
+    /// Converts the tensor from the channels first layout used by the convolution modules,
+    /// `[batch_size, channels, height, width]`, to the channels last layout,
+    /// `[batch_size, height, width, channels]`.
+    pub fn nchw_to_nhwc(self) -> Self {
+        Self::new(B::nchw_to_nhwc(self.primitive))
+    }
+
+    /// Converts the tensor from the channels last layout, `[batch_size, height, width, channels]`,
+    /// to the channels first layout used by the convolution modules,
+    /// `[batch_size, channels, height, width]`.
+    pub fn nhwc_to_nchw(self) -> Self {
+        Self::new(B::nhwc_to_nchw(self.primitive))
+    }
+
+    /// Splits a `[batch_size, channels, height, width]` image into non-overlapping square
+    /// patches, as done by vision transformers before projecting the patches into tokens.
+    ///
+    /// The patches are in row-major order and each one is flattened in the
+    /// `[channels, patch_size, patch_size]` order.
+    ///
+    /// # Panics
+    ///
+    /// If the height or the width isn't divisible by the patch size.
+    ///
+    /// # Shapes
+    ///
+    /// - output: `[batch_size, num_patches, channels * patch_size * patch_size]`
+    pub fn patchify(self, patch_size: usize) -> Tensor<B, 3> {
+        check!(TensorCheck::patchify(&self.shape(), patch_size));
+
+        Tensor::new(B::patchify(self.primitive, patch_size))
+    }
+}
+
 impl<const D: usize, B: ADBackend> Tensor<B, D> {
     pub fn backward(&self) -> B::Gradients {
         B::backward::<D>(self.primitive.clone())
@@ -287,3 +1095,11 @@ impl<const D: usize, B: ADBackend> Tensor<B, D> {
         Self::new(B::from_inner(inner.primitive))
     }
 }
+
+impl<const D: usize, B: CheckpointBackend> Tensor<B, D> {
+    /// Register a gradient for the tensor into the [grads](ADBackend::Gradients) struct, adding it
+    /// to the gradient already registered if any.
+    pub fn grad_register(&self, grads: &mut B::Gradients, grad: Tensor<B::InnerBackend, D>) {
+        B::grad_register(&self.primitive, grads, grad.primitive)
+    }
+}
@@ -91,6 +91,21 @@ where
         Self::new(K::neg(self.primitive))
     }
 
+    /// Applies the absolute value of each element in the tensor.
+    ///
+    /// `y = |x|`
+    pub fn abs(self) -> Self {
+        Self::new(K::abs(self.primitive))
+    }
+
+    /// Returns the sign of each element in the tensor: `-1` for negative values, `1` for positive
+    /// values and `0` for zeros.
+    ///
+    /// NaN values have no sign and are mapped to `0`.
+    pub fn sign(self) -> Self {
+        Self::new(K::sign(self.primitive))
+    }
+
     /// Create a tensor of the given shape where each element is zero.
     pub fn zeros<S: Into<Shape<D>>>(shape: S) -> Self {
         Self::zeros_device(shape, &B::Device::default())
@@ -133,6 +148,17 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Aggregate all elements in the tensor with the product operation.
+    pub fn prod(self) -> Tensor<B, 1, K> {
+        Tensor::new(K::prod(self.primitive))
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the product operation.
+    pub fn prod_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Prod", dim));
+        Self::new(K::prod_dim(self.primitive, dim))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -208,6 +234,28 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Fill each element with the corresponding value of the given tensor where the mask is true.
+    ///
+    /// The values tensor is broadcasted against the current tensor, so a tensor of shape
+    /// `[batch_size, 1]` can be used to fill each row with a different value.
+    ///
+    /// # Panics
+    ///
+    /// If the values tensor can't be broadcasted to the shape of the current tensor.
+    pub fn mask_fill_broadcast(self, mask: Tensor<B, D, Bool>, values: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "Mask Fill Broadcast",
+            &self,
+            &values
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let source = K::add(K::zeros(shape, &device), values.primitive);
+
+        Self::new(K::mask_scatter(self.primitive, mask, source))
+    }
+
     /// Gather tensor elements corresponding to the given indexes from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -220,6 +268,10 @@ where
     ///
     /// The index tensor shoud have the same shape as the original tensor except for the dim
     /// specified.
+    ///
+    /// # Panics
+    ///
+    /// If an index is negative or greater or equal to the size of the specified dimension.
     pub fn gather(self, dim: usize, indexes: Tensor<B, D, Int>) -> Self {
         check!(TensorCheck::gather::<D>(
             dim,
@@ -244,7 +296,12 @@ where
     /// The index tensor shoud have the same shape as the original tensor except for the speficied
     /// dimension. The value and index tensors should have the same shape.
     ///
-    /// Other references to the input tensor will not be modified by this operation.
+    /// Values sharing the same index are all accumulated, like PyTorch's `scatter_add`. Other
+    /// references to the input tensor will not be modified by this operation.
+    ///
+    /// # Panics
+    ///
+    /// If an index is negative or greater or equal to the size of the specified dimension.
     pub fn scatter(self, dim: usize, indexes: Tensor<B, D, Int>, values: Self) -> Self {
         check!(TensorCheck::scatter::<D>(
             dim,
@@ -263,6 +320,8 @@ where
     /// `output[i, j, k] = input[indexes[i], j, k]; // dim = 0`
     /// `output[i, j, k] = input[i, indexes[j], k]; // dim = 1`
     /// `output[i, j, k] = input[i, j, indexes[k]]; // dim = 2`
+    ///
+    /// The size of the output along `dim` is the number of indexes, which can be repeated.
     pub fn index_select(self, dim: usize, indexes: Tensor<B, 1, Int>) -> Self {
         check!(TensorCheck::index_select::<D>(dim));
         Self::new(K::index_select(self.primitive, dim, indexes))
@@ -380,6 +439,53 @@ where
 
         (tensor, index)
     }
+
+    /// Sort the elements along the given dimension, in descending order if `descending` is
+    /// true.
+    ///
+    /// The sort is stable, so equal elements keep their original order.
+    pub fn sort(self, dim: usize, descending: bool) -> Tensor<B, D, K> {
+        check!(TensorCheck::aggregate_dim::<D>("Sort", dim));
+
+        Tensor::new(K::sort(self.primitive, dim, descending))
+    }
+
+    /// Sort the elements along the given dimension, in descending order if `descending` is
+    /// true.
+    ///
+    /// Also returns the indexes of the sorted elements in the original tensor.
+    pub fn sort_with_indexes(
+        self,
+        dim: usize,
+        descending: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("Sort", dim));
+
+        let (tensor, indexes) = K::sort_with_indexes(self.primitive, dim, descending);
+
+        (Tensor::new(tensor), Tensor::new(indexes))
+    }
+
+    /// Returns the indexes that sort the elements along the given dimension, in descending
+    /// order if `descending` is true.
+    ///
+    /// The sort is stable, so the indexes of equal elements keep their original order.
+    pub fn argsort(self, dim: usize, descending: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::aggregate_dim::<D>("Argsort", dim));
+
+        Tensor::new(K::argsort(self.primitive, dim, descending))
+    }
+
+    /// Find the `k` largest values along the given dimension, sorted in descending order.
+    ///
+    /// Also returns the indexes. The size of the given dimension is `k` in both outputs.
+    pub fn topk(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(k, dim, &self.shape()));
+
+        let (tensor, indexes) = K::topk(self.primitive, k, dim);
+
+        (Tensor::new(tensor), Tensor::new(indexes))
+    }
 }
 
 /// Trait that list all operations that can be applied on all numerical tensors.
@@ -412,10 +518,14 @@ where
         rhs: E,
     ) -> Self::Primitive<D>;
     fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
     fn zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D>;
     fn ones<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D>;
     fn sum<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1>;
     fn mean_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
     fn equal_elem<const D: usize>(lhs: Self::Primitive<D>, rhs: Self::Elem) -> Tensor<B, D, Bool>;
@@ -492,6 +602,26 @@ where
         tensor: Self::Primitive<D>,
         dim: usize,
     ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D>;
+    fn sort_with_indexes<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D>;
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -546,6 +676,12 @@ impl<B: Backend> Numeric<B> for Int {
     fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::int_neg(tensor)
     }
+    fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::int_abs(tensor)
+    }
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::int_sign(tensor)
+    }
     fn zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::int_zeros(shape, device)
     }
@@ -558,6 +694,12 @@ impl<B: Backend> Numeric<B> for Int {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::int_sum_dim(tensor, dim)
     }
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::int_prod(tensor)
+    }
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_prod_dim(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_mean(tensor)
     }
@@ -713,6 +855,38 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
         B::int_min_dim_with_indexes(tensor, dim)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::int_sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indexes<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_sort_with_indexes(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::int_argsort(tensor, dim, descending)
+    }
+
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_topk(tensor, k, dim)
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -767,6 +941,12 @@ impl<B: Backend> Numeric<B> for Float {
     fn neg<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
         B::neg(tensor)
     }
+    fn abs<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::abs(tensor)
+    }
+    fn sign<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::sign(tensor)
+    }
     fn zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
         B::zeros(shape, device)
     }
@@ -779,6 +959,12 @@ impl<B: Backend> Numeric<B> for Float {
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
         B::sum_dim(tensor, dim)
     }
+    fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
+        B::prod(tensor)
+    }
+    fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::prod_dim(tensor, dim)
+    }
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::mean(tensor)
     }
@@ -935,6 +1121,38 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
         B::min_dim_with_indexes(tensor, dim)
     }
+
+    fn sort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> Self::Primitive<D> {
+        B::sort(tensor, dim, descending)
+    }
+
+    fn sort_with_indexes<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::sort_with_indexes(tensor, dim, descending)
+    }
+
+    fn argsort<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> <B as Backend>::IntTensorPrimitive<D> {
+        B::argsort(tensor, dim, descending)
+    }
+
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::topk(tensor, k, dim)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use alloc::string::String;
 
 use crate::ops::*;
@@ -99,6 +100,21 @@ pub trait Backend:
 pub(crate) type ADBackendTensorPrimitive<const D: usize, B> =
     <<B as ADBackend>::InnerBackend as Backend>::TensorPrimitive<D>;
 
+/// Function recomputing a [checkpointed](CheckpointBackend::checkpoint) operation during the backward
+/// pass.
+///
+/// It receives the saved input and the gradient of the output, registers the gradients of any
+/// tracked tensor it captured into the given gradients, and returns the gradient of the input.
+pub type CheckpointBackward<B, const D_IN: usize, const D_OUT: usize> = Box<
+    dyn Fn(
+            ADBackendTensorPrimitive<D_IN, B>,
+            ADBackendTensorPrimitive<D_OUT, B>,
+            &mut <B as ADBackend>::Gradients,
+        ) -> ADBackendTensorPrimitive<D_IN, B>
+        + Send
+        + Sync,
+>;
+
 /// Trait that allows a backend to support autodiff.
 pub trait ADBackend: Backend {
     type InnerBackend: Backend<Device = Self::Device, FloatElem = Self::FloatElem>;
@@ -120,3 +136,26 @@ pub trait ADBackend: Backend {
         tensor: <Self::InnerBackend as Backend>::TensorPrimitive<D>,
     ) -> Self::TensorPrimitive<D>;
 }
+
+/// Trait that allows an [autodiff backend](ADBackend) to checkpoint operations, recomputing them
+/// during the backward pass instead of storing their intermediate activations.
+pub trait CheckpointBackend: ADBackend {
+    /// Register a gradient for the given tensor, adding it to any gradient already registered.
+    fn grad_register<const D: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        grads: &mut Self::Gradients,
+        grad: ADBackendTensorPrimitive<D, Self>,
+    );
+    /// Create a tracked tensor from an output computed without tracking.
+    ///
+    /// Only the input is kept in the graph; the backward function is called during the backward
+    /// pass to recompute the operation and compute the gradients. The output is tracked when the
+    /// input is tracked or when the backward function captures tracked tensors, such as module
+    /// parameters, otherwise it is untracked and the backward function is never called.
+    fn checkpoint<const D_IN: usize, const D_OUT: usize>(
+        input: Self::TensorPrimitive<D_IN>,
+        output: ADBackendTensorPrimitive<D_OUT, Self>,
+        backward: CheckpointBackward<Self, D_IN, D_OUT>,
+        captures_tracked: bool,
+    ) -> Self::TensorPrimitive<D_OUT>;
+}
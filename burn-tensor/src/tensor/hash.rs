@@ -0,0 +1,60 @@
+use crate::{backend::Backend, BasicOps, Element, ElementConversion, Tensor};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Computes a stable hash of the shape and the values of a tensor.
+///
+/// The values are hashed in the logical row-major order returned by
+/// [to_data](Tensor::to_data), so equal tensors hash equally independently of their memory
+/// layout or backend. Integer values are hashed from their `i64` representation, which is exact,
+/// while floating point values are hashed from their `f64` representation, with `-0.0` and every
+/// `NaN` canonicalized. The bytes are combined with the FNV-1a algorithm, which doesn't depend on
+/// the platform or the compiler version.
+pub fn tensor_hash<B, const D: usize, K>(tensor: &Tensor<B, D, K>) -> u64
+where
+    B: Backend,
+    K: BasicOps<B>,
+    K::Elem: Element,
+{
+    let data = tensor.to_data();
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut write = |bytes: [u8; 8]| {
+        for byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    };
+
+    write((D as u64).to_le_bytes());
+    for dim in data.shape.dims {
+        write((dim as u64).to_le_bytes());
+    }
+
+    if is_float::<K::Elem>() {
+        for value in data.value {
+            let value = value.elem::<f64>();
+            let value = if value.is_nan() {
+                f64::NAN
+            } else if value == 0.0 {
+                0.0
+            } else {
+                value
+            };
+
+            write(value.to_bits().to_le_bytes());
+        }
+    } else {
+        for value in data.value {
+            write(value.elem::<i64>().to_le_bytes());
+        }
+    }
+
+    hash
+}
+
+/// Integer elements truncate `0.5` when converted, while every floating point element represents
+/// it exactly.
+fn is_float<E: Element>() -> bool {
+    E::from_elem(0.5).to_f64() == Some(0.5)
+}
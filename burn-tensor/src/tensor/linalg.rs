@@ -0,0 +1,453 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use libm::{fabs, log, sqrt};
+
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+
+/// The maximum number of sweeps of the Jacobi methods.
+const MAX_SWEEPS: usize = 100;
+
+/// Dense row-major matrix used by the linear algebra algorithms computed on the host.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub values: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            values: vec![0.0; rows * cols],
+        }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = Self::zeros(size, size);
+        for i in 0..size {
+            matrix.set(i, i, 1.0);
+        }
+        matrix
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.values[row * self.cols + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: f64) {
+        self.values[row * self.cols + col] = value;
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut output = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                output.set(j, i, self.get(i, j));
+            }
+        }
+        output
+    }
+
+    pub fn from_primitive<B: Backend>(tensor: B::TensorPrimitive<2>) -> Self {
+        let data = B::into_data(tensor);
+        let [rows, cols] = data.shape.dims;
+
+        Self {
+            rows,
+            cols,
+            values: data.value.into_iter().map(|value| value.elem()).collect(),
+        }
+    }
+
+    pub fn into_primitive<B: Backend>(self, device: &B::Device) -> B::TensorPrimitive<2> {
+        let shape = Shape::new([self.rows, self.cols]);
+
+        B::from_data(Data::new(self.values, shape).convert(), device)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.cols {
+            self.values.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    /// Applies the transposed rotation `[c, -s; s, c]` on the rows `p` and `q`.
+    fn rotate_rows(&mut self, p: usize, q: usize, c: f64, s: f64) {
+        for j in 0..self.cols {
+            let (value_p, value_q) = (self.get(p, j), self.get(q, j));
+            self.set(p, j, c * value_p - s * value_q);
+            self.set(q, j, s * value_p + c * value_q);
+        }
+    }
+
+    /// Applies the rotation `[c, s; -s, c]` on the columns `p` and `q`.
+    fn rotate_columns(&mut self, p: usize, q: usize, c: f64, s: f64) {
+        for i in 0..self.rows {
+            let (value_p, value_q) = (self.get(i, p), self.get(i, q));
+            self.set(i, p, c * value_p - s * value_q);
+            self.set(i, q, s * value_p + c * value_q);
+        }
+    }
+}
+
+/// Creates a vector primitive from host values.
+pub(crate) fn vector_into_primitive<B: Backend>(
+    values: Vec<f64>,
+    device: &B::Device,
+) -> B::TensorPrimitive<1> {
+    let shape = Shape::new([values.len()]);
+
+    B::from_data(Data::new(values, shape).convert(), device)
+}
+
+/// LU decomposition `P A = L U` of a square matrix with partial pivoting, `L` having a unit
+/// diagonal.
+pub(crate) struct Lu {
+    /// The strictly lower part holds `L` and the upper part holds `U`.
+    factors: Matrix,
+    /// The row of the input matrix at each row of the factors.
+    permutation: Vec<usize>,
+    /// Whether an odd number of rows were swapped.
+    odd_swaps: bool,
+}
+
+impl Lu {
+    pub fn new(matrix: &Matrix) -> Self {
+        let size = matrix.rows;
+        let mut factors = matrix.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut odd_swaps = false;
+
+        for k in 0..size {
+            let pivot = (k..size)
+                .max_by(|a, b| {
+                    fabs(factors.get(*a, k))
+                        .partial_cmp(&fabs(factors.get(*b, k)))
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap();
+
+            if pivot != k {
+                factors.swap_rows(k, pivot);
+                permutation.swap(k, pivot);
+                odd_swaps = !odd_swaps;
+            }
+
+            let diagonal = factors.get(k, k);
+            if diagonal == 0.0 {
+                continue;
+            }
+
+            for i in k + 1..size {
+                let factor = factors.get(i, k) / diagonal;
+                factors.set(i, k, factor);
+
+                for j in k + 1..size {
+                    factors.set(i, j, factors.get(i, j) - factor * factors.get(k, j));
+                }
+            }
+        }
+
+        Self {
+            factors,
+            permutation,
+            odd_swaps,
+        }
+    }
+
+    /// The determinant, which is the signed product of the pivots.
+    pub fn determinant(&self) -> f64 {
+        let sign = if self.odd_swaps { -1.0 } else { 1.0 };
+
+        (0..self.factors.rows).fold(sign, |det, i| det * self.factors.get(i, i))
+    }
+
+    /// The natural logarithm of the determinant, summing the logarithms of the pivots so that it
+    /// doesn't overflow for large matrices.
+    ///
+    /// Returns NaN for a negative determinant and negative infinity for a singular matrix.
+    pub fn log_determinant(&self) -> f64 {
+        let mut negative = self.odd_swaps;
+        let mut output = 0.0;
+
+        for i in 0..self.factors.rows {
+            let pivot = self.factors.get(i, i);
+            negative ^= pivot < 0.0;
+            output += log(fabs(pivot));
+        }
+
+        if negative && output > f64::NEG_INFINITY {
+            return f64::NAN;
+        }
+
+        output
+    }
+
+    /// Whether a pivot is negligible compared to the magnitude of the factors.
+    pub fn is_singular(&self) -> bool {
+        let size = self.factors.rows;
+        let scale = self
+            .factors
+            .values
+            .iter()
+            .fold(0.0, |max, value| f64::max(max, fabs(*value)));
+        let tolerance = f64::EPSILON * size as f64 * scale;
+
+        (0..size).any(|i| fabs(self.factors.get(i, i)) <= tolerance)
+    }
+
+    /// Solves `A X = B` for every column of `B`.
+    pub fn solve(&self, rhs: &Matrix) -> Matrix {
+        let size = self.factors.rows;
+        let mut output = Matrix::zeros(size, rhs.cols);
+
+        for col in 0..rhs.cols {
+            // Forward substitution with the unit lower triangular factor.
+            for i in 0..size {
+                let mut value = rhs.get(self.permutation[i], col);
+                for j in 0..i {
+                    value -= self.factors.get(i, j) * output.get(j, col);
+                }
+                output.set(i, col, value);
+            }
+
+            // Back substitution with the upper triangular factor.
+            for i in (0..size).rev() {
+                let mut value = output.get(i, col);
+                for j in i + 1..size {
+                    value -= self.factors.get(i, j) * output.get(j, col);
+                }
+                output.set(i, col, value / self.factors.get(i, i));
+            }
+        }
+
+        output
+    }
+}
+
+/// Computes the lower triangular Cholesky factor `L` of a symmetric matrix with `A = L L^T`.
+///
+/// Returns `None` when the matrix isn't positive definite.
+pub(crate) fn cholesky(matrix: &Matrix) -> Option<Matrix> {
+    let size = matrix.rows;
+    let mut factor = Matrix::zeros(size, size);
+
+    for j in 0..size {
+        let mut diagonal = matrix.get(j, j);
+        for k in 0..j {
+            diagonal -= factor.get(j, k) * factor.get(j, k);
+        }
+
+        if diagonal <= 0.0 || diagonal.is_nan() {
+            return None;
+        }
+
+        let diagonal = sqrt(diagonal);
+        factor.set(j, j, diagonal);
+
+        for i in j + 1..size {
+            let mut value = matrix.get(i, j);
+            for k in 0..j {
+                value -= factor.get(i, k) * factor.get(j, k);
+            }
+            factor.set(i, j, value / diagonal);
+        }
+    }
+
+    Some(factor)
+}
+
+/// Computes the reduced QR decomposition `A = Q R` with Householder reflections, `R` having a
+/// non-negative diagonal.
+///
+/// With `k = min(rows, cols)`, `Q` is `[rows, k]` with orthonormal columns and `R` is the upper
+/// triangular `[k, cols]`.
+pub(crate) fn qr(matrix: &Matrix) -> (Matrix, Matrix) {
+    let (rows, cols) = (matrix.rows, matrix.cols);
+    let size = usize::min(rows, cols);
+    let mut r = matrix.clone();
+    let mut q = Matrix::identity(rows);
+
+    for k in 0..size {
+        let norm = sqrt((k..rows).map(|i| r.get(i, k) * r.get(i, k)).sum());
+        let alpha = if r.get(k, k) > 0.0 { -norm } else { norm };
+
+        // The reflection `H = I - 2 v v^T / (v^T v)` zeroes the column below the diagonal.
+        let mut v: Vec<f64> = (k..rows).map(|i| r.get(i, k)).collect();
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|value| value * value).sum::<f64>();
+
+        if v_norm == 0.0 {
+            continue;
+        }
+
+        for j in 0..cols {
+            let factor = 2.0 * (k..rows).map(|i| v[i - k] * r.get(i, j)).sum::<f64>() / v_norm;
+            for i in k..rows {
+                r.set(i, j, r.get(i, j) - factor * v[i - k]);
+            }
+        }
+
+        for i in 0..rows {
+            let factor = 2.0 * (k..rows).map(|j| q.get(i, j) * v[j - k]).sum::<f64>() / v_norm;
+            for j in k..rows {
+                q.set(i, j, q.get(i, j) - factor * v[j - k]);
+            }
+        }
+    }
+
+    let mut q_reduced = Matrix::zeros(rows, size);
+    let mut r_reduced = Matrix::zeros(size, cols);
+
+    for k in 0..size {
+        let sign = if r.get(k, k) < 0.0 { -1.0 } else { 1.0 };
+
+        for i in 0..rows {
+            q_reduced.set(i, k, sign * q.get(i, k));
+        }
+        for j in k..cols {
+            r_reduced.set(k, j, sign * r.get(k, j));
+        }
+    }
+
+    (q_reduced, r_reduced)
+}
+
+/// Computes the singular value decomposition `A = U diag(S) V^T` with the one-sided Jacobi
+/// method, the singular values being sorted in descending order.
+///
+/// With `k = min(rows, cols)`, `U` is `[rows, k]`, `S` is `[k]` and `V` is `[cols, k]`.
+pub(crate) fn svd(matrix: &Matrix) -> (Matrix, Vec<f64>, Matrix) {
+    if matrix.rows < matrix.cols {
+        let (u, s, v) = svd(&matrix.transpose());
+        return (v, s, u);
+    }
+
+    let mut u = matrix.clone();
+    let mut v = Matrix::identity(matrix.cols);
+
+    // Rotate pairs of columns until they are all orthogonal.
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..u.cols {
+            for q in p + 1..u.cols {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for i in 0..u.rows {
+                    let (value_p, value_q) = (u.get(i, p), u.get(i, q));
+                    alpha += value_p * value_p;
+                    beta += value_q * value_q;
+                    gamma += value_p * value_q;
+                }
+
+                if fabs(gamma) <= f64::EPSILON * sqrt(alpha * beta) {
+                    continue;
+                }
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let sign = if zeta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (fabs(zeta) + sqrt(1.0 + zeta * zeta));
+                let c = 1.0 / sqrt(1.0 + t * t);
+
+                u.rotate_columns(p, q, c, c * t);
+                v.rotate_columns(p, q, c, c * t);
+                rotated = true;
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    // The singular values are the norms of the orthogonal columns.
+    let norms: Vec<f64> = (0..u.cols)
+        .map(|j| sqrt((0..u.rows).map(|i| u.get(i, j) * u.get(i, j)).sum()))
+        .collect();
+    let mut order: Vec<usize> = (0..u.cols).collect();
+    order.sort_by(|a, b| norms[*b].partial_cmp(&norms[*a]).unwrap_or(Ordering::Equal));
+
+    let mut u_sorted = Matrix::zeros(u.rows, u.cols);
+    let mut v_sorted = Matrix::zeros(v.rows, v.cols);
+    let mut s = Vec::with_capacity(u.cols);
+
+    for (j, col) in order.into_iter().enumerate() {
+        let norm = norms[col];
+        for i in 0..u.rows {
+            let value = if norm > 0.0 {
+                u.get(i, col) / norm
+            } else {
+                0.0
+            };
+            u_sorted.set(i, j, value);
+        }
+        for i in 0..v.rows {
+            v_sorted.set(i, j, v.get(i, col));
+        }
+        s.push(norm);
+    }
+
+    (u_sorted, s, v_sorted)
+}
+
+/// Computes the eigendecomposition `A = V diag(L) V^T` of a symmetric matrix with the cyclic
+/// Jacobi method, the eigenvalues being sorted in ascending order.
+///
+/// The eigenvectors are the columns of `V`.
+pub(crate) fn symeig(matrix: &Matrix) -> (Vec<f64>, Matrix) {
+    let size = matrix.rows;
+    let mut a = matrix.clone();
+    let mut v = Matrix::identity(size);
+
+    // Rotate pairs of rows and columns until the matrix is diagonal.
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..size {
+            for q in p + 1..size {
+                let (alpha, beta, gamma) = (a.get(p, p), a.get(q, q), a.get(p, q));
+
+                if gamma == 0.0 || fabs(gamma) <= f64::EPSILON * sqrt(fabs(alpha * beta)) {
+                    continue;
+                }
+
+                let theta = (beta - alpha) / (2.0 * gamma);
+                let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (fabs(theta) + sqrt(1.0 + theta * theta));
+                let c = 1.0 / sqrt(1.0 + t * t);
+
+                a.rotate_columns(p, q, c, c * t);
+                a.rotate_rows(p, q, c, c * t);
+                v.rotate_columns(p, q, c, c * t);
+                rotated = true;
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let eigenvalues: Vec<f64> = (0..size).map(|i| a.get(i, i)).collect();
+    let mut order: Vec<usize> = (0..size).collect();
+    order.sort_by(|a, b| {
+        eigenvalues[*a]
+            .partial_cmp(&eigenvalues[*b])
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut v_sorted = Matrix::zeros(size, size);
+    for (j, col) in order.iter().enumerate() {
+        for i in 0..size {
+            v_sorted.set(i, j, v.get(i, *col));
+        }
+    }
+
+    (
+        order.into_iter().map(|i| eigenvalues[i]).collect(),
+        v_sorted,
+    )
+}
@@ -1,13 +1,16 @@
+pub(crate) mod linalg;
 pub(crate) mod stats;
 
 mod api;
 mod data;
 mod element;
+mod hash;
 mod shape;
 
 pub use api::*;
 pub use data::*;
 pub use element::*;
+pub use hash::*;
 pub use shape::*;
 
 pub mod activation;
@@ -1,6 +1,6 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions},
+    ops::{ConvOptions, ConvTransposeOptions, InterpolateMode},
     Int, Tensor,
 };
 
@@ -137,3 +137,58 @@ where
 
     (Tensor::new(output.output), Tensor::new(output.indexes))
 }
+
+/// Applies an [interpolation](crate::ops::ModuleOps::interpolate) over the spatial dimensions.
+pub fn interpolate<B>(
+    x: Tensor<B, 4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::interpolate(x.primitive, output_size, mode))
+}
+
+/// Resamples a grid of positional embeddings to a new resolution with a bilinear
+/// [interpolation](crate::ops::ModuleOps::interpolate), e.g. to fine-tune a vision transformer
+/// on larger images.
+///
+/// # Shapes
+///
+/// - embed: `[height, width, d_model]`
+/// - output: `[new_height, new_width, d_model]`
+pub fn interpolate_pos_embed<B>(embed: Tensor<B, 3>, new_hw: [usize; 2]) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [height, width, d_model] = embed.dims();
+    let [new_height, new_width] = new_hw;
+
+    let x = embed
+        .permute([2, 0, 1])
+        .reshape([1, d_model, height, width]);
+    let output = interpolate(x, new_hw, InterpolateMode::Bilinear);
+
+    output
+        .reshape([d_model, new_height, new_width])
+        .permute([1, 2, 0])
+}
+
+/// Applies a [graph convolution](crate::ops::ModuleOps::graph_conv) over the nodes of a graph.
+pub fn graph_conv<B>(
+    features: Tensor<B, 2>,
+    adjacency: Tensor<B, 2>,
+    weight: Tensor<B, 2>,
+    normalize: bool,
+) -> Tensor<B, 2>
+where
+    B: Backend,
+{
+    Tensor::new(B::graph_conv(
+        features.primitive,
+        adjacency.primitive,
+        weight.primitive,
+        normalize,
+    ))
+}
@@ -27,6 +27,21 @@ pub trait ActivationOps<B: Backend> {
         B::div_scalar(x, 2i32.elem())
     }
 
+    /// Computes `log(sigmoid(x)) = -softplus(-x)` as `min(x, 0) - log(1 + exp(-|x|))`, which
+    /// doesn't overflow for inputs of large magnitude.
+    fn log_sigmoid<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let negative_part = B::clamp_max(tensor.clone(), 0.elem());
+        let softplus_abs = B::log1p(B::exp(B::neg(B::abs(tensor))));
+
+        B::sub(negative_part, softplus_abs)
+    }
+
+    /// Computes `sigmoid(x) = 1 / (1 + exp(-x))` from the stable
+    /// [log sigmoid](ActivationOps::log_sigmoid), so large magnitude inputs don't overflow.
+    fn sigmoid<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::exp(B::log_sigmoid(tensor))
+    }
+
     fn gelu_backward<const D: usize>(
         x: B::TensorPrimitive<D>,
         grad: B::TensorPrimitive<D>,
@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::split::{chunk_ranges, index_ranges, split_ranges};
 use crate::{backend::Backend, tensor::Shape, Data};
 
 /// Bool Tensor API for basic operations, see [tensor](crate::Tensor)
@@ -28,6 +29,32 @@ pub trait BoolTensorOps<B: Backend> {
         tensor: B::BoolTensorPrimitive<D1>,
         shape: Shape<D2>,
     ) -> B::BoolTensorPrimitive<D2>;
+    /// Removes the dimension of size 1 at the given position.
+    fn bool_squeeze<const D1: usize, const D2: usize>(
+        tensor: B::BoolTensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D2> {
+        let dims = B::bool_shape(&tensor).dims;
+        let mut new_dims = [0; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim..].copy_from_slice(&dims[dim + 1..]);
+
+        B::bool_reshape(tensor, Shape::new(new_dims))
+    }
+    /// Inserts a dimension of size 1 at the given position.
+    fn bool_unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: B::BoolTensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D2> {
+        let dims = B::bool_shape(&tensor).dims;
+        let mut new_dims = [1; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim + 1..].copy_from_slice(&dims[dim..]);
+
+        B::bool_reshape(tensor, Shape::new(new_dims))
+    }
     fn bool_index<const D1: usize, const D2: usize>(
         tensor: B::BoolTensorPrimitive<D1>,
         indexes: [Range<usize>; D2],
@@ -43,10 +70,8 @@ pub trait BoolTensorOps<B: Backend> {
         times: usize,
     ) -> B::BoolTensorPrimitive<D> {
         let mut shape = Self::bool_shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indexes_select_all = [0; D].map(|_| {
@@ -59,12 +84,32 @@ pub trait BoolTensorOps<B: Backend> {
         let mut tensor_output = Self::bool_empty(shape, &Self::bool_device(&tensor));
         for i in 0..times {
             let mut indexes = indexes_select_all.clone();
-            indexes[dim] = i..i + 1;
+            indexes[dim] = i * size..(i + 1) * size;
             tensor_output = Self::bool_index_assign(tensor_output, indexes, tensor.clone());
         }
 
         tensor_output
     }
+    fn bool_chunk<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<B::BoolTensorPrimitive<D>> {
+        let shape = Self::bool_shape(&tensor);
+        let ranges = chunk_ranges(shape.dims[dim], chunks);
+
+        index_ranges(tensor, &shape, dim, ranges, Self::bool_index)
+    }
+    fn bool_split<const D: usize>(
+        tensor: B::BoolTensorPrimitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<B::BoolTensorPrimitive<D>> {
+        let shape = Self::bool_shape(&tensor);
+        let ranges = split_ranges(shape.dims[dim], split_size);
+
+        index_ranges(tensor, &shape, dim, ranges, Self::bool_index)
+    }
     fn bool_cat<const D: usize>(
         tensors: Vec<B::BoolTensorPrimitive<D>>,
         dim: usize,
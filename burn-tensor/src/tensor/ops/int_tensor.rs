@@ -1,6 +1,8 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::sort;
+use super::split::{chunk_ranges, index_ranges, split_ranges};
 use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
@@ -25,6 +27,32 @@ pub trait IntTensorOps<B: Backend> {
         tensor: B::IntTensorPrimitive<D1>,
         shape: Shape<D2>,
     ) -> B::IntTensorPrimitive<D2>;
+    /// Removes the dimension of size 1 at the given position.
+    fn int_squeeze<const D1: usize, const D2: usize>(
+        tensor: B::IntTensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D2> {
+        let dims = B::int_shape(&tensor).dims;
+        let mut new_dims = [0; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim..].copy_from_slice(&dims[dim + 1..]);
+
+        B::int_reshape(tensor, Shape::new(new_dims))
+    }
+    /// Inserts a dimension of size 1 at the given position.
+    fn int_unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: B::IntTensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D2> {
+        let dims = B::int_shape(&tensor).dims;
+        let mut new_dims = [1; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim + 1..].copy_from_slice(&dims[dim..]);
+
+        B::int_reshape(tensor, Shape::new(new_dims))
+    }
     fn int_index<const D1: usize, const D2: usize>(
         tensor: B::IntTensorPrimitive<D1>,
         indexes: [Range<usize>; D2],
@@ -72,10 +100,8 @@ pub trait IntTensorOps<B: Backend> {
         times: usize,
     ) -> B::IntTensorPrimitive<D> {
         let mut shape = Self::int_shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indexes_select_all = [0; D].map(|_| {
@@ -88,12 +114,32 @@ pub trait IntTensorOps<B: Backend> {
         let mut tensor_output = Self::int_empty(shape, &Self::int_device(&tensor));
         for i in 0..times {
             let mut indexes = indexes_select_all.clone();
-            indexes[dim] = i..i + 1;
+            indexes[dim] = i * size..(i + 1) * size;
             tensor_output = Self::int_index_assign(tensor_output, indexes, tensor.clone());
         }
 
         tensor_output
     }
+    fn int_chunk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<B::IntTensorPrimitive<D>> {
+        let shape = Self::int_shape(&tensor);
+        let ranges = chunk_ranges(shape.dims[dim], chunks);
+
+        index_ranges(tensor, &shape, dim, ranges, Self::int_index)
+    }
+    fn int_split<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<B::IntTensorPrimitive<D>> {
+        let shape = Self::int_shape(&tensor);
+        let ranges = split_ranges(shape.dims[dim], split_size);
+
+        index_ranges(tensor, &shape, dim, ranges, Self::int_index)
+    }
     fn int_cat<const D: usize>(
         tensors: Vec<B::IntTensorPrimitive<D>>,
         dim: usize,
@@ -175,6 +221,24 @@ pub trait IntTensorOps<B: Backend> {
     fn int_neg<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
         Self::int_mul_scalar(tensor, (-1.0).elem::<B::IntElem>())
     }
+    fn int_abs<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        let negative = B::int_lower_elem(tensor.clone(), 0_i64.elem());
+
+        B::int_mask_scatter(tensor.clone(), negative, B::int_neg(tensor))
+    }
+    /// Returns the sign of each element: `-1` for negative values, `1` for positive values and `0`
+    /// for zeros.
+    fn int_sign<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D> {
+        let zeros = B::int_zeros(B::int_shape(&tensor), &B::int_device(&tensor));
+        let positive = B::int_greater_elem(tensor.clone(), 0_i64.elem());
+        let negative = B::int_lower_elem(tensor, 0_i64.elem());
+
+        B::int_mask_fill(
+            B::int_mask_fill(zeros, positive, 1_i64.elem()),
+            negative,
+            (-1_i64).elem(),
+        )
+    }
     fn int_zeros<const D: usize>(shape: Shape<D>, device: &B::Device) -> B::IntTensorPrimitive<D>;
     fn int_ones<const D: usize>(shape: Shape<D>, device: &B::Device) -> B::IntTensorPrimitive<D>;
     fn int_sum<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<1>;
@@ -182,6 +246,32 @@ pub trait IntTensorOps<B: Backend> {
         tensor: B::IntTensorPrimitive<D>,
         dim: usize,
     ) -> B::IntTensorPrimitive<D>;
+    /// Multiplies all elements together.
+    fn int_prod<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<1> {
+        let tensor = (0..D).fold(tensor, |tensor, dim| B::int_prod_dim(tensor, dim));
+
+        B::int_reshape(tensor, Shape::new([1]))
+    }
+    /// Multiplies all elements along the given dimension, keeping it with a size of 1.
+    ///
+    /// The default implementation multiplies the slices of the dimension one after the other.
+    fn int_prod_dim<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::IntTensorPrimitive<D> {
+        let shape = B::int_shape(&tensor);
+        let mut indexes = shape.dims.map(|size| 0..size);
+
+        indexes[dim] = 0..1;
+        let mut output = B::int_index(tensor.clone(), indexes.clone());
+
+        for i in 1..shape.dims[dim] {
+            indexes[dim] = i..i + 1;
+            output = B::int_mul(output, B::int_index(tensor.clone(), indexes.clone()));
+        }
+
+        output
+    }
     fn int_mean<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<1>;
     fn int_mean_dim<const D: usize>(
         tensor: B::IntTensorPrimitive<D>,
@@ -208,14 +298,14 @@ pub trait IntTensorOps<B: Backend> {
     ) -> B::IntTensorPrimitive<D> {
         let index = B::int_argmax(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
     fn int_max_dim_with_indexes<const D: usize>(
         tensor: B::IntTensorPrimitive<D>,
         dim: usize,
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::int_argmax(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
+        let values = B::int_gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -231,15 +321,60 @@ pub trait IntTensorOps<B: Backend> {
     ) -> B::IntTensorPrimitive<D> {
         let index = B::int_argmin(tensor.clone(), dim);
 
-        B::int_gather(D - 1, tensor, index)
+        B::int_gather(dim, tensor, index)
     }
     fn int_min_dim_with_indexes<const D: usize>(
         tensor: B::IntTensorPrimitive<D>,
         dim: usize,
     ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::int_argmin(tensor.clone(), dim);
-        let values = B::int_gather(D - 1, tensor, index.clone());
+        let values = B::int_gather(dim, tensor, index.clone());
 
         (values, index)
     }
+    /// Returns the permutation sorting the tensor along the given dimension.
+    ///
+    /// The sort is stable, so equal elements keep their original order.
+    fn int_argsort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+        let indexes = sort::int_argsort_data(&B::int_into_data(tensor), dim, descending);
+
+        B::int_from_data(indexes.convert(), &device)
+    }
+    fn int_sort<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        B::int_sort_with_indexes(tensor, dim, descending).0
+    }
+    fn int_sort_with_indexes<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let indexes = B::int_argsort(tensor.clone(), dim, descending);
+        let values = B::int_gather(dim, tensor, indexes.clone());
+
+        (values, indexes)
+    }
+    fn int_topk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let mut ranges = B::int_shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = 0..k;
+
+        let (values, indexes) = B::int_sort_with_indexes(tensor, dim, true);
+
+        (
+            B::int_index(values, ranges.clone()),
+            B::int_index(indexes, ranges),
+        )
+    }
 }
@@ -2,10 +2,14 @@ mod activation;
 mod bool_tensor;
 mod int_tensor;
 mod modules;
+mod optim;
+mod sort;
+mod split;
 mod tensor;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
 pub use modules::*;
+pub use optim::*;
 pub use tensor::*;
@@ -1,4 +1,4 @@
-use super::{conv, pool};
+use super::{conv, graph, interpolate, pool, InterpolateMode};
 use crate::backend::Backend;
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -211,4 +211,37 @@ pub trait ModuleOps<B: Backend> {
         output_grad: B::TensorPrimitive<4>,
         indexes: B::IntTensorPrimitive<4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Resizes the two spatial dimensions of the input to the given output size.
+    ///
+    /// # Shapes
+    ///
+    /// x:      [batch_size, channels, height_in, width_in],
+    /// output: [batch_size, channels, height_out, width_out],
+    fn interpolate(
+        x: B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+        mode: InterpolateMode,
+    ) -> B::TensorPrimitive<4> {
+        interpolate::interpolate_from_index_select::<B>(x, output_size, mode)
+    }
+    /// Applies a [graph convolution](https://arxiv.org/abs/1609.02907) `A X W`.
+    ///
+    /// When `normalize` is true, the adjacency matrix is replaced by its symmetric normalization
+    /// with self-loops `D^-1/2 (A + I) D^-1/2`, where `D` is the degree matrix of `A + I`.
+    ///
+    /// # Shapes
+    ///
+    /// features:  [num_nodes, d_input],
+    /// adjacency: [num_nodes, num_nodes],
+    /// weight:    [d_input, d_output],
+    /// output:    [num_nodes, d_output],
+    fn graph_conv(
+        features: B::TensorPrimitive<2>,
+        adjacency: B::TensorPrimitive<2>,
+        weight: B::TensorPrimitive<2>,
+        normalize: bool,
+    ) -> B::TensorPrimitive<2> {
+        graph::graph_conv_from_matmul::<B>(features, adjacency, weight, normalize)
+    }
 }
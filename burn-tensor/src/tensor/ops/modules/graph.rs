@@ -0,0 +1,34 @@
+use crate::{backend::Backend, Data, Shape};
+use alloc::vec::Vec;
+
+pub(crate) fn graph_conv_from_matmul<B: Backend>(
+    features: B::TensorPrimitive<2>,
+    adjacency: B::TensorPrimitive<2>,
+    weight: B::TensorPrimitive<2>,
+    normalize: bool,
+) -> B::TensorPrimitive<2> {
+    let adjacency = match normalize {
+        true => normalize_adjacency::<B>(adjacency),
+        false => adjacency,
+    };
+
+    B::matmul(adjacency, B::matmul(features, weight))
+}
+
+/// Computes `D^-1/2 (A + I) D^-1/2`, where `D` is the diagonal degree matrix of `A + I`.
+fn normalize_adjacency<B: Backend>(adjacency: B::TensorPrimitive<2>) -> B::TensorPrimitive<2> {
+    let [num_nodes, _] = B::shape(&adjacency).dims;
+    let device = B::device(&adjacency);
+
+    let identity: Vec<f32> = (0..num_nodes * num_nodes)
+        .map(|i| if i % (num_nodes + 1) == 0 { 1.0 } else { 0.0 })
+        .collect();
+    let identity = Data::new(identity, Shape::new([num_nodes, num_nodes])).convert();
+    let adjacency = B::add(adjacency, B::from_data(identity, &device));
+
+    // The self-loops make every degree at least one, so the inverse square root is finite.
+    let degrees = B::powf(B::sum_dim(adjacency.clone(), 1), -0.5);
+    let adjacency = B::mul(adjacency, degrees.clone());
+
+    B::mul(adjacency, B::reshape(degrees, Shape::new([1, num_nodes])))
+}
@@ -0,0 +1,77 @@
+use crate::{backend::Backend, Data, Shape};
+use alloc::vec::Vec;
+
+/// Interpolation mode used by [interpolate](crate::ops::ModuleOps::interpolate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMode {
+    /// Each output value takes the value of its nearest input value.
+    Nearest,
+    /// Each output value is linearly interpolated from its two closest input values on each
+    /// spatial dimension, without aligning the corners.
+    Bilinear,
+}
+
+pub(crate) fn interpolate_from_index_select<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    output_size: [usize; 2],
+    mode: InterpolateMode,
+) -> B::TensorPrimitive<4> {
+    let x = interpolate_dim::<B>(x, 2, output_size[0], mode);
+    interpolate_dim::<B>(x, 3, output_size[1], mode)
+}
+
+fn interpolate_dim<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    dim: usize,
+    size_out: usize,
+    mode: InterpolateMode,
+) -> B::TensorPrimitive<4> {
+    let size_in = B::shape(&x).dims[dim];
+
+    if size_in == size_out {
+        return x;
+    }
+
+    let device = B::device(&x);
+    let scale = size_in as f64 / size_out as f64;
+    let indexes = |indexes: Vec<i64>| {
+        B::int_from_data(
+            Data::new(indexes, Shape::new([size_out])).convert(),
+            &device,
+        )
+    };
+
+    match mode {
+        InterpolateMode::Nearest => {
+            let nearest = (0..size_out)
+                .map(|i| (libm::floor(i as f64 * scale) as usize).min(size_in - 1) as i64)
+                .collect();
+
+            B::index_select(x, dim, indexes(nearest))
+        }
+        InterpolateMode::Bilinear => {
+            let mut lower = Vec::with_capacity(size_out);
+            let mut upper = Vec::with_capacity(size_out);
+            let mut weights = Vec::with_capacity(size_out);
+
+            for i in 0..size_out {
+                let source = ((i as f64 + 0.5) * scale - 0.5).max(0.0);
+                let index = (libm::floor(source) as usize).min(size_in - 1);
+
+                lower.push(index as i64);
+                upper.push(usize::min(index + 1, size_in - 1) as i64);
+                weights.push(source - index as f64);
+            }
+
+            let mut shape = [1; 4];
+            shape[dim] = size_out;
+            let weights = B::from_data(Data::new(weights, Shape::new(shape)).convert(), &device);
+
+            let lower = B::index_select(x.clone(), dim, indexes(lower));
+            let upper = B::index_select(x, dim, indexes(upper));
+            let delta = B::sub(upper, lower.clone());
+
+            B::add(lower, B::mul(delta, weights))
+        }
+    }
+}
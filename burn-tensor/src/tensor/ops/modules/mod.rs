@@ -2,5 +2,8 @@ pub mod conv;
 pub mod pool;
 
 mod base;
+mod graph;
+mod interpolate;
 
 pub use base::*;
+pub use interpolate::InterpolateMode;
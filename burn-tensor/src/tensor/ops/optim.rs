@@ -0,0 +1,38 @@
+/// Options of the fused [adam_update](super::TensorOps::adam_update) operation.
+#[derive(new, Debug, Clone)]
+pub struct AdamUpdateOptions {
+    /// Learning rate.
+    pub lr: f32,
+    /// Exponential decay rate of the first moment.
+    pub beta_1: f32,
+    /// Exponential decay rate of the second moment.
+    pub beta_2: f32,
+    /// Value added to the denominator for numerical stability.
+    pub epsilon: f32,
+    /// Number of steps done, including the current one, used for bias correction.
+    pub time: usize,
+}
+
+/// Options of the fused [rmsprop_update](super::TensorOps::rmsprop_update) operation.
+#[derive(new, Debug, Clone)]
+pub struct RmsPropUpdateOptions {
+    /// Learning rate.
+    pub lr: f32,
+    /// Smoothing constant of the running average of the squared gradient.
+    pub alpha: f32,
+    /// Value added to the denominator for numerical stability.
+    pub epsilon: f32,
+}
+
+/// Options of the fused [sgd_momentum_update](super::TensorOps::sgd_momentum_update) operation.
+#[derive(new, Debug, Clone)]
+pub struct SgdMomentumUpdateOptions {
+    /// Learning rate.
+    pub lr: f32,
+    /// Momentum factor.
+    pub momentum: f32,
+    /// Decoupled weight decay, applied directly on the parameter.
+    pub weight_decay: f32,
+    /// Whether to use the Nesterov variant.
+    pub nesterov: bool,
+}
@@ -0,0 +1,97 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Data, Element};
+
+/// Computes the permutation sorting every lane of the float data along the given dimension.
+///
+/// The sort is stable, so equal elements keep their original order, and `NaN` values are placed
+/// last independently of the order.
+pub(crate) fn argsort_data<E: Element, const D: usize>(
+    data: &Data<E, D>,
+    dim: usize,
+    descending: bool,
+) -> Data<i64, D> {
+    argsort_by(data, dim, |a, b| {
+        let a = a.elem::<f64>();
+        let b = b.elem::<f64>();
+
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => match descending {
+                true => b.partial_cmp(&a).unwrap(),
+                false => a.partial_cmp(&b).unwrap(),
+            },
+        }
+    })
+}
+
+/// Computes the permutation sorting every lane of the integer data along the given dimension.
+///
+/// The elements are compared as `i64`, so large values don't lose precision, and the sort is
+/// stable, so equal elements keep their original order.
+pub(crate) fn int_argsort_data<E: Element, const D: usize>(
+    data: &Data<E, D>,
+    dim: usize,
+    descending: bool,
+) -> Data<i64, D> {
+    argsort_by(data, dim, |a, b| {
+        let a = a.elem::<i64>();
+        let b = b.elem::<i64>();
+
+        match descending {
+            true => b.cmp(&a),
+            false => a.cmp(&b),
+        }
+    })
+}
+
+fn argsort_by<E: Element, F, const D: usize>(data: &Data<E, D>, dim: usize, cmp: F) -> Data<i64, D>
+where
+    F: Fn(&E, &E) -> Ordering,
+{
+    let dims = data.shape.dims;
+    let size = dims[dim];
+    let stride: usize = dims[dim + 1..].iter().product();
+    let num_lanes = data.value.len() / usize::max(size, 1);
+
+    let mut indexes = vec![0; data.value.len()];
+    let mut order = Vec::with_capacity(size);
+
+    for lane in 0..num_lanes {
+        let start = (lane / stride) * size * stride + lane % stride;
+
+        order.clear();
+        order.extend(0..size);
+        order.sort_by(|a, b| {
+            cmp(
+                &data.value[start + a * stride],
+                &data.value[start + b * stride],
+            )
+        });
+
+        for (position, index) in order.iter().enumerate() {
+            indexes[start + position * stride] = *index as i64;
+        }
+    }
+
+    Data::new(indexes, data.shape.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn int_argsort_should_not_lose_precision() {
+        // Both values round to the same `f64`.
+        let data = Data::from([i64::MAX, i64::MAX - 1]);
+
+        let indexes = int_argsort_data(&data, 0, false);
+
+        assert_eq!(indexes, Data::from([1, 0]));
+    }
+}
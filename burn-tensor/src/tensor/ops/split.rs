@@ -0,0 +1,50 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::Shape;
+
+/// The ranges of the pieces of size `split_size` covering `0..size`, the last piece being smaller
+/// when `size` isn't divisible by `split_size`.
+pub(crate) fn split_ranges(size: usize, split_size: usize) -> Vec<Range<usize>> {
+    (0..size)
+        .step_by(split_size)
+        .map(|start| start..usize::min(start + split_size, size))
+        .collect()
+}
+
+/// The ranges of the `chunks` pieces covering `0..size`, whose sizes differ by at most one.
+///
+/// When `size` is smaller than `chunks`, only `size` pieces of size one are returned.
+pub(crate) fn chunk_ranges(size: usize, chunks: usize) -> Vec<Range<usize>> {
+    let chunks = usize::max(usize::min(chunks, size), 1);
+    let (chunk_size, remainder) = (size / chunks, size % chunks);
+    let mut start = 0;
+
+    (0..chunks)
+        .map(|i| {
+            let end = start + chunk_size + usize::from(i < remainder);
+            let range = start..end;
+            start = end;
+            range
+        })
+        .collect()
+}
+
+/// Index the tensor with each of the ranges along the given dimension, keeping the other
+/// dimensions whole.
+pub(crate) fn index_ranges<T: Clone, const D: usize>(
+    tensor: T,
+    shape: &Shape<D>,
+    dim: usize,
+    ranges: Vec<Range<usize>>,
+    index: impl Fn(T, [Range<usize>; D]) -> T,
+) -> Vec<T> {
+    ranges
+        .into_iter()
+        .map(|range| {
+            let mut indexes = shape.dims.map(|size| 0..size);
+            indexes[dim] = range;
+            index(tensor.clone(), indexes)
+        })
+        .collect()
+}
@@ -1,6 +1,12 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use core::ops::Range;
+use rand::{rngs::StdRng, SeedableRng};
 
+use super::sort;
+use super::split::{chunk_ranges, index_ranges, split_ranges};
+use super::{AdamUpdateOptions, RmsPropUpdateOptions, SgdMomentumUpdateOptions};
+use crate::tensor::linalg::{self, Matrix};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
 
 /// Operations on float tensors.
@@ -46,10 +52,8 @@ pub trait TensorOps<B: Backend> {
         times: usize,
     ) -> B::TensorPrimitive<D> {
         let mut shape = B::shape(&tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indexes_select_all = [0; D].map(|_| {
@@ -62,12 +66,32 @@ pub trait TensorOps<B: Backend> {
         let mut tensor_output = B::empty(shape, &B::device(&tensor));
         for i in 0..times {
             let mut indexes = indexes_select_all.clone();
-            indexes[dim] = i..i + 1;
+            indexes[dim] = i * size..(i + 1) * size;
             tensor_output = B::index_assign(tensor_output, indexes, tensor.clone());
         }
 
         tensor_output
     }
+    fn chunk<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        chunks: usize,
+        dim: usize,
+    ) -> Vec<B::TensorPrimitive<D>> {
+        let shape = B::shape(&tensor);
+        let ranges = chunk_ranges(shape.dims[dim], chunks);
+
+        index_ranges(tensor, &shape, dim, ranges, B::index)
+    }
+    fn split<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        split_size: usize,
+        dim: usize,
+    ) -> Vec<B::TensorPrimitive<D>> {
+        let shape = B::shape(&tensor);
+        let ranges = split_ranges(shape.dims[dim], split_size);
+
+        index_ranges(tensor, &shape, dim, ranges, B::index)
+    }
     fn add<const D: usize>(
         lhs: B::TensorPrimitive<D>,
         rhs: B::TensorPrimitive<D>,
@@ -104,9 +128,75 @@ pub trait TensorOps<B: Backend> {
         lhs: B::TensorPrimitive<D>,
         rhs: B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
+    /// Matrix multiplication of the transposed left hand side with the right hand side.
+    ///
+    /// Backends can override this to avoid materializing the transposed matrix.
+    fn matmul_transpose_a<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        B::matmul(B::transpose(lhs), rhs)
+    }
+    /// Matrix multiplication of the left hand side with the transposed right hand side.
+    ///
+    /// Backends can override this to avoid materializing the transposed matrix.
+    fn matmul_transpose_b<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        B::matmul(lhs, B::transpose(rhs))
+    }
     fn neg<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
         Self::mul_scalar(tensor, (-1.0_f32).elem::<B::FloatElem>())
     }
+    fn abs<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let negative = B::lower_elem(tensor.clone(), 0.0_f32.elem());
+
+        B::mask_scatter(tensor.clone(), negative, B::neg(tensor))
+    }
+    /// Returns the sign of each element: `-1` for negative values, `1` for positive values and `0`
+    /// for zeros.
+    ///
+    /// NaN values have no sign and are mapped to `0`.
+    fn sign<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let zeros = B::zeros(B::shape(&tensor), &B::device(&tensor));
+        let positive = B::greater_elem(tensor.clone(), 0.elem());
+        let negative = B::lower_elem(tensor, 0.elem());
+
+        B::mask_fill(
+            B::mask_fill(zeros, positive, 1.elem()),
+            negative,
+            (-1.0_f32).elem(),
+        )
+    }
+    /// Clamps every element of the tensor to be greater or equal to `min`.
+    fn clamp_min<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::lower_elem(tensor.clone(), min);
+
+        B::mask_fill(tensor, mask, min)
+    }
+    /// Clamps every element of the tensor to be lower or equal to `max`.
+    fn clamp_max<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        let mask = B::greater_elem(tensor.clone(), max);
+
+        B::mask_fill(tensor, mask, max)
+    }
+    /// Clamps every element of the tensor in the range `[min, max]`.
+    ///
+    /// The upper bound is applied first, so every element is clamped to `min` when `min > max`.
+    fn clamp<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        min: B::FloatElem,
+        max: B::FloatElem,
+    ) -> B::TensorPrimitive<D> {
+        B::clamp_min(B::clamp_max(tensor, max), min)
+    }
     fn transpose<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
         Self::swap_dims(tensor, D - 2, D - 1)
     }
@@ -115,10 +205,128 @@ pub trait TensorOps<B: Backend> {
         dim1: usize,
         dim2: usize,
     ) -> B::TensorPrimitive<D>;
+    /// Permutes the dimensions of the tensor, the dimension `i` of the output being the dimension
+    /// `axes[i]` of the input.
+    fn permute<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::TensorPrimitive<D> {
+        let mut current: [usize; D] = core::array::from_fn(|i| i);
+        let mut tensor = tensor;
+
+        for (i, axis) in axes.iter().enumerate() {
+            let j = i + current[i..].iter().position(|dim| dim == axis).unwrap();
+
+            if j != i {
+                tensor = B::swap_dims(tensor, i, j);
+                current.swap(i, j);
+            }
+        }
+
+        tensor
+    }
+    /// Reverses the order of the elements along each of the given dimensions.
+    fn flip<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dims: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+        let device = B::device(&tensor);
+
+        dims.iter().fold(tensor, |tensor, dim| {
+            let size = shape.dims[*dim];
+            let indexes: Vec<i64> = (0..size as i64).rev().collect();
+            let indexes = Data::new(indexes, Shape::new([size])).convert();
+
+            B::index_select(tensor, *dim, B::int_from_data(indexes, &device))
+        })
+    }
+    /// Converts a tensor from the channels first layout `[batch_size, channels, height, width]`
+    /// to the channels last layout `[batch_size, height, width, channels]`.
+    fn nchw_to_nhwc(tensor: B::TensorPrimitive<4>) -> B::TensorPrimitive<4> {
+        let tensor = B::swap_dims(tensor, 1, 2);
+        B::swap_dims(tensor, 2, 3)
+    }
+    /// Converts a tensor from the channels last layout `[batch_size, height, width, channels]`
+    /// to the channels first layout `[batch_size, channels, height, width]`.
+    fn nhwc_to_nchw(tensor: B::TensorPrimitive<4>) -> B::TensorPrimitive<4> {
+        let tensor = B::swap_dims(tensor, 2, 3);
+        B::swap_dims(tensor, 1, 2)
+    }
+    /// Splits a `[batch_size, channels, height, width]` image into non-overlapping square
+    /// patches, returning a `[batch_size, num_patches, channels * patch_size * patch_size]`
+    /// sequence with the patches in row-major order.
+    fn patchify(tensor: B::TensorPrimitive<4>, patch_size: usize) -> B::TensorPrimitive<3> {
+        let [batch_size, channels, height, width] = B::shape(&tensor).dims;
+        let [num_rows, num_cols] = [height / patch_size, width / patch_size];
+
+        let tensor = B::reshape(
+            tensor,
+            Shape::new([
+                batch_size, channels, num_rows, patch_size, num_cols, patch_size,
+            ]),
+        );
+        // [batch_size, num_rows, num_cols, channels, patch_size, patch_size]
+        let tensor = B::swap_dims(tensor, 1, 2);
+        let tensor = B::swap_dims(tensor, 2, 4);
+        let tensor = B::swap_dims(tensor, 3, 4);
+
+        B::reshape(
+            tensor,
+            Shape::new([
+                batch_size,
+                num_rows * num_cols,
+                channels * patch_size * patch_size,
+            ]),
+        )
+    }
     fn reshape<const D1: usize, const D2: usize>(
         tensor: B::TensorPrimitive<D1>,
         shape: Shape<D2>,
     ) -> B::TensorPrimitive<D2>;
+    /// Removes the dimension of size 1 at the given position.
+    fn squeeze<const D1: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D2> {
+        let dims = B::shape(&tensor).dims;
+        let mut new_dims = [0; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim..].copy_from_slice(&dims[dim + 1..]);
+
+        B::reshape(tensor, Shape::new(new_dims))
+    }
+    /// Inserts a dimension of size 1 at the given position.
+    fn unsqueeze_dim<const D1: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D1>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D2> {
+        let dims = B::shape(&tensor).dims;
+        let mut new_dims = [1; D2];
+
+        new_dims[..dim].copy_from_slice(&dims[..dim]);
+        new_dims[dim + 1..].copy_from_slice(&dims[dim..]);
+
+        B::reshape(tensor, Shape::new(new_dims))
+    }
+    /// Broadcasts the tensor to the given shape by repeating its dimensions of size 1.
+    ///
+    /// When the target shape has more dimensions, the tensor is aligned with its trailing
+    /// dimensions and the new dimensions are added at the front.
+    fn expand<const D1: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D1>,
+        shape: Shape<D2>,
+    ) -> B::TensorPrimitive<D2> {
+        let mut dims = [1; D2];
+        dims[D2 - D1..].copy_from_slice(&B::shape(&tensor).dims);
+        let tensor = B::reshape(tensor, Shape::new(dims));
+
+        (0..D2).fold(tensor, |tensor, dim| match dims[dim] == shape.dims[dim] {
+            true => tensor,
+            false => B::repeat(tensor, dim, shape.dims[dim]),
+        })
+    }
     fn gather<const D: usize>(
         dim: usize,
         tensor: B::TensorPrimitive<D>,
@@ -217,9 +425,176 @@ pub trait TensorOps<B: Backend> {
     }
     fn sum<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1>;
     fn sum_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D>;
+    /// Multiplies all elements together.
+    fn prod<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1> {
+        let tensor = (0..D).fold(tensor, |tensor, dim| B::prod_dim(tensor, dim));
+
+        B::reshape(tensor, Shape::new([1]))
+    }
+    /// Multiplies all elements along the given dimension, keeping it with a size of 1.
+    ///
+    /// The default implementation multiplies the slices of the dimension one after the other.
+    fn prod_dim<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&tensor);
+        let mut indexes = shape.dims.map(|size| 0..size);
+
+        indexes[dim] = 0..1;
+        let mut output = B::index(tensor.clone(), indexes.clone());
+
+        for i in 1..shape.dims[dim] {
+            indexes[dim] = i..i + 1;
+            output = B::mul(output, B::index(tensor.clone(), indexes.clone()));
+        }
+
+        output
+    }
     fn mean<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1>;
     fn mean_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize)
         -> B::TensorPrimitive<D>;
+    /// Sum all elements using the [Neumaier](https://en.wikipedia.org/wiki/Kahan_summation_algorithm)
+    /// compensated summation, accumulated in double precision.
+    ///
+    /// The elements are summed sequentially on the host, so this is slower than [sum](TensorOps::sum)
+    /// and should only be used when the accuracy of the reduction matters.
+    fn sum_kahan<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<1> {
+        let device = B::device(&tensor);
+        let data = B::into_data(tensor);
+
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+
+        for value in data.value {
+            let value: f64 = value.elem();
+            let total = sum + value;
+
+            if libm::fabs(sum) >= libm::fabs(value) {
+                compensation += (sum - total) + value;
+            } else {
+                compensation += (value - total) + sum;
+            }
+
+            sum = total;
+        }
+
+        let data = Data::new(vec![(sum + compensation).elem()], Shape::new([1]));
+
+        B::from_data(data, &device)
+    }
+    fn sum_dims<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dims: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        dims.iter()
+            .fold(tensor, |tensor, dim| B::sum_dim(tensor, *dim))
+    }
+    fn mean_dims<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dims: &[usize],
+    ) -> B::TensorPrimitive<D> {
+        dims.iter()
+            .fold(tensor, |tensor, dim| B::mean_dim(tensor, *dim))
+    }
+    /// Sums the rows of the tensor sharing the same segment id along the first dimension.
+    fn segment_sum<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        segment_ids: B::IntTensorPrimitive<1>,
+        num_segments: usize,
+    ) -> B::TensorPrimitive<D> {
+        let mut shape = B::shape(&tensor);
+        shape.dims[0] = num_segments;
+        let output = B::zeros(shape, &B::device(&tensor));
+
+        B::index_select_assign(output, 0, segment_ids, tensor)
+    }
+    /// Averages the rows of the tensor sharing the same segment id along the first dimension,
+    /// empty segments being zeros.
+    fn segment_mean<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        segment_ids: B::IntTensorPrimitive<1>,
+        num_segments: usize,
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let num_rows = B::shape(&tensor).dims[0];
+
+        let counts = B::index_select_assign(
+            B::zeros(Shape::new([num_segments]), &device),
+            0,
+            segment_ids.clone(),
+            B::ones(Shape::new([num_rows]), &device),
+        );
+        let mut shape = [1; D];
+        shape[0] = num_segments;
+        let counts = B::reshape(B::clamp_min(counts, 1.0_f32.elem()), Shape::new(shape));
+
+        B::div(B::segment_sum(tensor, segment_ids, num_segments), counts)
+    }
+    /// Takes the maximum of the rows of the tensor sharing the same segment id along the first
+    /// dimension, empty segments being zeros.
+    fn segment_max<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        segment_ids: B::IntTensorPrimitive<1>,
+        num_segments: usize,
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let segment_ids = B::int_into_data(segment_ids).convert::<i64>().value;
+        let mut shape = B::shape(&tensor);
+        shape.dims[0] = 1;
+
+        let segments = (0..num_segments)
+            .map(|segment| {
+                let rows: Vec<i64> = segment_ids
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, id)| **id == segment as i64)
+                    .map(|(row, _)| row as i64)
+                    .collect();
+
+                if rows.is_empty() {
+                    return B::zeros(shape.clone(), &device);
+                }
+
+                let num_rows = rows.len();
+                let rows =
+                    B::int_from_data(Data::new(rows, Shape::new([num_rows])).convert(), &device);
+                B::max_dim(B::index_select(tensor.clone(), 0, rows), 0)
+            })
+            .collect();
+
+        B::cat(segments, 0)
+    }
+    /// Computes the sample variance along the given dimension, dividing by `N - 1`.
+    fn var<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        let n = B::shape(&tensor).dims[dim] - 1;
+        let mean = B::mean_dim(tensor.clone(), dim);
+        let squared = B::powf(B::sub(tensor, mean), 2.0);
+
+        B::div_scalar(B::sum_dim(squared, dim), (n as f32).elem())
+    }
+    /// Computes the population variance along the given dimension, dividing by `N`.
+    fn var_bias<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let n = B::shape(&tensor).dims[dim];
+        let mean = B::mean_dim(tensor.clone(), dim);
+        let squared = B::powf(B::sub(tensor, mean), 2.0);
+
+        B::div_scalar(B::sum_dim(squared, dim), (n as f32).elem())
+    }
+    /// Computes the sample standard deviation along the given dimension.
+    fn std<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
+        B::sqrt(B::var(tensor, dim))
+    }
+    /// Computes the population standard deviation along the given dimension.
+    fn std_bias<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        B::sqrt(B::var_bias(tensor, dim))
+    }
     fn to_full_precision<const D: usize>(
         tensor: &B::TensorPrimitive<D>,
     ) -> <B::FullPrecisionBackend as Backend>::TensorPrimitive<D>;
@@ -229,16 +604,362 @@ pub trait TensorOps<B: Backend> {
     fn exp<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn log<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn log1p<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    /// Computes the base 2 logarithm of every element.
+    fn log2<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::div_scalar(B::log(tensor), core::f64::consts::LN_2.elem())
+    }
+    /// Computes the base 10 logarithm of every element.
+    fn log10<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::div_scalar(B::log(tensor), core::f64::consts::LN_10.elem())
+    }
     fn powf<const D: usize>(tensor: B::TensorPrimitive<D>, value: f32) -> B::TensorPrimitive<D>;
+    /// Raises each element of `lhs` to the power of the corresponding element of `rhs`, with the
+    /// same broadcasting rules as the other binary operations.
+    ///
+    /// The default implementation computes `exp(rhs * log(|lhs|))`, multiplied by `cos(pi * rhs)`
+    /// for negative bases, which is the sign of the result when the exponent is an integer. Like
+    /// [powf](TensorOps::powf), negative bases with non-integer exponents give `NaN`, and zero
+    /// exponents give one, even for a zero base. The exponents are read back to find the ones that
+    /// aren't integers.
+    fn powf_tensor<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let lhs = B::add(lhs, B::zeros(B::shape(&rhs), &B::device(&rhs)));
+        let rhs = B::add(rhs, B::zeros(B::shape(&lhs), &B::device(&lhs)));
+        let negative = B::lower_elem(lhs.clone(), 0.elem());
+        let exponents = B::to_data(&rhs);
+        let fractional = exponents
+            .value
+            .into_iter()
+            .map(|value| value.elem::<f64>() % 1.0 != 0.0)
+            .collect();
+        let fractional =
+            B::bool_from_data(Data::new(fractional, exponents.shape), &B::device(&rhs));
+        let zero_exponent = B::equal_elem(rhs.clone(), 0.elem());
+
+        let magnitude = B::exp(B::mul(rhs.clone(), B::log(B::abs(lhs))));
+        let sign = B::cos(B::mul_scalar(rhs, core::f32::consts::PI.elem()));
+        let signed = B::mask_fill(B::mul(magnitude.clone(), sign), fractional, f32::NAN.elem());
+
+        let output = B::mask_scatter(magnitude, negative, signed);
+        B::mask_fill(output, zero_exponent, 1.elem())
+    }
     fn sqrt<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    /// Computes the reciprocal `1 / x` of every element, zeros becoming infinite.
+    fn recip<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        B::powf(tensor, -1.0)
+    }
     fn cos<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn sin<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn tanh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    /// Computes the arctangent of every element.
+    fn atan<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::atan)
+    }
+    /// Computes the arcsine of every element, `NaN` outside of `[-1, 1]`.
+    fn asin<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::asin)
+    }
+    /// Computes the arccosine of every element, `NaN` outside of `[-1, 1]`.
+    fn acos<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::acos)
+    }
+    /// Computes the hyperbolic sine of every element, `(exp(x) - exp(-x)) / 2`.
+    fn sinh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let exp = B::exp(tensor.clone());
+        let exp_neg = B::exp(B::neg(tensor));
+
+        B::div_scalar(B::sub(exp, exp_neg), 2.elem())
+    }
+    /// Computes the hyperbolic cosine of every element, `(exp(x) + exp(-x)) / 2`.
+    fn cosh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        let exp = B::exp(tensor.clone());
+        let exp_neg = B::exp(B::neg(tensor));
+
+        B::div_scalar(B::add(exp, exp_neg), 2.elem())
+    }
     fn erf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    /// Rounds every element down to the nearest integer.
+    fn floor<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::floor)
+    }
+    /// Rounds every element up to the nearest integer.
+    fn ceil<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::ceil)
+    }
+    /// Rounds every element to the nearest integer, ties being rounded to the nearest even
+    /// integer.
+    fn round<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        map_elements::<B, D>(tensor, libm::rint)
+    }
     fn cat<const D: usize>(
         tensors: Vec<B::TensorPrimitive<D>>,
         dim: usize,
     ) -> B::TensorPrimitive<D>;
+    fn cross<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> B::TensorPrimitive<D> {
+        let shape = B::shape(&lhs);
+        let component = |tensor: &B::TensorPrimitive<D>, index: usize| {
+            let mut ranges = shape.dims.map(|size| 0..size);
+            ranges[dim] = index..index + 1;
+            B::index(tensor.clone(), ranges)
+        };
+        let term = |i: usize, j: usize| {
+            B::sub(
+                B::mul(component(&lhs, i), component(&rhs, j)),
+                B::mul(component(&lhs, j), component(&rhs, i)),
+            )
+        };
+
+        B::cat(vec![term(1, 2), term(2, 0), term(0, 1)], dim)
+    }
+    fn pad_reflect<const D: usize>(
+        mut tensor: B::TensorPrimitive<D>,
+        pads: [(usize, usize); D],
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let indexes = |values: Vec<i64>| {
+            let num_values = values.len();
+            B::int_from_data(
+                Data::new(values, Shape::new([num_values])).convert(),
+                &device,
+            )
+        };
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let size = B::shape(&tensor).dims[dim];
+            let reflect = |range: Range<usize>| {
+                let values = range.rev().map(|index| index as i64).collect();
+                B::index_select(tensor.clone(), dim, indexes(values))
+            };
+
+            let left = (before > 0).then(|| reflect(1..before + 1));
+            let right = (after > 0).then(|| reflect(size - 1 - after..size - 1));
+            let tensors = left
+                .into_iter()
+                .chain(Some(tensor.clone()))
+                .chain(right)
+                .collect();
+
+            tensor = B::cat(tensors, dim);
+        }
+
+        tensor
+    }
+
+    /// Randomly zeroes elements of the tensor with the given probability, scaling the kept
+    /// elements by `1 / (1 - prob)`.
+    ///
+    /// The mask is sampled from a local random generator created from the given seed, so the
+    /// same seed always produces the same mask, independently of the backend seed.
+    fn dropout_seeded<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        prob: f64,
+        seed: u64,
+    ) -> B::TensorPrimitive<D> {
+        let prob_keep = 1.0 - prob;
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mask = Data::random(
+            B::shape(&tensor),
+            Distribution::Bernoulli(prob_keep),
+            &mut rng,
+        );
+        let mask = B::from_data(mask, &B::device(&tensor));
+
+        B::mul_scalar(B::mul(tensor, mask), (1.0 / prob_keep).elem())
+    }
+
+    /// Crops a random window of size `[crop_height, crop_width]` from the last two dimensions of
+    /// the tensor, keeping all the other dimensions.
+    ///
+    /// The top-left corner of the window is sampled from the backend random generator on the
+    /// given device, so seeding the backend makes the crop deterministic.
+    fn random_crop<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        crop_height: usize,
+        crop_width: usize,
+        device: &B::Device,
+    ) -> B::TensorPrimitive<D> {
+        let dims = B::shape(&tensor).dims;
+        let sizes = [(dims[D - 2], crop_height), (dims[D - 1], crop_width)];
+        let offsets = B::into_data(B::random(Shape::new([2]), Distribution::Standard, device));
+
+        let mut indexes = dims.map(|dim| 0..dim);
+        for (i, ((size, crop_size), offset)) in sizes.into_iter().zip(offsets.value).enumerate() {
+            let max_start = size - crop_size;
+            let start = libm::floor(offset.elem::<f64>() * (max_start + 1) as f64) as usize;
+            let start = usize::min(start, max_start);
+
+            indexes[D - 2 + i] = start..start + crop_size;
+        }
+
+        B::index(tensor, indexes)
+    }
+
+    /// Reverses the order of the elements along the given dimension with probability `prob`.
+    ///
+    /// The decision is sampled from the backend random generator on the given device.
+    fn random_flip<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        prob: f64,
+        dim: usize,
+        device: &B::Device,
+    ) -> B::TensorPrimitive<D> {
+        let sample = B::into_data(B::random(Shape::new([1]), Distribution::Standard, device));
+
+        if sample.value[0].elem::<f64>() >= prob {
+            return tensor;
+        }
+
+        B::flip(tensor, &[dim])
+    }
+
+    /// Adjusts the brightness and contrast of an image with values in `[0, 1]`.
+    ///
+    /// The brightness factor is added to every value, then the contrast factor scales the values
+    /// around the mean of the whole tensor, the result being clamped to `[0, 1]`.
+    fn adjust_brightness_contrast<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        brightness: f64,
+        contrast: f64,
+    ) -> B::TensorPrimitive<D> {
+        let tensor = B::add_scalar(tensor, brightness.elem());
+        let mean = B::reshape(B::mean(tensor.clone()), Shape::new([1; D]));
+        let tensor = B::mul_scalar(B::sub(tensor, mean.clone()), contrast.elem());
+        let tensor = B::add(tensor, mean);
+
+        B::clamp(tensor, 0.elem(), 1.elem())
+    }
+
+    /// Randomly adjusts the brightness and contrast of an image with values in `[0, 1]`, see
+    /// [adjust_brightness_contrast](TensorOps::adjust_brightness_contrast).
+    ///
+    /// Both factors are sampled uniformly in their given `(min, max)` range from the backend
+    /// random generator on the given device.
+    fn color_jitter<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        brightness: (f64, f64),
+        contrast: (f64, f64),
+        device: &B::Device,
+    ) -> B::TensorPrimitive<D> {
+        let samples = B::into_data(B::random(Shape::new([2]), Distribution::Standard, device));
+        let [brightness_sample, contrast_sample] = [0, 1].map(|i| samples.value[i].elem::<f64>());
+        let brightness = brightness.0 + (brightness.1 - brightness.0) * brightness_sample;
+        let contrast = contrast.0 + (contrast.1 - contrast.0) * contrast_sample;
+
+        Self::adjust_brightness_contrast(tensor, brightness, contrast)
+    }
+
+    /// Applies a single [Adam](https://arxiv.org/pdf/1412.6980.pdf) optimization step.
+    ///
+    /// Both moments are updated using the given gradient before updating the parameter with the
+    /// bias corrected moments.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter, first moment and second moment.
+    fn adam_update<const D: usize>(
+        param: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        moment_1: B::TensorPrimitive<D>,
+        moment_2: B::TensorPrimitive<D>,
+        options: &AdamUpdateOptions,
+    ) -> (
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+    ) {
+        let moment_1 = B::add(
+            B::mul_scalar(moment_1, options.beta_1.elem()),
+            B::mul_scalar(grad.clone(), (1.0 - options.beta_1).elem()),
+        );
+        let moment_2 = B::add(
+            B::mul_scalar(moment_2, options.beta_2.elem()),
+            B::mul_scalar(B::powf(grad, 2.0), (1.0 - options.beta_2).elem()),
+        );
+
+        let time = options.time as f32;
+        let correction_1 = 1.0 - libm::powf(options.beta_1, time);
+        let correction_2 = 1.0 - libm::powf(options.beta_2, time);
+
+        let moment_1_corrected = B::div_scalar(moment_1.clone(), correction_1.elem());
+        let moment_2_corrected = B::div_scalar(moment_2.clone(), correction_2.elem());
+        let update = B::div(
+            moment_1_corrected,
+            B::add_scalar(B::sqrt(moment_2_corrected), options.epsilon.elem()),
+        );
+        let param = B::sub(param, B::mul_scalar(update, options.lr.elem()));
+
+        (param, moment_1, moment_2)
+    }
+    /// Applies a single [RMSprop](https://www.cs.toronto.edu/~tijmen/csc321/slides/lecture_slides_lec6.pdf)
+    /// optimization step.
+    ///
+    /// The running average of the squared gradient is updated before dividing the gradient by
+    /// its square root to update the parameter.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter and running average of the squared gradient.
+    fn rmsprop_update<const D: usize>(
+        param: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        square_avg: B::TensorPrimitive<D>,
+        options: &RmsPropUpdateOptions,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let square_avg = B::add(
+            B::mul_scalar(square_avg, options.alpha.elem()),
+            B::mul_scalar(B::powf(grad.clone(), 2.0), (1.0 - options.alpha).elem()),
+        );
+
+        let update = B::div(
+            grad,
+            B::add_scalar(B::sqrt(square_avg.clone()), options.epsilon.elem()),
+        );
+        let param = B::sub(param, B::mul_scalar(update, options.lr.elem()));
+
+        (param, square_avg)
+    }
+    /// Applies a single SGD with momentum optimization step.
+    ///
+    /// The weight decay is decoupled from the gradient: the parameter is shrunk by
+    /// `lr * weight_decay` before the momentum step is applied.
+    ///
+    /// # Returns
+    ///
+    /// The updated parameter and velocity.
+    fn sgd_momentum_update<const D: usize>(
+        param: B::TensorPrimitive<D>,
+        grad: B::TensorPrimitive<D>,
+        velocity: B::TensorPrimitive<D>,
+        options: &SgdMomentumUpdateOptions,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        let param = B::mul_scalar(param, (1.0 - options.lr * options.weight_decay).elem());
+        let velocity = B::add(
+            B::mul_scalar(velocity, options.momentum.elem()),
+            grad.clone(),
+        );
+
+        let update = match options.nesterov {
+            true => B::add(
+                grad,
+                B::mul_scalar(velocity.clone(), options.momentum.elem()),
+            ),
+            false => velocity.clone(),
+        };
+        let param = B::sub(param, B::mul_scalar(update, options.lr.elem()));
+
+        (param, velocity)
+    }
+
     fn argmax<const D: usize>(
         tensor: B::TensorPrimitive<D>,
         dim: usize,
@@ -257,14 +978,14 @@ pub trait TensorOps<B: Backend> {
     fn max_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
         let index = B::argmax(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
     fn max_dim_with_indexes<const D: usize>(
         tensor: B::TensorPrimitive<D>,
         dim: usize,
     ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::argmax(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
@@ -277,15 +998,200 @@ pub trait TensorOps<B: Backend> {
     fn min_dim<const D: usize>(tensor: B::TensorPrimitive<D>, dim: usize) -> B::TensorPrimitive<D> {
         let index = B::argmin(tensor.clone(), dim);
 
-        B::gather(D - 1, tensor, index)
+        B::gather(dim, tensor, index)
     }
     fn min_dim_with_indexes<const D: usize>(
         tensor: B::TensorPrimitive<D>,
         dim: usize,
     ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
         let index = B::argmin(tensor.clone(), dim);
-        let values = B::gather(D - 1, tensor, index.clone());
+        let values = B::gather(dim, tensor, index.clone());
 
         (values, index)
     }
+    /// Returns the permutation sorting the tensor along the given dimension.
+    ///
+    /// The sort is stable, so equal elements keep their original order.
+    fn argsort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let indexes = sort::argsort_data(&B::into_data(tensor), dim, descending);
+
+        B::int_from_data(indexes.convert(), &device)
+    }
+    fn sort<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> B::TensorPrimitive<D> {
+        B::sort_with_indexes(tensor, dim, descending).0
+    }
+    fn sort_with_indexes<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        descending: bool,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let indexes = B::argsort(tensor.clone(), dim, descending);
+        let values = B::gather(dim, tensor, indexes.clone());
+
+        (values, indexes)
+    }
+    fn topk<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let mut ranges = B::shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = 0..k;
+
+        let (values, indexes) = B::sort_with_indexes(tensor, dim, true);
+
+        (
+            B::index(values, ranges.clone()),
+            B::int_index(indexes, ranges),
+        )
+    }
+
+    /// Computes the singular value decomposition `A = U diag(S) V^T` of a matrix.
+    ///
+    /// With `k = min(rows, cols)`, returns `U` of shape `[rows, k]`, the singular values `S` of
+    /// shape `[k]` sorted in descending order, and `V` of shape `[cols, k]`.
+    ///
+    /// The default implementation uses the one-sided Jacobi method on the host.
+    fn svd(
+        tensor: B::TensorPrimitive<2>,
+    ) -> (
+        B::TensorPrimitive<2>,
+        B::TensorPrimitive<1>,
+        B::TensorPrimitive<2>,
+    ) {
+        let device = B::device(&tensor);
+        let (u, s, v) = linalg::svd(&Matrix::from_primitive::<B>(tensor));
+
+        (
+            u.into_primitive::<B>(&device),
+            linalg::vector_into_primitive::<B>(s, &device),
+            v.into_primitive::<B>(&device),
+        )
+    }
+
+    /// Computes the eigendecomposition `A = V diag(L) V^T` of a symmetric matrix.
+    ///
+    /// Returns the eigenvalues `L` of shape `[n]` sorted in ascending order, and the eigenvectors
+    /// as the columns of `V` of shape `[n, n]`.
+    ///
+    /// The default implementation uses the cyclic Jacobi method on the host.
+    fn symeig(tensor: B::TensorPrimitive<2>) -> (B::TensorPrimitive<1>, B::TensorPrimitive<2>) {
+        let device = B::device(&tensor);
+        let (eigenvalues, eigenvectors) = linalg::symeig(&Matrix::from_primitive::<B>(tensor));
+
+        (
+            linalg::vector_into_primitive::<B>(eigenvalues, &device),
+            eigenvectors.into_primitive::<B>(&device),
+        )
+    }
+
+    /// Solves the linear system `A X = B`, with `A` of shape `[n, n]` and `B` of shape `[n, k]`.
+    ///
+    /// The default implementation uses the LU decomposition with partial pivoting on the host.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix `A` is singular.
+    fn solve(lhs: B::TensorPrimitive<2>, rhs: B::TensorPrimitive<2>) -> B::TensorPrimitive<2> {
+        let device = B::device(&lhs);
+        let lu = linalg::Lu::new(&Matrix::from_primitive::<B>(lhs));
+
+        if lu.is_singular() {
+            panic!("Can't solve a linear system with a singular matrix.");
+        }
+
+        lu.solve(&Matrix::from_primitive::<B>(rhs))
+            .into_primitive::<B>(&device)
+    }
+    /// Computes the inverse of a square matrix.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is singular.
+    fn inverse(tensor: B::TensorPrimitive<2>) -> B::TensorPrimitive<2> {
+        let [size, _] = B::shape(&tensor).dims;
+        let identity = Matrix::identity(size).into_primitive::<B>(&B::device(&tensor));
+
+        B::solve(tensor, identity)
+    }
+
+    /// Computes the lower triangular Cholesky factor `L` of a symmetric positive definite matrix,
+    /// such that `A = L L^T`.
+    ///
+    /// # Panics
+    ///
+    /// If the matrix isn't positive definite.
+    fn cholesky(tensor: B::TensorPrimitive<2>) -> B::TensorPrimitive<2> {
+        let device = B::device(&tensor);
+
+        match linalg::cholesky(&Matrix::from_primitive::<B>(tensor)) {
+            Some(factor) => factor.into_primitive::<B>(&device),
+            None => panic!(
+                "Can't compute the Cholesky factor of a matrix that isn't positive definite."
+            ),
+        }
+    }
+
+    /// Computes the determinant of a square matrix, returning a tensor of shape `[1]`.
+    ///
+    /// The default implementation uses the LU decomposition with partial pivoting on the host.
+    fn det(tensor: B::TensorPrimitive<2>) -> B::TensorPrimitive<1> {
+        let device = B::device(&tensor);
+        let lu = linalg::Lu::new(&Matrix::from_primitive::<B>(tensor));
+
+        linalg::vector_into_primitive::<B>(vec![lu.determinant()], &device)
+    }
+    /// Computes the natural logarithm of the determinant of a square matrix, returning a tensor
+    /// of shape `[1]`.
+    ///
+    /// The logarithms of the pivots of the LU decomposition are summed, so large matrices don't
+    /// overflow the determinant. The result is NaN when the determinant is negative, and negative
+    /// infinity when the matrix is singular.
+    fn logdet(tensor: B::TensorPrimitive<2>) -> B::TensorPrimitive<1> {
+        let device = B::device(&tensor);
+        let lu = linalg::Lu::new(&Matrix::from_primitive::<B>(tensor));
+
+        linalg::vector_into_primitive::<B>(vec![lu.log_determinant()], &device)
+    }
+
+    /// Computes the reduced QR decomposition `A = Q R` of a matrix.
+    ///
+    /// With `k = min(rows, cols)`, returns `Q` of shape `[rows, k]` with orthonormal columns and
+    /// the upper triangular `R` of shape `[k, cols]` with a non-negative diagonal.
+    ///
+    /// The default implementation uses Householder reflections on the host.
+    fn qr(tensor: B::TensorPrimitive<2>) -> (B::TensorPrimitive<2>, B::TensorPrimitive<2>) {
+        let device = B::device(&tensor);
+        let (q, r) = linalg::qr(&Matrix::from_primitive::<B>(tensor));
+
+        (
+            q.into_primitive::<B>(&device),
+            r.into_primitive::<B>(&device),
+        )
+    }
+}
+
+/// Applies the function on every element of the tensor on the host.
+fn map_elements<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    func: fn(f64) -> f64,
+) -> B::TensorPrimitive<D> {
+    let device = B::device(&tensor);
+    let data = B::into_data(tensor);
+    let value = data
+        .value
+        .into_iter()
+        .map(|x| func(x.elem::<f64>()).elem())
+        .collect();
+
+    B::from_data(Data::new(value, data.shape), &device)
 }
@@ -1,10 +1,5 @@
 use crate::{backend::Backend, Tensor};
 
-pub fn var<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
-    let mean = tensor.clone().mean_dim(dim);
-    var_with_mean(tensor, mean, dim)
-}
-
 pub fn var_with_mean<B: Backend, const D: usize>(
     tensor: Tensor<B, D>,
     mean: Tensor<B, D>,
@@ -14,11 +9,6 @@ pub fn var_with_mean<B: Backend, const D: usize>(
     var_with_mean_n(tensor, mean, dim, n)
 }
 
-pub fn var_bias<B: Backend, const D: usize>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
-    let mean = tensor.clone().mean_dim(dim);
-    var_with_mean_bias(tensor, mean, dim)
-}
-
 pub fn var_with_mean_bias<B: Backend, const D: usize>(
     tensor: Tensor<B, D>,
     mean: Tensor<B, D>,
@@ -24,4 +24,37 @@ mod tests {
         let data_expected = Data::from([1.0, 0.0]);
         data_actual.assert_approx_eq(&data_expected, 4);
     }
+
+    #[test]
+    fn test_log_sigmoid() {
+        let tensor = TestTensor::from_floats([[1.0, 7.0], [0.0, -3.0]]);
+
+        let data_actual = activation::log_sigmoid(tensor.clone()).to_data();
+
+        let data_expected = activation::sigmoid(tensor).log().into_data();
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+
+    #[test]
+    fn test_log_sigmoid_large_magnitude() {
+        let tensor = TestTensor::from_floats([1000.0, -1000.0]);
+
+        let data_actual = activation::log_sigmoid(tensor).to_data();
+
+        let data_expected = Data::from([0.0, -1000.0]);
+        data_actual.assert_approx_eq(&data_expected, 3);
+    }
+
+    #[test]
+    fn test_sigmoid_extreme_inputs() {
+        let tensor = TestTensor::from_floats([-50.0, 50.0]);
+
+        let data_actual = activation::sigmoid(tensor).to_data();
+
+        let [low, high] = [data_actual.value[0], data_actual.value[1]];
+        assert!(!low.is_nan() && !high.is_nan());
+        assert!(low > 0.0 && low < 1e-20);
+        // The distance of 1 to sigmoid(50) is below the float precision.
+        assert!(high > 0.5 && high <= 1.0);
+    }
 }
@@ -21,36 +21,68 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_max_pool2d!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
+        burn_tensor::testgen_module_interpolate!();
+        burn_tensor::testgen_module_graph_conv!();
 
         // test ops
+        burn_tensor::testgen_abs!();
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_chunk!();
+        burn_tensor::testgen_clamp!();
+        burn_tensor::testgen_color_jitter!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_cosh!();
+        burn_tensor::testgen_acos!();
+        burn_tensor::testgen_asin!();
+        burn_tensor::testgen_atan!();
+        burn_tensor::testgen_sinh!();
+        burn_tensor::testgen_cross!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_dropout!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_expand!();
+        burn_tensor::testgen_linalg!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_sqrt!();
+        burn_tensor::testgen_floor!();
         burn_tensor::testgen_log1p!();
+        burn_tensor::testgen_log2!();
+        burn_tensor::testgen_log10!();
         burn_tensor::testgen_index!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_hash!();
         burn_tensor::testgen_index_select!();
         burn_tensor::testgen_map_comparison!();
         burn_tensor::testgen_mask!();
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_mul!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_optim!();
+        burn_tensor::testgen_pad!();
+        burn_tensor::testgen_patchify!();
+        burn_tensor::testgen_permute!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_recip!();
+        burn_tensor::testgen_random_crop!();
+        burn_tensor::testgen_random_flip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_segment!();
         burn_tensor::testgen_flatten!();
+        burn_tensor::testgen_flip!();
+        burn_tensor::testgen_sign!();
         burn_tensor::testgen_sin!();
+        burn_tensor::testgen_sort!();
         burn_tensor::testgen_squeeze!();
+        burn_tensor::testgen_stack!();
         burn_tensor::testgen_tanh!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_transpose!();
+        burn_tensor::testgen_windows!();
 
         // test stats
         burn_tensor::testgen_stats!();
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(module_graph_conv)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::graph_conv;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_graph_conv() {
+        let (features, adjacency, weight) = path_graph();
+
+        let output = graph_conv(features, adjacency, weight, false);
+
+        // `X W` is [1, 2, 3], and each node sums the values of its neighbors.
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0], [4.0], [2.0]]), 4);
+    }
+
+    #[test]
+    fn test_graph_conv_normalized() {
+        let (features, adjacency, weight) = path_graph();
+
+        let output = graph_conv(features, adjacency, weight, true);
+
+        // With the self-loops, the degrees are [2, 3, 2] and the normalized adjacency is
+        // [[1/2, 1/sqrt(6), 0], [1/sqrt(6), 1/3, 1/sqrt(6)], [0, 1/sqrt(6), 1/2]].
+        let sqrt_6 = 6.0_f32.sqrt();
+        let expected = Data::from([
+            [0.5 + 2.0 / sqrt_6],
+            [4.0 / sqrt_6 + 2.0 / 3.0],
+            [2.0 / sqrt_6 + 1.5],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    /// The path graph `0 - 1 - 2` with two features per node projected to one output feature.
+    fn path_graph() -> (TestTensor<2>, TestTensor<2>, TestTensor<2>) {
+        let features = TestTensor::from_floats([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+        let adjacency =
+            TestTensor::from_floats([[0.0, 1.0, 0.0], [1.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+        let weight = TestTensor::from_floats([[1.0], [2.0]]);
+
+        (features, adjacency, weight)
+    }
+}
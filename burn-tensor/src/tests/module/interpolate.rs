@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(module_interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{interpolate, interpolate_pos_embed};
+    use burn_tensor::ops::InterpolateMode;
+
+    #[test]
+    fn test_interpolate_nearest() {
+        let x = TestTensor::from_floats([[[[1., 2.], [3., 4.]]]]);
+
+        let output = interpolate(x, [4, 4], InterpolateMode::Nearest);
+
+        output.to_data().assert_approx_eq(
+            &TestTensor::from_floats([[[
+                [1., 1., 2., 2.],
+                [1., 1., 2., 2.],
+                [3., 3., 4., 4.],
+                [3., 3., 4., 4.],
+            ]]])
+            .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_bilinear() {
+        let x = TestTensor::from_floats([[[[1., 2.], [3., 4.]]]]);
+
+        let output = interpolate(x, [4, 4], InterpolateMode::Bilinear);
+
+        output.to_data().assert_approx_eq(
+            &TestTensor::from_floats([[[
+                [1.0, 1.25, 1.75, 2.0],
+                [1.5, 1.75, 2.25, 2.5],
+                [2.5, 2.75, 3.25, 3.5],
+                [3.0, 3.25, 3.75, 4.0],
+            ]]])
+            .into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_nearest_downsample() {
+        let x = TestTensor::from_floats([[[
+            [1., 2., 3., 4.],
+            [5., 6., 7., 8.],
+            [9., 10., 11., 12.],
+            [13., 14., 15., 16.],
+        ]]]);
+
+        let output = interpolate(x, [2, 2], InterpolateMode::Nearest);
+
+        output.to_data().assert_approx_eq(
+            &TestTensor::from_floats([[[[1., 3.], [9., 11.]]]]).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_interpolate_pos_embed() {
+        // A 2x2 grid of 2-dimensional embeddings, the second feature being the first one negated.
+        let embed = TestTensor::from_floats([[[1., -1.], [2., -2.]], [[3., -3.], [4., -4.]]]);
+
+        let output = interpolate_pos_embed(embed, [4, 4]);
+
+        assert_eq!(output.dims(), [4, 4, 2]);
+        let expected = TestTensor::from_floats([
+            [1.0, 1.25, 1.75, 2.0],
+            [1.5, 1.75, 2.25, 2.5],
+            [2.5, 2.75, 3.25, 3.5],
+            [3.0, 3.25, 3.75, 4.0],
+        ]);
+        output
+            .clone()
+            .index([0..4, 0..4, 0..1])
+            .reshape([4, 4])
+            .into_data()
+            .assert_approx_eq(&expected.clone().into_data(), 3);
+        output
+            .index([0..4, 0..4, 1..2])
+            .reshape([4, 4])
+            .into_data()
+            .assert_approx_eq(&expected.neg().into_data(), 3);
+    }
+}
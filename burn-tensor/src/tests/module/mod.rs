@@ -5,4 +5,6 @@ mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod graph_conv;
+mod interpolate;
 mod maxpool2d;
@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(acos)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_acos_ops() {
+        let data = Data::from([[0.0, 1.0, -1.0], [0.5, -0.5, 0.25]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.acos().into_data();
+
+        let data_expected = Data::from([[1.5708, 0.0, 3.1416], [1.0472, 2.0944, 1.3181]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_give_nan_outside_of_the_domain_for_acos() {
+        let tensor = TestTensor::from_floats([1.5, -2.0]);
+
+        let data_actual = tensor.acos().into_data();
+
+        assert!(data_actual.value.iter().all(|value| value.is_nan()));
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(aggregation)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Shape, Tensor};
+    use burn_tensor::{Data, ElementConversion, Shape, Tensor};
 
     #[test]
     fn test_should_mean() {
@@ -21,6 +21,68 @@ mod tests {
         assert_eq!(data_actual, Data::from([15.0]));
     }
 
+    #[test]
+    fn test_should_sum_kahan_more_accurately_than_sum() {
+        let num_small = 10_000;
+        let mut values = vec![1.0e8];
+        values.extend(vec![1.0; num_small]);
+        values.push(-1.0e8);
+        let shape = Shape::new([values.len()]);
+        let tensor = TestTensor::<1>::from_data(Data::<f32, 1>::new(values, shape).convert());
+
+        let naive: f32 = tensor.clone().sum().into_scalar().elem();
+        let compensated: f32 = tensor.sum_kahan().into_scalar().elem();
+
+        let exact = num_small as f32;
+        assert_eq!(compensated, exact);
+        assert!((compensated - exact).abs() < (naive - exact).abs());
+    }
+
+    #[test]
+    fn test_should_prod() {
+        let tensor = TestTensor::from_data([[-1.0, 1.5, 2.0], [3.0, 4.0, 0.5]]);
+
+        let data_actual = tensor.prod().to_data();
+
+        assert_eq!(data_actual, Data::from([-18.0]));
+    }
+
+    #[test]
+    fn test_should_prod_last_dim() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, -4.0, 5.0]]);
+
+        let data_actual = tensor.prod_dim(1).to_data();
+
+        assert_eq!(data_actual, Data::from([[0.0], [-60.0]]));
+    }
+
+    #[test]
+    fn test_should_prod_first_dim_3d() {
+        let tensor = TestTensor::from_data([
+            [[2.0, 4.0, 1.0], [7.0, -5.0, 3.0]],
+            [[3.0, 1.0, 2.0], [4.0, 2.0, 3.0]],
+        ]);
+
+        let data_actual = tensor.prod_dim(0).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[[6.0, 4.0, 2.0], [28.0, -10.0, 9.0]]])
+        );
+    }
+
+    #[test]
+    fn test_should_prod_int() {
+        // The product of every element stays far below `i32::MAX`.
+        let tensor = TestTensorInt::from_ints([[1, -2, 3], [4, 5, -6]]);
+
+        let data_actual = tensor.clone().prod().into_data();
+        let data_actual_dim = tensor.prod_dim(0).into_data();
+
+        assert_eq!(data_actual, Data::from([720]));
+        assert_eq!(data_actual_dim, Data::from([[4, -10, -18]]));
+    }
+
     #[test]
     fn test_should_mean_last_dim() {
         let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -86,4 +148,71 @@ mod tests {
             Data::new(vec![5.0, 5.0, 3.0, 11.0, -3.0, 6.0], Shape::new([2, 1, 3]))
         );
     }
+
+    #[test]
+    fn test_should_sum_dims_keepdim() {
+        let tensor = TestTensor::from_data([
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            [
+                [12.0, 13.0, 14.0, 15.0],
+                [16.0, 17.0, 18.0, 19.0],
+                [20.0, 21.0, 22.0, 23.0],
+            ],
+        ]);
+
+        let tensor_actual: TestTensor<3> = tensor.clone().sum_dims(&[1, 2], true);
+        let tensor_expected = tensor.sum_dim(1).sum_dim(2);
+
+        assert_eq!(tensor_actual.shape(), Shape::new([2, 1, 1]));
+        assert_eq!(tensor_actual.into_data(), tensor_expected.into_data());
+    }
+
+    #[test]
+    fn test_should_sum_dims() {
+        let tensor = TestTensor::from_data([
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            [
+                [12.0, 13.0, 14.0, 15.0],
+                [16.0, 17.0, 18.0, 19.0],
+                [20.0, 21.0, 22.0, 23.0],
+            ],
+        ]);
+
+        let tensor_actual: TestTensor<1> = tensor.clone().sum_dims(&[1, 2], false);
+        let tensor_expected = tensor.sum_dim(1).sum_dim(2).reshape([2]);
+
+        assert_eq!(tensor_actual.clone().into_data(), Data::from([66.0, 210.0]));
+        assert_eq!(tensor_actual.into_data(), tensor_expected.into_data());
+    }
+
+    #[test]
+    fn test_should_mean_dims() {
+        let tensor = TestTensor::from_data([
+            [
+                [0.0, 1.0, 2.0, 3.0],
+                [4.0, 5.0, 6.0, 7.0],
+                [8.0, 9.0, 10.0, 11.0],
+            ],
+            [
+                [12.0, 13.0, 14.0, 15.0],
+                [16.0, 17.0, 18.0, 19.0],
+                [20.0, 21.0, 22.0, 23.0],
+            ],
+        ]);
+
+        let tensor_actual: TestTensor<1> = tensor.clone().mean_dims(&[1, 2], false);
+        let tensor_expected = tensor.mean_dim(1).mean_dim(2).reshape([2]);
+
+        tensor_actual
+            .into_data()
+            .assert_approx_eq(&tensor_expected.into_data(), 3);
+    }
 }
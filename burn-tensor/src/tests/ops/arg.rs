@@ -13,4 +13,104 @@ mod tests {
         let data_expected = Data::from([[2], [2]]);
         assert_eq!(data_expected, data_actual.to_data());
     }
+
+    #[test]
+    fn test_argmin_2d() {
+        let data = Data::from([[0.0, 1.0, 2.0], [5.0, 4.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.argmin(1);
+
+        let data_expected = Data::from([[0], [2]]);
+        assert_eq!(data_expected, data_actual.to_data());
+    }
+
+    #[test]
+    fn test_arg_with_negative_values() {
+        let data = Data::from([[-3.0, -1.0, -2.0], [-0.5, -4.0, -0.25]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(1);
+        let data_actual_min = tensor.argmin(1);
+
+        assert_eq!(Data::from([[1], [2]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[0], [1]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_with_ties_returns_first_occurrence() {
+        let data = Data::from([[1.0, 3.0, 3.0], [2.0, 2.0, 0.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(1);
+        let data_actual_min = tensor.argmin(1);
+
+        assert_eq!(Data::from([[1], [0]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[0], [2]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_3d() {
+        let data = Data::from([[[0.0, -1.0, 5.0, 5.0], [2.0, -7.0, -7.0, 1.0]]]);
+        let tensor = Tensor::<TestBackend, 3>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(2);
+        let data_actual_min = tensor.argmin(2);
+
+        assert_eq!(Data::from([[[2], [0]]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[[1], [1]]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_with_repeated_extremes_returns_first_occurrence() {
+        let data = Data::from([[0.5, 3.0, -1.0, 3.0, -1.0], [7.0, 7.0, 7.0, 7.0, 7.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(1);
+        let data_actual_min = tensor.argmin(1);
+
+        assert_eq!(Data::from([[1], [0]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[2], [0]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_with_computed_values() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.1, 0.7, 0.3, 0.7]]));
+
+        // The values are computed instead of being exactly represented.
+        let tensor = tensor.mul_scalar(3.0).div_scalar(7.0);
+
+        let data_actual_max = tensor.clone().argmax(1);
+        let data_actual_min = tensor.argmin(1);
+
+        assert_eq!(Data::from([[1]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[0]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_along_first_dim() {
+        let data = Data::from([[0.0, 4.0, 2.0], [3.0, 1.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(0);
+        let data_actual_min = tensor.argmin(0);
+
+        assert_eq!(Data::from([[1, 0, 1]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[0, 1, 0]]), data_actual_min.to_data());
+    }
+
+    #[test]
+    fn test_arg_along_middle_dim() {
+        let data = Data::from([
+            [[0.0, 9.0], [5.0, -1.0], [2.0, 3.0]],
+            [[-4.0, 1.0], [-6.0, 1.0], [7.0, 0.0]],
+        ]);
+        let tensor = Tensor::<TestBackend, 3>::from_data(data);
+
+        let data_actual_max = tensor.clone().argmax(1);
+        let data_actual_min = tensor.argmin(1);
+
+        assert_eq!(Data::from([[[1, 0]], [[2, 0]]]), data_actual_max.to_data());
+        assert_eq!(Data::from([[[0, 1]], [[1, 2]]]), data_actual_min.to_data());
+    }
 }
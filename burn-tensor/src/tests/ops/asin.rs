@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(asin)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_asin_ops() {
+        let data = Data::from([[0.0, 1.0, -1.0], [0.5, -0.5, 0.25]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.asin().into_data();
+
+        let data_expected = Data::from([[0.0, 1.5708, -1.5708], [0.5236, -0.5236, 0.2527]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_give_nan_outside_of_the_domain_for_asin() {
+        let tensor = TestTensor::from_floats([1.5, -2.0]);
+
+        let data_actual = tensor.asin().into_data();
+
+        assert!(data_actual.value.iter().all(|value| value.is_nan()));
+    }
+}
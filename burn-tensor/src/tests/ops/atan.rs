@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(atan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_atan_ops() {
+        let data = Data::from([[0.0, 1.0, -1.0], [0.5, 10.0, -3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.atan().into_data();
+
+        let data_expected = Data::from([[0.0, 0.7854, -0.7854], [0.4636, 1.4711, -1.2490]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
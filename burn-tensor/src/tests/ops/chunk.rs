@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(chunk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_chunk_evenly() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]]);
+
+        let chunks = tensor.chunk(3, 1);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].to_data(), Data::from([[0.0, 1.0]]));
+        assert_eq!(chunks[1].to_data(), Data::from([[2.0, 3.0]]));
+        assert_eq!(chunks[2].to_data(), Data::from([[4.0, 5.0]]));
+    }
+
+    #[test]
+    fn should_chunk_unevenly() {
+        let tensor = TestTensorInt::arange(0..7).reshape([7, 1]);
+
+        let chunks = tensor.chunk(3, 0);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].to_data(), Data::from([[0], [1], [2]]));
+        assert_eq!(chunks[1].to_data(), Data::from([[3], [4]]));
+        assert_eq!(chunks[2].to_data(), Data::from([[5], [6]]));
+    }
+
+    #[test]
+    fn should_chunk_into_single_elements_when_there_are_more_chunks_than_elements() {
+        let tensor = Tensor::<TestBackend, 1, Bool>::from_data(Data::from([true, false]));
+
+        let chunks = tensor.chunk(4, 0);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].to_data(), Data::from([true]));
+        assert_eq!(chunks[1].to_data(), Data::from([false]));
+    }
+
+    #[test]
+    fn should_split_evenly() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0]]);
+
+        let pieces = tensor.split(2, 0);
+
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[0].to_data(), Data::from([[0.0, 1.0], [2.0, 3.0]]));
+        assert_eq!(pieces[1].to_data(), Data::from([[4.0, 5.0], [6.0, 7.0]]));
+    }
+
+    #[test]
+    fn should_split_unevenly() {
+        let tensor = TestTensorInt::arange(0..5);
+
+        let pieces = tensor.split(2, 0);
+
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces[0].to_data(), Data::from([0, 1]));
+        assert_eq!(pieces[1].to_data(), Data::from([2, 3]));
+        assert_eq!(pieces[2].to_data(), Data::from([4]));
+    }
+
+    #[test]
+    fn should_split_qkv_projection() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]);
+
+        let pieces = tensor.clone().chunk(3, 1);
+        let output = Tensor::cat(pieces, 1);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_splitting_with_size_zero() {
+        let tensor = TestTensor::from_floats([0.0, 1.0]);
+
+        tensor.split(0, 0);
+    }
+}
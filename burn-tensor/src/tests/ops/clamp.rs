@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(clamp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn clamp_min_should_match_reference() {
+        let tensor = TestTensor::from_floats([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+
+        let data_actual = tensor.clamp_min(-2.0).into_data();
+
+        let data_expected = Data::from([[0.0, -1.0, 2.0], [3.0, -2.0, 5.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn clamp_max_should_match_reference() {
+        let tensor = TestTensor::from_floats([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+
+        let data_actual = tensor.clamp_max(2.0).into_data();
+
+        let data_expected = Data::from([[0.0, -1.0, 2.0], [2.0, -4.0, 2.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn clamp_should_match_reference() {
+        let tensor = TestTensor::from_floats([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+
+        let data_actual = tensor.clamp(-1.0, 3.0).into_data();
+
+        let data_expected = Data::from([[0.0, -1.0, 2.0], [3.0, -1.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn clamp_should_return_min_when_min_is_greater_than_max() {
+        let tensor = TestTensor::from_floats([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+
+        let data_actual = tensor.clamp(2.5, 1.0).into_data();
+
+        let data_expected = Data::from([[2.5, 2.5, 2.5], [2.5, 2.5, 2.5]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn clamp_should_match_default_implementation() {
+        let tensor = TestTensor::from_floats([[0.5, -1.5, 2.0], [3.5, -4.0, 5.0]]);
+
+        let clamped = tensor.clone().clamp(-1.0, 3.0);
+        let composed = tensor.clamp_max(3.0).clamp_min(-1.0);
+
+        assert_eq!(composed.into_data(), clamped.into_data());
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(color_jitter)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Distribution, Tensor};
+
+    #[test]
+    fn should_adjust_brightness_and_contrast() {
+        let tensor = TestTensor::from_data(Data::from([[0.2, 0.4], [0.6, 0.8]]));
+
+        let output = tensor.adjust_brightness_contrast(0.1, 2.0);
+
+        // Shifted to [[0.3, 0.5], [0.7, 0.9]], then scaled around the mean of 0.6.
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.4], [0.8, 1.0]]), 3);
+    }
+
+    #[test]
+    fn should_jitter_with_fixed_factors_when_ranges_are_empty() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data(Data::from([[0.2, 0.4], [0.6, 0.8]]));
+
+        let output = tensor
+            .clone()
+            .color_jitter((-0.1, -0.1), (0.5, 0.5), &device);
+        let expected = tensor.adjust_brightness_contrast(-0.1, 0.5);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn should_keep_values_in_unit_range() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::random([3, 8, 8], Distribution::Standard);
+
+        let output = tensor.color_jitter((-0.5, 0.5), (0.0, 3.0), &device);
+
+        for value in output.into_data().convert::<f32>().value {
+            assert!((0.0..=1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_contrast_is_negative() {
+        let tensor = TestTensor::from_data(Data::from([[0.2, 0.4], [0.6, 0.8]]));
+
+        tensor.adjust_brightness_contrast(0.0, -1.0);
+    }
+}
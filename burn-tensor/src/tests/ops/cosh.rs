@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(cosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_cosh_ops() {
+        let data = Data::from([[0.0, 1.0, -1.0], [2.0, -2.0, 0.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.cosh().into_data();
+
+        let data_expected = Data::from([[1.0, 1.5431, 1.5431], [3.7622, 3.7622, 1.1276]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(cross)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_cross_product() {
+        let tensor_1 = TestTensor::from_floats([1.0, 0.0, 0.0]);
+        let tensor_2 = TestTensor::from_floats([0.0, 1.0, 0.0]);
+
+        let data_actual = tensor_1.cross(tensor_2, 0).into_data();
+
+        assert_eq!(data_actual, Data::from([0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn should_support_cross_product_batched() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 2.0, 3.0], [0.0, 0.0, 1.0]]);
+        let tensor_2 = TestTensor::from_floats([[4.0, 5.0, 6.0], [1.0, 0.0, 0.0]]);
+
+        let data_actual = tensor_1.cross(tensor_2, 1).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[-3.0, 6.0, -3.0], [0.0, 1.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_cross_product_first_dim() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 0.0], [2.0, 0.0], [3.0, 1.0]]);
+        let tensor_2 = TestTensor::from_floats([[4.0, 1.0], [5.0, 0.0], [6.0, 0.0]]);
+
+        let data_actual = tensor_1.cross(tensor_2, 0).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[-3.0, 0.0], [6.0, 1.0], [-3.0, 0.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_cross_dim_is_not_3() {
+        let tensor_1 = TestTensor::from_floats([1.0, 0.0]);
+        let tensor_2 = TestTensor::from_floats([0.0, 1.0]);
+
+        let _tensor = tensor_1.cross(tensor_2, 0);
+    }
+}
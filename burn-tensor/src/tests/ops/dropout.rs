@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(dropout)]
+mod tests {
+    use super::*;
+    use burn_tensor::{ElementConversion, Tensor};
+
+    #[test]
+    fn should_produce_same_mask_with_same_seed() {
+        let tensor = Tensor::<TestBackend, 2>::ones([32, 32]);
+
+        let output_1 = tensor.clone().dropout_seeded(0.5, 42);
+        let output_2 = tensor.dropout_seeded(0.5, 42);
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn should_produce_different_masks_with_different_seeds() {
+        let tensor = Tensor::<TestBackend, 2>::ones([32, 32]);
+
+        let output_1 = tensor.clone().dropout_seeded(0.5, 42);
+        let output_2 = tensor.dropout_seeded(0.5, 43);
+
+        assert_ne!(output_1.into_data().value, output_2.into_data().value);
+    }
+
+    #[test]
+    fn should_scale_kept_values() {
+        let tensor = Tensor::<TestBackend, 2>::ones([32, 32]);
+
+        let output = tensor.dropout_seeded(0.5, 42).into_data();
+
+        for value in output.value {
+            let value: f32 = value.elem();
+            assert!(value == 0.0 || value == 2.0, "Unexpected value {value}");
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_invalid_probability() {
+        let tensor = Tensor::<TestBackend, 2>::ones([2, 2]);
+
+        tensor.dropout_seeded(1.0, 42);
+    }
+}
@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_expand_leading_singleton_dim() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0]]);
+
+        let output = tensor.expand([2, 3]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_expand_trailing_singleton_dim() {
+        let tensor = TestTensor::from_floats([[1.0], [2.0]]);
+
+        let output = tensor.expand([2, 3]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]])
+        );
+    }
+
+    #[test]
+    fn should_expand_to_more_dims() {
+        let tensor = TestTensor::from_floats([1.0, 2.0]);
+
+        let output = tensor.expand([2, 1, 2]);
+
+        assert_eq!(output.into_data(), Data::from([[[1.0, 2.0]], [[1.0, 2.0]]]));
+    }
+
+    #[test]
+    fn should_expand_multiple_singleton_dims() {
+        let tensor = TestTensor::from_floats([[[1.0], [2.0]]]);
+
+        let output = tensor.expand([2, 2, 3]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+                [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shapes_are_incompatible() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        tensor.expand([3, 2]);
+    }
+}
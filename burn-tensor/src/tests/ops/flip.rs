@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_flip_3d_single_axis() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let output = tensor.flip(&[2]);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]],
+                [[8.0, 7.0, 6.0], [11.0, 10.0, 9.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn should_flip_3d_multiple_axes() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let output_01 = tensor.clone().flip(&[0, 1]);
+        let output_all = tensor.flip(&[2, 0, 1]);
+
+        assert_eq!(
+            output_01.into_data(),
+            Data::from([
+                [[9.0, 10.0, 11.0], [6.0, 7.0, 8.0]],
+                [[3.0, 4.0, 5.0], [0.0, 1.0, 2.0]],
+            ])
+        );
+        assert_eq!(
+            output_all.into_data(),
+            Data::from([
+                [[11.0, 10.0, 9.0], [8.0, 7.0, 6.0]],
+                [[5.0, 4.0, 3.0], [2.0, 1.0, 0.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn should_reshape_flipped_tensor() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.flip(&[1]).reshape([4]);
+
+        assert_eq!(output.into_data(), Data::from([2.0, 1.0, 4.0, 3.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_an_axis_is_flipped_twice() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        tensor.flip(&[0, 0]);
+    }
+}
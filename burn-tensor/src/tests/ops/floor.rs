@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(floor)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_floor_ops() {
+        let tensor = TestTensor::from_floats([[-1.5, -0.2, 0.0], [0.5, 1.7, 2.0]]);
+
+        let data_actual = tensor.floor().into_data();
+
+        let data_expected = Data::from([[-2.0, -1.0, 0.0], [0.0, 1.0, 2.0]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_support_ceil_ops() {
+        let tensor = TestTensor::from_floats([[-1.5, -0.2, 0.0], [0.5, 1.7, 2.0]]);
+
+        let data_actual = tensor.ceil().into_data();
+
+        let data_expected = Data::from([[-1.0, 0.0, 0.0], [1.0, 2.0, 2.0]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_support_round_ops() {
+        let tensor = TestTensor::from_floats([[-1.7, -0.2, 0.3], [0.7, 1.2, 2.6]]);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([[-2.0, 0.0, 0.0], [1.0, 1.0, 3.0]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_round_half_to_even() {
+        let tensor = TestTensor::from_floats([-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 3.5]);
+
+        let data_actual = tensor.round().into_data();
+
+        let data_expected = Data::from([-2.0, -2.0, 0.0, 0.0, 2.0, 2.0, 4.0]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+}
@@ -129,4 +129,64 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn should_gather_like_pytorch() {
+        // torch.gather(torch.tensor([[1, 2], [3, 4]]), 1, torch.tensor([[0, 0], [1, 0]]))
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+        let indexes = TestTensorInt::from_ints([[0, 0], [1, 0]]);
+
+        let output = tensor.gather(1, indexes);
+
+        assert_eq!(output.into_data(), Data::from([[1.0, 1.0], [4.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_scatter_add_like_pytorch() {
+        // torch.zeros(3, 5).scatter_add_(0, torch.tensor([[0, 1, 2, 0, 0]]), torch.ones(1, 5))
+        let tensor = TestTensor::zeros([3, 5]);
+        let values = TestTensor::ones([1, 5]);
+        let indexes = TestTensorInt::from_ints([[0, 1, 2, 0, 0]]);
+
+        let output = tensor.scatter(0, indexes, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([
+                [1.0, 0.0, 0.0, 1.0, 1.0],
+                [0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0, 0.0]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_scatter_add_duplicated_indexes() {
+        let tensor = TestTensor::from_floats([[1.0, 1.0, 1.0]]);
+        let values = TestTensor::from_floats([[1.0, 2.0, 3.0, 4.0]]);
+        let indexes = TestTensorInt::from_ints([[2, 2, 0, 2]]);
+
+        let output = tensor.scatter(1, indexes, values);
+
+        assert_eq!(output.into_data(), Data::from([[4.0, 1.0, 8.0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_gather_index_is_out_of_bounds() {
+        let tensor = TestTensor::from_floats([0.0, 1.0, 2.0]);
+        let indexes = TestTensorInt::from_ints([0, 3]);
+
+        tensor.gather(0, indexes).into_data();
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_scatter_index_is_out_of_bounds() {
+        let tensor = TestTensor::from_floats([0.0, 1.0, 2.0]);
+        let values = TestTensor::from_floats([1.0, 1.0]);
+        let indexes = TestTensorInt::from_ints([0, 3]);
+
+        tensor.scatter(0, indexes, values).into_data();
+    }
 }
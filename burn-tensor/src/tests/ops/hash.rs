@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(hash)]
+mod tests {
+    use super::*;
+    use burn_tensor::{tensor_hash, Data, Int, Tensor};
+
+    #[test]
+    fn should_hash_equal_tensors_equally() {
+        let tensor_1 = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let tensor_2 = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+        assert_eq!(tensor_hash(&tensor_1), tensor_hash(&tensor_2));
+    }
+
+    #[test]
+    fn should_hash_independently_of_the_memory_layout() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let transposed = TestTensor::from_data(Data::from([[1.0, 3.0], [2.0, 4.0]])).transpose();
+
+        assert_eq!(tensor_hash(&tensor), tensor_hash(&transposed));
+    }
+
+    #[test]
+    fn should_change_hash_when_an_element_changes() {
+        let tensor_1 = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let tensor_2 = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.5]]));
+
+        assert_ne!(tensor_hash(&tensor_1), tensor_hash(&tensor_2));
+    }
+
+    #[test]
+    fn should_change_hash_when_the_shape_changes() {
+        let tensor = TestTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let reshaped = tensor.clone().reshape([4]);
+
+        assert_ne!(tensor_hash(&tensor), tensor_hash(&reshaped));
+    }
+
+    #[test]
+    fn should_hash_int_tensors() {
+        let tensor_1 = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 2, 3]));
+        let tensor_2 = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 2, 3]));
+        let tensor_3 = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 2, 4]));
+
+        assert_eq!(tensor_hash(&tensor_1), tensor_hash(&tensor_2));
+        assert_ne!(tensor_hash(&tensor_1), tensor_hash(&tensor_3));
+    }
+
+    #[test]
+    fn should_hash_large_int_values_exactly() {
+        let tensor_1 = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1 << 60]));
+        let tensor_2 = Tensor::<TestBackend, 1, Int>::from_data(Data::from([(1 << 60) + 1]));
+
+        assert_ne!(tensor_hash(&tensor_1), tensor_hash(&tensor_2));
+    }
+}
@@ -57,6 +57,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_select_2d_dim0_int() {
+        let tensor = TestTensorInt::from_data(Data::from([[0, 1, 2], [3, 4, 5], [6, 7, 8]]));
+        let indexes = TestTensorInt::from_data(Data::from([2, 0, 2]));
+
+        let output = tensor.index_select(0, indexes);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[6, 7, 8], [0, 1, 2], [6, 7, 8]])
+        );
+    }
+
     #[test]
     fn should_select_assign_1d() {
         let tensor = TestTensor::from_data(Data::from([0.0, 1.0, 2.0]));
@@ -0,0 +1,224 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn svd_should_match_analytic_singular_values() {
+        let tensor = TestTensor::from_floats([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (_, s, _) = tensor.svd();
+
+        // The eigenvalues of A^T A are 45 and 5.
+        let expected = Data::from([45.0_f32.sqrt(), 5.0_f32.sqrt()]);
+        s.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn svd_should_reconstruct_the_matrix() {
+        for tensor in [
+            TestTensor::from_floats([[1.0, -2.0, 0.5], [3.0, 0.0, 1.0]]),
+            TestTensor::from_floats([[2.0, 1.0], [-1.0, 0.5], [0.0, 4.0], [1.0, 1.0]]),
+        ] {
+            let (u, s, v) = tensor.clone().svd();
+            let [k] = s.dims();
+
+            let reconstructed = (u * s.reshape([1, k])).matmul(v.clone().transpose());
+            let identity = v.clone().transpose().matmul(v);
+
+            reconstructed
+                .into_data()
+                .assert_approx_eq(&tensor.into_data(), 4);
+            identity
+                .into_data()
+                .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 4);
+        }
+    }
+
+    #[test]
+    fn pca_should_recover_the_variance_of_a_plane() {
+        // Samples of a plane spanned by (1, 1, 0) / sqrt(2) and (0, 0, 1) embedded in 3D, with
+        // uncorrelated coordinates so that both directions are principal components.
+        let coordinates = [
+            (-3.0, 1.0),
+            (-1.0, -1.0),
+            (0.0, 0.0),
+            (1.0, -1.0),
+            (3.0, 1.0),
+        ];
+        let samples: Vec<f32> = coordinates
+            .iter()
+            .flat_map(|(a, b)| {
+                let a = a / 2.0_f32.sqrt();
+                [a + 1.0, a - 2.0, b + 0.5]
+            })
+            .collect();
+        let samples = TestTensor::from_data(Data::new(samples, Shape::new([5, 3])).convert());
+
+        let projected = samples.clone().pca(2);
+
+        let variance = projected.clone().var(0).sum().into_scalar();
+        let variance_expected = samples.var(0).sum().into_scalar();
+        assert!((variance - variance_expected).abs() < 1e-4);
+
+        // The first component explains the variance along (1, 1, 0): var([-3, -1, 0, 1, 3]).
+        let variance_first = projected.index([0..5, 0..1]).var(0).into_scalar();
+        assert!((variance_first - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn symeig_should_match_analytic_eigenvalues() {
+        let tensor =
+            TestTensor::from_floats([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        let (eigenvalues, eigenvectors) = tensor.clone().symeig();
+
+        let sqrt_2 = 2.0_f32.sqrt();
+        let expected = Data::from([2.0 - sqrt_2, 2.0, 2.0 + sqrt_2]);
+        eigenvalues
+            .clone()
+            .into_data()
+            .assert_approx_eq(&expected, 4);
+
+        // Every eigenvector satisfies `A v = lambda v`.
+        let lhs = tensor.matmul(eigenvectors.clone());
+        let rhs = eigenvectors * eigenvalues.reshape([1, 3]);
+        lhs.into_data().assert_approx_eq(&rhs.into_data(), 4);
+    }
+
+    #[test]
+    fn inverse_should_give_identity() {
+        let tensor = TestTensor::from_floats([[2.0, 1.0, 1.0], [1.0, 3.0, 2.0], [1.0, 0.0, 0.0]]);
+
+        let inverse = tensor.clone().inverse();
+
+        let expected = Data::from([[0.0, 0.0, 1.0], [-2.0, 1.0, 3.0], [3.0, -1.0, -5.0]]);
+        let identity = Data::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        inverse.clone().into_data().assert_approx_eq(&expected, 4);
+        tensor
+            .matmul(inverse)
+            .into_data()
+            .assert_approx_eq(&identity, 4);
+    }
+
+    #[test]
+    fn solve_should_recover_the_solution() {
+        let tensor = TestTensor::from_floats([[4.0, -2.0, 1.0], [3.0, 6.0, -4.0], [2.0, 1.0, 8.0]]);
+        let solution = Data::from([[1.0, -2.0], [2.0, 0.5], [-1.0, 3.0]]);
+        let rhs = tensor
+            .clone()
+            .matmul(TestTensor::from_data(solution.clone()));
+
+        let data_actual = tensor.solve(rhs).into_data();
+
+        data_actual.assert_approx_eq(&solution, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverse_should_panic_when_the_matrix_is_singular() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [2.0, 4.0]]);
+
+        tensor.inverse();
+    }
+
+    #[test]
+    fn cholesky_should_reconstruct_the_matrix() {
+        let tensor = TestTensor::from_floats([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let factor = tensor.clone().cholesky();
+
+        let expected = Data::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        factor.clone().into_data().assert_approx_eq(&expected, 4);
+        factor
+            .clone()
+            .matmul(factor.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cholesky_should_panic_when_the_matrix_is_not_positive_definite() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [2.0, 1.0]]);
+
+        tensor.cholesky();
+    }
+
+    #[test]
+    fn det_should_match_analytic_determinant() {
+        let tensor = TestTensor::from_floats([[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]]);
+        let swapped =
+            TestTensor::from_floats([[2.0, 0.0, -1.0], [2.0, -3.0, 1.0], [1.0, 4.0, 5.0]]);
+
+        let det = tensor.det().into_scalar();
+        let det_swapped = swapped.det().into_scalar();
+
+        assert!((det - 49.0).abs() < 1e-4);
+        assert!((det_swapped + 49.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn logdet_should_match_log_of_det() {
+        let tensor = TestTensor::from_floats([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let logdet = tensor.clone().logdet().into_data();
+        let log_of_det = tensor.det().log().into_data();
+
+        logdet.assert_approx_eq(&Data::from([36.0_f32.ln()]), 4);
+        logdet.assert_approx_eq(&log_of_det, 4);
+    }
+
+    #[test]
+    fn logdet_should_not_overflow_for_large_matrices() {
+        let size = 50;
+        let values: Vec<f32> = (0..size * size)
+            .map(|i| if i % (size + 1) == 0 { 10.0 } else { 0.0 })
+            .collect();
+        let tensor = TestTensor::from_data(Data::new(values, Shape::new([size, size])).convert());
+
+        let det = tensor.clone().det().into_scalar();
+        let logdet = tensor.logdet().into_scalar();
+
+        assert!(det.is_infinite());
+        assert!((logdet - size as f32 * 10.0_f32.ln()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn qr_should_match_known_decomposition() {
+        let tensor =
+            TestTensor::from_floats([[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]]);
+
+        let (_, r) = tensor.qr();
+
+        let expected = Data::from([[14.0, 21.0, -14.0], [0.0, 175.0, -70.0], [0.0, 0.0, 35.0]]);
+        r.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn qr_should_reconstruct_the_matrix() {
+        for tensor in [
+            TestTensor::from_floats([[1.0, -2.0, 0.5], [3.0, 0.0, 1.0]]),
+            TestTensor::from_floats([[2.0, 1.0], [-1.0, 0.5], [0.0, 4.0], [1.0, 1.0]]),
+        ] {
+            let (q, r) = tensor.clone().qr();
+
+            let identity = q.clone().transpose().matmul(q.clone());
+
+            q.matmul(r)
+                .into_data()
+                .assert_approx_eq(&tensor.into_data(), 4);
+            identity
+                .into_data()
+                .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 4);
+        }
+    }
+}
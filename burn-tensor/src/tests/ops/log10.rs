@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(log10)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_log10_ops() {
+        let data = Data::from([[1.0, 10.0, 1000.0], [0.1, 2.0, 50.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.log10().into_data();
+
+        let data_expected = Data::from([[0.0, 1.0, 3.0], [-1.0, 0.3010, 1.6990]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
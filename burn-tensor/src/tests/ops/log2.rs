@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(log2)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_log2_ops() {
+        let data = Data::from([[1.0, 2.0, 8.0], [0.5, 10.0, 1024.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.log2().into_data();
+
+        let data_expected = Data::from([[0.0, 1.0, 3.0], [-1.0, 3.3219, 10.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
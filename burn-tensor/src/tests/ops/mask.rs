@@ -17,6 +17,19 @@ mod tests {
         assert_eq!(data_expected, data_actual);
     }
 
+    #[test]
+    fn should_support_mask_scatter_ops_on_nan() {
+        let tensor =
+            Tensor::<TestBackend, 1>::from_data(Data::from([1.0, f32::NAN, f32::INFINITY]));
+        let mask = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([true, true, false]));
+        let source = Tensor::<TestBackend, 1>::from_data(Data::from([f32::NAN, 2.0, 3.0]));
+
+        let data_actual = tensor.mask_scatter(mask, source).into_data();
+
+        assert!(data_actual.value[0].is_nan());
+        assert_eq!(data_actual.value[1..], [2.0, f32::INFINITY]);
+    }
+
     #[test]
     fn should_support_mask_fill_ops() {
         let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 7.0], [2.0, 3.0]]));
@@ -28,4 +41,47 @@ mod tests {
         let data_expected = Data::from([[2.0, 7.0], [2.0, 2.0]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_mask_fill_ops_on_nan() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, f32::NAN, 3.0]));
+        let mask = Tensor::<TestBackend, 1, Bool>::from_bool(Data::from([false, true, false]));
+
+        let data_actual = tensor.mask_fill(mask, 0.0).to_data();
+
+        let data_expected = Data::from([1.0, 0.0, 3.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_mask_fill_broadcast_ops() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [1.0, 2.0, 3.0, 6.0],
+            [4.0, 8.0, 0.0, 4.0],
+        ]));
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([
+            [false, true, false, true],
+            [false, false, true, false],
+        ]));
+        let row_mean = tensor.clone().mean_dim(1);
+
+        let data_actual = tensor.mask_fill_broadcast(mask, row_mean).to_data();
+
+        let data_expected = Data::from([[1.0, 3.0, 3.0, 3.0], [4.0, 8.0, 4.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_safe_div_ops() {
+        let data_1 = Data::from([[1.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let data_2 = Data::from([[0.0, 2.0, 2.0], [3.0, 0.0, 5.0]]);
+        let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+        let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+        let output = tensor_1.safe_div(tensor_2, -1.0);
+
+        let data_actual = output.into_data();
+        let data_expected = Data::from([[-1.0, 0.5, 1.0], [1.0, -1.0, 1.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
@@ -42,4 +42,91 @@ mod tests {
             Data::from([[[18.0, 28.0], [14.0, 23.0]], [[44.0, 26.0], [22.0, 19.0]]])
         );
     }
+
+    #[test]
+    fn test_matmul_vector_matrix() {
+        let vector = TestTensor::from_floats([1.0, 2.0]);
+        let matrix = TestTensor::from_floats([[4.0, 7.0, 5.0], [2.0, 3.0, 5.0]]);
+
+        let tensor = vector.vecmat(matrix);
+
+        assert_eq!(tensor.into_data(), Data::from([8.0, 13.0, 15.0]));
+    }
+
+    #[test]
+    fn test_matmul_matrix_vector() {
+        let matrix = TestTensor::from_floats([[1.0, 7.0], [2.0, 3.0], [1.0, 5.0]]);
+        let vector = TestTensor::from_floats([2.0, 1.0]);
+
+        let tensor = matrix.matvec(vector);
+
+        assert_eq!(tensor.into_data(), Data::from([9.0, 7.0, 7.0]));
+    }
+
+    #[test]
+    fn test_matmul_vector_vector() {
+        let tensor_1 = TestTensor::from_floats([1.0, 2.0, 3.0]);
+        let tensor_2 = TestTensor::from_floats([4.0, 5.0, 6.0]);
+
+        let tensor_3 = tensor_1.matmul(tensor_2);
+
+        assert_eq!(tensor_3.into_data(), Data::from([32.0]));
+    }
+
+    #[test]
+    fn test_matmul_transpose_a_should_match_explicit_transpose() {
+        let tensor_1 = TestTensor::from_floats([
+            [[1.0, 7.0, 2.0], [2.0, 3.0, 4.0]],
+            [[5.0, 1.0, 0.0], [3.0, 6.0, 2.0]],
+        ]);
+        let tensor_2 =
+            TestTensor::from_floats([[[4.0, 7.0], [2.0, 3.0]], [[1.0, 2.0], [8.0, 5.0]]]);
+
+        let expected = tensor_1.clone().swap_dims(1, 2).matmul(tensor_2.clone());
+        let output = tensor_1.matmul_transpose_a(tensor_2);
+
+        assert_eq!(output.shape(), expected.shape());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_matmul_transpose_b_should_match_explicit_transpose() {
+        let tensor_1 = TestTensor::from_floats([
+            [[1.0, 7.0, 2.0], [2.0, 3.0, 4.0]],
+            [[5.0, 1.0, 0.0], [3.0, 6.0, 2.0]],
+        ]);
+        let tensor_2 = TestTensor::from_floats([
+            [
+                [4.0, 7.0, 1.0],
+                [2.0, 3.0, 5.0],
+                [0.0, 1.0, 2.0],
+                [3.0, 3.0, 3.0],
+            ],
+            [
+                [1.0, 2.0, 3.0],
+                [8.0, 5.0, 2.0],
+                [4.0, 4.0, 0.0],
+                [1.0, 0.0, 1.0],
+            ],
+        ]);
+
+        let expected = tensor_1.clone().matmul(tensor_2.clone().swap_dims(1, 2));
+        let output = tensor_1.matmul_transpose_b(tensor_2);
+
+        assert_eq!(output.shape(), expected.shape());
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matmul_transpose_b_should_panic_with_incompatible_shapes() {
+        let tensor_1 = TestTensor::from_floats([[1.0, 7.0, 2.0], [2.0, 3.0, 4.0]]);
+        let tensor_2 = TestTensor::from_floats([[4.0, 7.0], [2.0, 3.0]]);
+
+        let _ = tensor_1.matmul_transpose_b(tensor_2);
+    }
 }
@@ -48,4 +48,90 @@ mod tests {
         assert_eq!(output_expected, output_actual.into_data());
         assert_eq!(index_expected, index_actual.into_data());
     }
+
+    #[test]
+    fn test_max_dim_along_first_dim() {
+        let tensor = TestTensor::from_floats([[0.0, 4.0, 2.0], [3.0, 1.0, 5.0]]);
+
+        let (output_actual, index_actual) = tensor.clone().max_dim_with_indexes(0);
+
+        let output_expected = Data::from([[3., 4., 5.]]);
+        let index_expected = Data::from([[1, 0, 1]]);
+
+        assert_eq!(output_expected, tensor.max_dim(0).into_data());
+        assert_eq!(output_expected, output_actual.into_data());
+        assert_eq!(index_expected, index_actual.into_data());
+    }
+
+    #[test]
+    fn test_min_dim_along_first_dim() {
+        let tensor = TestTensor::from_floats([[0.0, 4.0, 2.0], [3.0, 1.0, 5.0]]);
+
+        let (output_actual, index_actual) = tensor.clone().min_dim_with_indexes(0);
+
+        let output_expected = Data::from([[0., 1., 2.]]);
+        let index_expected = Data::from([[0, 1, 0]]);
+
+        assert_eq!(output_expected, tensor.min_dim(0).into_data());
+        assert_eq!(output_expected, output_actual.into_data());
+        assert_eq!(index_expected, index_actual.into_data());
+    }
+
+    #[test]
+    fn test_max_min_dim_along_middle_dim() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 9.0], [5.0, -1.0], [2.0, 3.0]],
+            [[-4.0, 1.0], [-6.0, 2.0], [7.0, 0.0]],
+        ]);
+
+        let output_max = tensor.clone().max_dim(1);
+        let output_min = tensor.min_dim(1);
+
+        let output_max_expected = Data::from([[[5., 9.]], [[7., 2.]]]);
+        let output_min_expected = Data::from([[[0., -1.]], [[-6., 0.]]]);
+
+        assert_eq!(output_max_expected, output_max.into_data());
+        assert_eq!(output_min_expected, output_min.into_data());
+    }
+
+    #[test]
+    fn test_max_dim_with_indexes_matches_max_dim_and_argmax() {
+        let tensor = TestTensor::from_floats([
+            [[1.0, 7.0, 7.0], [-2.0, 3.0, 0.0]],
+            [[4.0, 4.0, -5.0], [6.0, 6.0, 6.0]],
+        ]);
+
+        for dim in 0..3 {
+            let (values, indexes) = tensor.clone().max_dim_with_indexes(dim);
+
+            assert_eq!(values.into_data(), tensor.clone().max_dim(dim).into_data());
+            assert_eq!(indexes.into_data(), tensor.clone().argmax(dim).into_data());
+        }
+    }
+
+    #[test]
+    fn test_min_dim_with_indexes_matches_min_dim_and_argmin() {
+        let tensor = TestTensor::from_floats([
+            [[1.0, -7.0, -7.0], [-2.0, 3.0, 0.0]],
+            [[4.0, 4.0, -5.0], [6.0, 6.0, 6.0]],
+        ]);
+
+        for dim in 0..3 {
+            let (values, indexes) = tensor.clone().min_dim_with_indexes(dim);
+
+            assert_eq!(values.into_data(), tensor.clone().min_dim(dim).into_data());
+            assert_eq!(indexes.into_data(), tensor.clone().argmin(dim).into_data());
+        }
+    }
+
+    #[test]
+    fn test_max_min_dim_with_indexes_ties_resolve_to_first_index() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 3.0, 1.0]]);
+
+        let (_, index_max) = tensor.clone().max_dim_with_indexes(1);
+        let (_, index_min) = tensor.min_dim_with_indexes(1);
+
+        assert_eq!(Data::from([[0]]), index_max.into_data());
+        assert_eq!(Data::from([[1]]), index_min.into_data());
+    }
 }
@@ -1,28 +1,66 @@
+mod abs;
+mod acos;
 mod add;
 mod aggregation;
 mod arg;
+mod asin;
+mod atan;
+mod chunk;
+mod clamp;
+mod color_jitter;
 mod cos;
+mod cosh;
+mod cross;
 mod div;
+mod dropout;
 mod erf;
 mod exp;
+mod expand;
 mod flatten;
+mod flip;
+mod floor;
 mod gather_scatter;
+mod hash;
 mod index;
 mod index_select;
+#[cfg(feature = "linalg")]
+mod linalg;
 mod log;
+mod log10;
 mod log1p;
+mod log2;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
 mod neg;
+mod optim;
+mod pad;
+mod patchify;
+mod permute;
 mod powf;
+mod random_crop;
+mod random_flip;
+mod recip;
 mod repeat;
 mod reshape;
+mod segment;
+mod sign;
 mod sin;
+mod sinh;
+mod sort;
 mod sqrt;
 mod squeeze;
+mod stack;
 mod sub;
 mod tanh;
 mod transpose;
+mod windows;
+
+/// The linear algebra tests are skipped when the `linalg` feature is disabled.
+#[cfg(not(feature = "linalg"))]
+#[macro_export]
+macro_rules! testgen_linalg {
+    () => {};
+}
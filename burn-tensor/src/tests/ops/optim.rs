@@ -0,0 +1,153 @@
+#[burn_tensor_testgen::testgen(optim)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        ops::{AdamUpdateOptions, RmsPropUpdateOptions, SgdMomentumUpdateOptions},
+        Data,
+    };
+
+    #[test]
+    fn should_support_adam_update() {
+        let mut param = TestTensor::from_floats([[1.0, -2.0], [0.5, 3.0]]);
+        let mut moment_1 = TestTensor::zeros([2, 2]);
+        let mut moment_2 = TestTensor::zeros([2, 2]);
+        let grads = [
+            TestTensor::from_floats([[0.1, -0.3], [2.0, 0.0]]),
+            TestTensor::from_floats([[-0.2, 0.4], [1.0, 0.5]]),
+        ];
+
+        let mut reference = [[1.0, -2.0], [0.5, 3.0]].map(|row| row.map(|p| (p, 0.0, 0.0)));
+        let grads_ref = [[[0.1, -0.3], [2.0, 0.0]], [[-0.2, 0.4], [1.0, 0.5]]];
+
+        for (time, (grad, grad_ref)) in grads.into_iter().zip(grads_ref).enumerate() {
+            let options = AdamUpdateOptions::new(0.01, 0.9, 0.999, 1e-8, time + 1);
+            (param, moment_1, moment_2) = param.adam_update(grad, moment_1, moment_2, &options);
+
+            for (states, grads) in reference.iter_mut().zip(grad_ref) {
+                for (state, grad) in states.iter_mut().zip(grads) {
+                    *state = adam_reference(*state, grad, &options);
+                }
+            }
+        }
+
+        let param_expected = Data::from(reference.map(|row| row.map(|(p, _, _)| p)));
+        let moment_1_expected = Data::from(reference.map(|row| row.map(|(_, m, _)| m)));
+        let moment_2_expected = Data::from(reference.map(|row| row.map(|(_, _, v)| v)));
+
+        param_expected.assert_approx_eq(&param.into_data(), 5);
+        moment_1_expected.assert_approx_eq(&moment_1.into_data(), 5);
+        moment_2_expected.assert_approx_eq(&moment_2.into_data(), 5);
+    }
+
+    fn adam_reference(
+        (param, moment_1, moment_2): (f32, f32, f32),
+        grad: f32,
+        options: &AdamUpdateOptions,
+    ) -> (f32, f32, f32) {
+        let moment_1 = options.beta_1 * moment_1 + (1.0 - options.beta_1) * grad;
+        let moment_2 = options.beta_2 * moment_2 + (1.0 - options.beta_2) * grad * grad;
+        let moment_1_corrected = moment_1 / (1.0 - options.beta_1.powi(options.time as i32));
+        let moment_2_corrected = moment_2 / (1.0 - options.beta_2.powi(options.time as i32));
+        let param =
+            param - options.lr * moment_1_corrected / (moment_2_corrected.sqrt() + options.epsilon);
+
+        (param, moment_1, moment_2)
+    }
+
+    #[test]
+    fn should_support_rmsprop_update() {
+        let mut param = TestTensor::from_floats([[1.0, -2.0], [0.5, 3.0]]);
+        let mut square_avg = TestTensor::zeros([2, 2]);
+        let grads = [
+            TestTensor::from_floats([[0.1, -0.3], [2.0, 0.0]]),
+            TestTensor::from_floats([[-0.2, 0.4], [1.0, 0.5]]),
+        ];
+        let options = RmsPropUpdateOptions::new(0.01, 0.99, 1e-8);
+
+        let mut reference = [[1.0, -2.0], [0.5, 3.0]].map(|row| row.map(|p| (p, 0.0)));
+        let grads_ref = [[[0.1, -0.3], [2.0, 0.0]], [[-0.2, 0.4], [1.0, 0.5]]];
+
+        for (grad, grad_ref) in grads.into_iter().zip(grads_ref) {
+            (param, square_avg) = param.rmsprop_update(grad, square_avg, &options);
+
+            for (states, grads) in reference.iter_mut().zip(grad_ref) {
+                for (state, grad) in states.iter_mut().zip(grads) {
+                    *state = rmsprop_reference(*state, grad, &options);
+                }
+            }
+        }
+
+        let param_expected = Data::from(reference.map(|row| row.map(|(p, _)| p)));
+        let square_avg_expected = Data::from(reference.map(|row| row.map(|(_, v)| v)));
+
+        param_expected.assert_approx_eq(&param.into_data(), 5);
+        square_avg_expected.assert_approx_eq(&square_avg.into_data(), 5);
+    }
+
+    fn rmsprop_reference(
+        (param, square_avg): (f32, f32),
+        grad: f32,
+        options: &RmsPropUpdateOptions,
+    ) -> (f32, f32) {
+        let square_avg = options.alpha * square_avg + (1.0 - options.alpha) * grad * grad;
+        let param = param - options.lr * grad / (square_avg.sqrt() + options.epsilon);
+
+        (param, square_avg)
+    }
+
+    #[test]
+    fn should_support_sgd_momentum_update() {
+        sgd_momentum_update_test(false);
+    }
+
+    #[test]
+    fn should_support_sgd_nesterov_momentum_update() {
+        sgd_momentum_update_test(true);
+    }
+
+    fn sgd_momentum_update_test(nesterov: bool) {
+        let mut param = TestTensor::from_floats([[1.0, -2.0], [0.5, 3.0]]);
+        let mut velocity = TestTensor::zeros([2, 2]);
+        let grads_ref = [
+            [[0.1, -0.3], [2.0, 0.0]],
+            [[-0.2, 0.4], [1.0, 0.5]],
+            [[0.3, 0.1], [-0.5, 1.5]],
+        ];
+        let options = SgdMomentumUpdateOptions::new(0.1, 0.9, 0.01, nesterov);
+
+        let mut reference = [[1.0, -2.0], [0.5, 3.0]].map(|row| row.map(|p| (p, 0.0)));
+
+        for grad_ref in grads_ref {
+            let grad = TestTensor::from_floats(grad_ref);
+            (param, velocity) = param.sgd_momentum_update(grad, velocity, &options);
+
+            for (states, grads) in reference.iter_mut().zip(grad_ref) {
+                for (state, grad) in states.iter_mut().zip(grads) {
+                    *state = sgd_momentum_reference(*state, grad, &options);
+                }
+            }
+        }
+
+        let param_expected = Data::from(reference.map(|row| row.map(|(p, _)| p)));
+        let velocity_expected = Data::from(reference.map(|row| row.map(|(_, v)| v)));
+
+        param_expected.assert_approx_eq(&param.into_data(), 5);
+        velocity_expected.assert_approx_eq(&velocity.into_data(), 5);
+    }
+
+    fn sgd_momentum_reference(
+        (param, velocity): (f32, f32),
+        grad: f32,
+        options: &SgdMomentumUpdateOptions,
+    ) -> (f32, f32) {
+        let param = param - options.lr * options.weight_decay * param;
+        let velocity = options.momentum * velocity + grad;
+        let update = if options.nesterov {
+            grad + options.momentum * velocity
+        } else {
+            velocity
+        };
+
+        (param - options.lr * update, velocity)
+    }
+}
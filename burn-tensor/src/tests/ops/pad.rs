@@ -0,0 +1,37 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_pad_reflect_1d() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+
+        let data_actual = tensor.pad_reflect([(2, 2)]).into_data();
+
+        let data_expected = Data::from([3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_pad_reflect_2d() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.pad_reflect([(1, 0), (0, 1)]).into_data();
+
+        let data_expected = Data::from([
+            [4.0, 5.0, 6.0, 5.0],
+            [1.0, 2.0, 3.0, 2.0],
+            [4.0, 5.0, 6.0, 5.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_pad_reflect_exceeds_dim_size() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+
+        let _tensor = tensor.pad_reflect([(3, 0)]);
+    }
+}
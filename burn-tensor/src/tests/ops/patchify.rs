@@ -0,0 +1,30 @@
+#[burn_tensor_testgen::testgen(patchify)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn should_support_patchify() {
+        let values = (0..48).map(|value| value as f32).collect();
+        let data = Data::new(values, Shape::new([1, 3, 4, 4]));
+        let tensor = TestTensor::<4>::from_data(data.convert());
+
+        let patches = tensor.patchify(2);
+        assert_eq!(patches.dims(), [1, 4, 12]);
+
+        // The top-right patch, flattened by channel, then row, then column.
+        let patch = patches.index([0..1, 1..2]).reshape([12]);
+        let data_expected = Data::from([
+            2.0, 3.0, 6.0, 7.0, 18.0, 19.0, 22.0, 23.0, 34.0, 35.0, 38.0, 39.0,
+        ]);
+        data_expected.assert_approx_eq(&patch.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_patchify_size_does_not_divide_image() {
+        let tensor = TestTensor::<4>::zeros([1, 3, 5, 4]);
+
+        let _patches = tensor.patchify(2);
+    }
+}
@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(permute)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn should_permute_channels_last_to_channels_first() {
+        let values: Vec<f32> = (0..24).map(|value| value as f32).collect();
+        let tensor = TestTensor::from_data(Data::new(values, Shape::new([1, 2, 3, 4])).convert());
+
+        let permuted = tensor.clone().permute([0, 3, 1, 2]);
+        let swapped = tensor.swap_dims(2, 3).swap_dims(1, 2);
+
+        assert_eq!(permuted.shape(), Shape::new([1, 4, 2, 3]));
+        assert_eq!(permuted.into_data(), swapped.into_data());
+    }
+
+    #[test]
+    fn should_permute_3d() {
+        let tensor = TestTensor::from_floats([
+            [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+            [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+        ]);
+
+        let permuted = tensor.clone().permute([2, 0, 1]);
+        let swapped = tensor.swap_dims(0, 2).swap_dims(1, 2);
+
+        assert_eq!(
+            permuted.clone().into_data(),
+            Data::from([
+                [[0.0, 3.0], [6.0, 9.0]],
+                [[1.0, 4.0], [7.0, 10.0]],
+                [[2.0, 5.0], [8.0, 11.0]],
+            ])
+        );
+        assert_eq!(permuted.into_data(), swapped.into_data());
+    }
+
+    #[test]
+    fn should_permute_with_identity_axes() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let permuted = tensor.clone().permute([0, 1]);
+
+        assert_eq!(permuted.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_axes_are_not_a_permutation() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        tensor.permute([1, 1]);
+    }
+}
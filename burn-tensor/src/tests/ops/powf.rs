@@ -13,4 +13,60 @@ mod tests {
         let data_expected = Data::from([[0.0, 1.0, 1.6358], [2.182, 2.6759, 3.1352]]);
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    fn should_support_powf_tensor_with_negative_bases_and_integer_exponents() {
+        let lhs = TestTensor::from_floats([[-2.0, -3.0, -1.5], [2.0, -4.0, 0.0]]);
+        let rhs = TestTensor::from_floats([[3.0, 2.0, 1.0], [-1.0, 0.0, 4.0]]);
+
+        let data_actual = lhs.powf_tensor(rhs).into_data();
+
+        let data_expected = Data::from([[-8.0, 9.0, -1.5], [0.5, 1.0, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_powf_tensor_with_fractional_exponents() {
+        let lhs = TestTensor::from_floats([[4.0, 9.0, 2.0], [8.0, 1.0, 0.25]]);
+        let rhs = TestTensor::from_floats([[0.5, 1.5, 0.71], [-1.0 / 3.0, 2.5, 0.5]]);
+
+        let data_actual = lhs.powf_tensor(rhs).into_data();
+
+        let data_expected = Data::from([[2.0, 27.0, 1.6358], [0.5, 1.0, 0.5]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_powf_tensor_broadcasting() {
+        let lhs = TestTensor::from_floats([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+        let rhs = TestTensor::from_floats([[2.0, 3.0, 1.0]]);
+
+        let data_actual = lhs.powf_tensor(rhs).into_data();
+
+        let data_expected = Data::from([[1.0, 8.0, 3.0], [1.0, -8.0, -3.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_powf_tensor_with_negative_bases_and_fractional_exponents() {
+        let lhs = TestTensor::from_floats([-4.0, -8.0, -2.0]);
+        let rhs = TestTensor::from_floats([0.5, 1.0 / 3.0, 2.0]);
+
+        let data_actual = lhs.powf_tensor(rhs).into_data();
+
+        assert!(data_actual.value[0].is_nan());
+        assert!(data_actual.value[1].is_nan());
+        assert_eq!(data_actual.value[2], 4.0);
+    }
+
+    #[test]
+    fn should_support_powf_tensor_with_zero_bases() {
+        let lhs = TestTensor::from_floats([0.0, 0.0, 0.0]);
+        let rhs = TestTensor::from_floats([0.0, 2.0, -1.0]);
+
+        let data_actual = lhs.powf_tensor(rhs).into_data();
+
+        let data_expected = Data::from([1.0, 0.0, f32::INFINITY]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
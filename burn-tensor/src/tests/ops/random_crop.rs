@@ -0,0 +1,62 @@
+#[burn_tensor_testgen::testgen(random_crop)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, Shape, Tensor};
+
+    #[test]
+    fn should_crop_with_the_given_shape() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 4>::ones([2, 3, 8, 6]);
+
+        let output = tensor.random_crop(4, 5, &device);
+
+        assert_eq!(output.shape(), Shape::new([2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn should_crop_the_whole_tensor_when_crop_has_the_same_shape() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+
+        let output = tensor.clone().random_crop(2, 2, &device);
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_crop_deterministically_with_a_fixed_seed() {
+        let device = Default::default();
+        let [height, width] = [6, 5];
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::new(
+            (0..height * width).map(|i| i as f32).collect(),
+            Shape::new([height * width]),
+        ))
+        .reshape([1, height, width]);
+
+        TestBackend::seed(42);
+        let output_1 = tensor.clone().random_crop(3, 2, &device);
+        TestBackend::seed(42);
+        let output_2 = tensor.clone().random_crop(3, 2, &device);
+        let output_1 = output_1.into_data().convert::<f32>();
+        let output_2 = output_2.into_data().convert::<f32>();
+
+        output_1.assert_approx_eq(&output_2, 3);
+
+        // The first value of the crop gives its top-left corner.
+        let top = output_1.value[0] as usize / width;
+        let left = output_1.value[0] as usize % width;
+        assert!(top <= height - 3 && left <= width - 2);
+
+        let expected = tensor.index([0..1, top..top + 3, left..left + 2]);
+        output_1.assert_approx_eq(&expected.into_data().convert(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_crop_is_larger_than_tensor() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 3>::ones([3, 4, 4]);
+
+        tensor.random_crop(5, 2, &device);
+    }
+}
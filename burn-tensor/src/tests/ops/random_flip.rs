@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(random_flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_always_flip_with_probability_one() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data(Data::from([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]));
+
+        let horizontal = tensor.clone().random_flip(1.0, 2, &device);
+        let vertical = tensor.random_flip(1.0, 1, &device);
+
+        horizontal
+            .into_data()
+            .assert_approx_eq(&Data::from([[[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]]]), 3);
+        vertical
+            .into_data()
+            .assert_approx_eq(&Data::from([[[3.0, 4.0, 5.0], [0.0, 1.0, 2.0]]]), 3);
+    }
+
+    #[test]
+    fn should_never_flip_with_probability_zero() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data(Data::from([[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]));
+
+        let output = tensor.clone().random_flip(0.0, 2, &device);
+
+        output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_probability_is_invalid() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 2>::ones([2, 2]);
+
+        tensor.random_flip(1.5, 1, &device);
+    }
+}
@@ -0,0 +1,26 @@
+#[burn_tensor_testgen::testgen(recip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_support_recip_ops() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 4.0], [-0.5, 0.25, -8.0]]);
+
+        let data_actual = tensor.recip().into_data();
+
+        let data_expected = Data::from([[1.0, 0.5, 0.25], [-2.0, 4.0, -0.125]]);
+        data_actual.assert_approx_eq(&data_expected, 5);
+    }
+
+    #[test]
+    fn should_give_infinity_for_zero() {
+        let tensor = TestTensor::from_floats([0.0, 2.0]);
+
+        let data_actual = tensor.recip().into_data();
+
+        assert!(data_actual.value[0].is_infinite());
+        assert!(data_actual.value[0] > 0.0);
+        assert_eq!(data_actual.value[1], 0.5);
+    }
+}
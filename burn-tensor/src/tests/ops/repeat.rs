@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(repeat)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Shape, Tensor};
 
     #[test]
     fn should_support_repeat_ops() {
@@ -18,4 +18,39 @@ mod tests {
         ]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_repeat_dim0_with_more_than_one_item() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let output = tensor.repeat(0, 2);
+
+        assert_eq!(output.shape(), Shape::new([4, 2]));
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 1.0], [2.0, 3.0], [0.0, 1.0], [2.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_repeat_middle_dim() {
+        let tensor = TestTensor::from_floats([[[0.0], [1.0]], [[2.0], [3.0]]]);
+
+        let output = tensor.repeat(1, 2);
+
+        assert_eq!(output.shape(), Shape::new([2, 4, 1]));
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[0.0], [1.0], [0.0], [1.0]], [[2.0], [3.0], [2.0], [3.0]]])
+        );
+    }
+
+    #[test]
+    fn should_repeat_int_tensor() {
+        let tensor = TestTensorInt::from_ints([[1, 2]]);
+
+        let output = tensor.repeat(1, 3);
+
+        assert_eq!(output.into_data(), Data::from([[1, 2, 1, 2, 1, 2]]));
+    }
 }
@@ -24,4 +24,44 @@ mod tests {
         let data_expected = Data::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_reshape_infer_first_dim() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([0.0; 24]));
+
+        let tensor_actual: Tensor<TestBackend, 2> = tensor.reshape_infer([-1, 4]);
+
+        assert_eq!(tensor_actual.shape().dims, [6, 4]);
+    }
+
+    #[test]
+    fn should_support_reshape_infer_middle_dim() {
+        let data = Data::from([
+            0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0, 17.0, 18.0, 19.0, 20.0, 21.0, 22.0, 23.0,
+        ]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data.clone());
+
+        let tensor_actual: Tensor<TestBackend, 3> = tensor.reshape_infer([2, -1, 3]);
+
+        assert_eq!(tensor_actual.shape().dims, [2, 4, 3]);
+        assert_eq!(tensor_actual.reshape([24]).into_data(), data);
+    }
+
+    #[test]
+    fn should_support_reshape_infer_copied_dim() {
+        let tensor = Tensor::<TestBackend, 3>::zeros([2, 3, 4]);
+
+        let tensor_actual: Tensor<TestBackend, 2> = tensor.reshape_infer([0, -1]);
+
+        assert_eq!(tensor_actual.shape().dims, [2, 12]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reshape_infer_is_ambiguous() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([0.0; 24]));
+
+        let _tensor: Tensor<TestBackend, 3> = tensor.reshape_infer([-1, -1, 4]);
+    }
 }
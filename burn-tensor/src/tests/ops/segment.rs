@@ -0,0 +1,63 @@
+#[burn_tensor_testgen::testgen(segment)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    fn rows() -> TestTensor<2> {
+        TestTensor::from_floats([[1.0, 2.0], [3.0, -4.0], [5.0, 6.0], [-7.0, 8.0], [9.0, 0.0]])
+    }
+
+    fn segment_ids() -> TestTensorInt<1> {
+        TestTensorInt::from_ints([0, 2, 0, 1, 2])
+    }
+
+    #[test]
+    fn should_support_segment_sum() {
+        let output = rows().segment_sum(segment_ids(), 3);
+
+        // Rows [0, 2], [3] and [1, 4].
+        let expected = Data::from([[6.0, 8.0], [-7.0, 8.0], [12.0, -4.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_segment_mean() {
+        let output = rows().segment_mean(segment_ids(), 3);
+
+        let expected = Data::from([[3.0, 4.0], [-7.0, 8.0], [6.0, -2.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_support_segment_max() {
+        let output = rows().segment_max(segment_ids(), 3);
+
+        let expected = Data::from([[5.0, 6.0], [-7.0, 8.0], [9.0, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn should_fill_empty_segments_with_zeros() {
+        let tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+        let segment_ids = TestTensorInt::from_ints([0, 0, 2]);
+
+        let sum = tensor.clone().segment_sum(segment_ids.clone(), 3);
+        let mean = tensor.clone().segment_mean(segment_ids.clone(), 3);
+        let max = tensor.segment_max(segment_ids, 3);
+
+        sum.into_data()
+            .assert_approx_eq(&Data::from([3.0, 0.0, 3.0]), 5);
+        mean.into_data()
+            .assert_approx_eq(&Data::from([1.5, 0.0, 3.0]), 5);
+        max.into_data()
+            .assert_approx_eq(&Data::from([2.0, 0.0, 3.0]), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_number_of_segment_ids_differs() {
+        let segment_ids = TestTensorInt::from_ints([0, 1]);
+
+        rows().segment_sum(segment_ids, 2);
+    }
+}
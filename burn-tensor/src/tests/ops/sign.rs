@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(sign)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_sign_ops_float() {
+        let tensor = TestTensor::from_floats([[-2.5, 0.0, 3.0], [-0.0, 1e-3, -7.0]]);
+
+        let data_actual = tensor.sign().into_data();
+
+        let data_expected = Data::from([[-1.0, 0.0, 1.0], [0.0, 1.0, -1.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_sign_ops_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[-2, 0, 3], [0, 1, -7]]);
+
+        let data_actual = tensor.sign().into_data();
+
+        let data_expected = Data::from([[-1, 0, 1], [0, 1, -1]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_map_nan_to_zero() {
+        let tensor = TestTensor::from_floats([f32::NAN, -1.0]);
+
+        let data_actual = tensor.sign().into_data();
+
+        assert_eq!(Data::from([0.0, -1.0]), data_actual);
+    }
+}
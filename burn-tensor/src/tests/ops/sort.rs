@@ -0,0 +1,145 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_sort_2d_dim0_ascending() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [1.0, 1.0, 5.0], [2.0, 1.0, 0.0]]);
+
+        let (values, indexes) = tensor.sort_with_indexes(0, false);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[1.0, 1.0, 0.0], [2.0, 1.0, 2.0], [3.0, 1.0, 5.0]])
+        );
+        assert_eq!(
+            indexes.into_data(),
+            Data::from([[1, 0, 2], [2, 1, 0], [0, 2, 1]])
+        );
+    }
+
+    #[test]
+    fn should_sort_2d_dim0_descending() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [1.0, 1.0, 5.0], [2.0, 1.0, 0.0]]);
+
+        let values = tensor.clone().sort(0, true);
+        let indexes = tensor.argsort(0, true);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[3.0, 1.0, 5.0], [2.0, 1.0, 2.0], [1.0, 1.0, 0.0]])
+        );
+        assert_eq!(
+            indexes.into_data(),
+            Data::from([[0, 0, 1], [2, 1, 0], [1, 2, 2]])
+        );
+    }
+
+    #[test]
+    fn should_sort_3d_dim1() {
+        let tensor = TestTensor::from_floats([[[1.0, 4.0], [3.0, 2.0]], [[0.0, 0.0], [-1.0, 5.0]]]);
+
+        let (values, indexes) = tensor.sort_with_indexes(1, false);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[[1.0, 2.0], [3.0, 4.0]], [[-1.0, 0.0], [0.0, 5.0]]])
+        );
+        assert_eq!(
+            indexes.into_data(),
+            Data::from([[[0, 1], [1, 0]], [[1, 0], [0, 1]]])
+        );
+    }
+
+    #[test]
+    fn argsort_should_keep_original_order_of_duplicates() {
+        let tensor = TestTensorInt::from_ints([[2, 5, 2, 5, 1]]);
+
+        let ascending = tensor.clone().argsort(1, false);
+        let descending = tensor.argsort(1, true);
+
+        assert_eq!(ascending.into_data(), Data::from([[4, 0, 2, 1, 3]]));
+        assert_eq!(descending.into_data(), Data::from([[1, 3, 0, 2, 4]]));
+    }
+
+    #[test]
+    fn should_sort_int_tensor() {
+        let tensor = TestTensorInt::from_ints([[2, 5, 2, 5, 1], [0, -3, 4, 4, 1]]);
+
+        let values = tensor.sort(1, true);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[5, 5, 2, 2, 1], [4, 4, 1, 0, -3]])
+        );
+    }
+
+    #[test]
+    fn sort_should_match_max_dim_with_indexes() {
+        let tensor = TestTensor::from_floats([[1.0, 7.0, 7.0, 2.0], [4.0, 4.0, 0.0, 4.0]]);
+
+        let (max_values, max_indexes) = tensor.clone().max_dim_with_indexes(1);
+        let (values, indexes) = tensor.sort_with_indexes(1, true);
+
+        assert_eq!(
+            values.index([0..2, 0..1]).into_data(),
+            max_values.into_data()
+        );
+        assert_eq!(
+            indexes.index([0..2, 0..1]).into_data(),
+            max_indexes.into_data()
+        );
+    }
+
+    #[test]
+    fn topk_with_k_1_should_match_max_dim_with_indexes() {
+        let tensor = TestTensor::from_floats([[1.0, 7.0, 7.0, 2.0], [4.0, 4.0, 0.0, 4.0]]);
+
+        let (max_values, max_indexes) = tensor.clone().max_dim_with_indexes(1);
+        let (values, indexes) = tensor.topk(1, 1);
+
+        assert_eq!(values.into_data(), max_values.into_data());
+        assert_eq!(indexes.into_data(), max_indexes.into_data());
+    }
+
+    #[test]
+    fn topk_with_full_k_should_match_descending_sort() {
+        let tensor = TestTensor::from_floats([[3.0, 1.0, 2.0], [1.0, 1.0, 5.0], [2.0, 1.0, 0.0]]);
+
+        let (sorted_values, sorted_indexes) = tensor.clone().sort_with_indexes(0, true);
+        let (values, indexes) = tensor.topk(3, 0);
+
+        assert_eq!(values.into_data(), sorted_values.into_data());
+        assert_eq!(indexes.into_data(), sorted_indexes.into_data());
+    }
+
+    #[test]
+    fn should_select_topk_along_dim0() {
+        let tensor = TestTensorInt::from_ints([[3, 1, 2], [1, 1, 5], [2, 1, 0]]);
+
+        let (values, indexes) = tensor.topk(2, 0);
+
+        assert_eq!(values.into_data(), Data::from([[3, 1, 5], [2, 1, 2]]));
+        assert_eq!(indexes.into_data(), Data::from([[0, 0, 1], [2, 1, 0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn topk_should_panic_when_k_is_larger_than_the_dimension() {
+        let tensor = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        tensor.topk(3, 1);
+    }
+
+    #[test]
+    fn should_sort_nan_last() {
+        let tensor = TestTensor::from_floats([[2.0, f32::NAN, -1.0, f32::NAN, 3.0]]);
+
+        let ascending = tensor.clone().argsort(1, false);
+        let descending = tensor.argsort(1, true);
+
+        assert_eq!(ascending.into_data(), Data::from([[2, 0, 4, 1, 3]]));
+        assert_eq!(descending.into_data(), Data::from([[4, 0, 2, 1, 3]]));
+    }
+}
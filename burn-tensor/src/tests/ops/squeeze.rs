@@ -34,4 +34,40 @@ mod tests {
         let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 5]));
         let squeezed_tensor: Tensor<TestBackend, 3> = tensor.squeeze(2);
     }
+    /// Test if the function can insert a size 1 dimension at each position of a 2D tensor.
+    #[test]
+    fn should_unsqueeze_dim() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([3, 4]));
+
+        let first: Tensor<TestBackend, 3> = tensor.clone().unsqueeze_dim(0);
+        let middle: Tensor<TestBackend, 3> = tensor.clone().unsqueeze_dim(1);
+        let last: Tensor<TestBackend, 3> = tensor.unsqueeze_dim(2);
+
+        assert_eq!(first.shape(), Shape::new([1, 3, 4]));
+        assert_eq!(middle.shape(), Shape::new([3, 1, 4]));
+        assert_eq!(last.shape(), Shape::new([3, 4, 1]));
+    }
+    /// Test if a tensor is unchanged after an unsqueeze followed by a squeeze.
+    #[test]
+    fn should_round_trip_unsqueeze_dim_and_squeeze() {
+        let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data.clone());
+
+        let unsqueezed: Tensor<TestBackend, 3> = tensor.unsqueeze_dim(1);
+        let data_unsqueezed = unsqueezed.clone().into_data();
+        let squeezed: Tensor<TestBackend, 2> = unsqueezed.squeeze(1);
+
+        assert_eq!(
+            data_unsqueezed,
+            Data::from([[[0.0, 1.0, 2.0]], [[3.0, 4.0, 5.0]]])
+        );
+        assert_eq!(squeezed.into_data(), data);
+    }
+    /// Test if the function panics when the inserted dimension is out of bounds.
+    #[test]
+    #[should_panic]
+    fn should_unsqueeze_dim_panic() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([3, 4]));
+        tensor.unsqueeze_dim::<3>(3);
+    }
 }
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(stack)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_vstack_1d() {
+        let tensor_1 = TestTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+        let tensor_2 = TestTensor::from_data(Data::from([4.0, 5.0, 6.0]));
+
+        let tensor: TestTensor<2> = Tensor::vstack(vec![tensor_1, tensor_2]);
+        let data_actual = tensor.into_data();
+
+        let data_expected = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_vstack_2d() {
+        let tensor_1 = TestTensor::from_data(Data::from([[1.0, 2.0]]));
+        let tensor_2 = TestTensor::from_data(Data::from([[3.0, 4.0], [5.0, 6.0]]));
+
+        let tensor: TestTensor<2> = Tensor::vstack(vec![tensor_1, tensor_2]);
+        let data_actual = tensor.into_data();
+
+        let data_expected = Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_hstack_1d() {
+        let tensor_1 = TestTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+        let tensor_2 = TestTensor::from_data(Data::from([4.0, 5.0, 6.0]));
+
+        let data_actual = Tensor::hstack(vec![tensor_1, tensor_2]).into_data();
+
+        let data_expected = Data::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_hstack_2d() {
+        let tensor_1 = TestTensor::from_data(Data::from([[1.0], [2.0]]));
+        let tensor_2 = TestTensor::from_data(Data::from([[3.0, 4.0], [5.0, 6.0]]));
+
+        let data_actual = Tensor::hstack(vec![tensor_1, tensor_2]).into_data();
+
+        let data_expected = Data::from([[1.0, 3.0, 4.0], [2.0, 5.0, 6.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(transpose)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Shape, Tensor};
 
     #[test]
     fn should_support_transpose_ops() {
@@ -35,4 +35,30 @@ mod tests {
         ]);
         data_expected.assert_approx_eq(&data_actual, 3);
     }
+
+    #[test]
+    #[should_panic(expected = "swap_dims: axis 3 out of range for rank 2")]
+    fn should_panic_when_swap_dims_out_of_range() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0], [2.0, 3.0]]);
+
+        let _tensor = tensor.swap_dims(0, 3);
+    }
+
+    #[test]
+    fn should_round_trip_nchw_nhwc() {
+        let values = (0..120).map(|value| value as f32).collect();
+        let data = Data::new(values, Shape::new([2, 3, 4, 5]));
+        let tensor = TestTensor::<4>::from_data(data.clone().convert());
+
+        let nhwc = tensor.nchw_to_nhwc();
+        assert_eq!(nhwc.dims(), [2, 4, 5, 3]);
+
+        let nhwc_data = nhwc.clone().into_data().convert::<f32>();
+        // The second channel of the first pixel, i.e. `nchw[0, 1, 0, 0]`.
+        assert_eq!(nhwc_data.value[1], 20.0);
+
+        let nchw = nhwc.nhwc_to_nchw();
+        assert_eq!(nchw.dims(), [2, 3, 4, 5]);
+        nchw.into_data().assert_approx_eq(&data.convert(), 3);
+    }
 }
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(windows)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_create_overlapping_windows() {
+        let series = TestTensor::from_floats([
+            [0.0, 10.0],
+            [1.0, 11.0],
+            [2.0, 12.0],
+            [3.0, 13.0],
+            [4.0, 14.0],
+            [5.0, 15.0],
+            [6.0, 16.0],
+            [7.0, 17.0],
+            [8.0, 18.0],
+            [9.0, 19.0],
+        ]);
+
+        let windows: Tensor<TestBackend, 3> = series.windows(4, 2);
+
+        assert_eq!(windows.dims(), [4, 4, 2]);
+        assert_eq!(
+            windows.into_data(),
+            Data::from([
+                [[0.0, 10.0], [1.0, 11.0], [2.0, 12.0], [3.0, 13.0]],
+                [[2.0, 12.0], [3.0, 13.0], [4.0, 14.0], [5.0, 15.0]],
+                [[4.0, 14.0], [5.0, 15.0], [6.0, 16.0], [7.0, 17.0]],
+                [[6.0, 16.0], [7.0, 17.0], [8.0, 18.0], [9.0, 19.0]],
+            ])
+        );
+    }
+
+    #[test]
+    fn should_drop_incomplete_trailing_window() {
+        let series = TestTensorInt::arange(0..7);
+
+        let windows: Tensor<TestBackend, 2, Int> = series.windows(3, 3);
+
+        assert_eq!(windows.into_data(), Data::from([[0, 1, 2], [3, 4, 5]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_window_is_larger_than_series() {
+        let series = TestTensor::from_floats([1.0, 2.0, 3.0]);
+
+        let _: Tensor<TestBackend, 2> = series.windows(4, 1);
+    }
+}
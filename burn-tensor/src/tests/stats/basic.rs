@@ -15,6 +15,35 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 3);
     }
 
+    #[test]
+    fn test_var_bias() {
+        let data = Data::from([[1.0, 2.0, 6.0], [-3.0, 0.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.clone().var_bias(1).into_data();
+        let data_actual_dim_0 = tensor.var_bias(0).into_data();
+
+        // Means of 3 and 0, divided by N = 3.
+        let data_expected = Data::from([[14.0 / 3.0], [6.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+        let data_expected = Data::from([[4.0, 1.0, 2.25]]);
+        data_expected.assert_approx_eq(&data_actual_dim_0, 3);
+    }
+
+    #[test]
+    fn test_std() {
+        let data = Data::from([[1.0, 2.0, 6.0], [-3.0, 0.0, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.clone().std(1).into_data();
+        let data_actual_bias = tensor.std_bias(1).into_data();
+
+        let data_expected = Data::from([[7.0_f32.sqrt()], [3.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+        let data_expected = Data::from([[(14.0_f32 / 3.0).sqrt()], [6.0_f32.sqrt()]]);
+        data_expected.assert_approx_eq(&data_actual_bias, 3);
+    }
+
     #[test]
     fn test_display_2d_int_tensor() {
         let int_data = Data::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
@@ -82,6 +82,50 @@ impl<B: Backend> Numeric for AccuracyMetric<B> {
     }
 }
 
+/// Compute the fraction of correct predictions from the logits and the integer targets.
+///
+/// Without `top_k`, the predicted class is the argmax over the class dimension. With `top_k`, a
+/// prediction is correct when the target is among the `k` classes with the highest logits, ties
+/// being counted in favor of the target.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, num_classes]`
+/// - targets: `[batch_size]`
+pub fn accuracy<B: Backend>(
+    logits: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+    top_k: Option<usize>,
+) -> f64 {
+    let [batch_size, num_classes] = logits.dims();
+
+    let num_correct = match top_k {
+        None => logits
+            .argmax(1)
+            .reshape([batch_size])
+            .equal(targets)
+            .into_int()
+            .sum(),
+        Some(k) => {
+            assert!(
+                k > 0 && k <= num_classes,
+                "top_k should be between 1 and the number of classes ({num_classes}), got {k}"
+            );
+
+            let target_logits = logits.clone().gather(1, targets.reshape([batch_size, 1]));
+            let rank = logits
+                .sub(target_logits)
+                .greater_elem(0.0)
+                .into_int()
+                .sum_dim(1);
+
+            rank.lower_elem(k as i64).into_int().sum()
+        }
+    };
+
+    num_correct.into_scalar().elem::<f64>() / batch_size as f64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +167,31 @@ mod tests {
         let _entry = metric.update(&input, &MetricMetadata::fake());
         assert_eq!(50.0, metric.value());
     }
+
+    #[test]
+    fn test_accuracy_top_1() {
+        let logits = Tensor::<TestBackend, 2>::from_data([
+            [0.0, 0.2, 0.8], // 2
+            [1.0, 2.0, 0.5], // 1
+            [0.4, 0.1, 0.2], // 0
+            [0.6, 0.7, 0.2], // 1
+        ]);
+        let targets = Tensor::from_data([2, 2, 1, 1]);
+
+        assert_eq!(0.5, accuracy(logits, targets, None));
+    }
+
+    #[test]
+    fn test_accuracy_top_2() {
+        let logits = Tensor::<TestBackend, 2>::from_data([
+            [0.0, 0.2, 0.8], // 2, 1
+            [1.0, 2.0, 0.5], // 1, 0
+            [0.4, 0.1, 0.2], // 0, 2
+            [0.6, 0.7, 0.2], // 1, 0
+        ]);
+        let targets = Tensor::from_data([1, 2, 1, 0]);
+
+        assert_eq!(0.5, accuracy(logits.clone(), targets.clone(), Some(2)));
+        assert_eq!(0.0, accuracy(logits, targets, Some(1)));
+    }
 }
@@ -0,0 +1,125 @@
+use super::confusion_matrix;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Per class and macro averaged precision, recall and F1 score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassificationReport {
+    /// The precision of each class.
+    pub precision: Vec<f64>,
+    /// The recall of each class.
+    pub recall: Vec<f64>,
+    /// The F1 score of each class.
+    pub f1: Vec<f64>,
+    /// The unweighted mean of the precision of each class.
+    pub macro_precision: f64,
+    /// The unweighted mean of the recall of each class.
+    pub macro_recall: f64,
+    /// The unweighted mean of the F1 score of each class.
+    pub macro_f1: f64,
+}
+
+/// Compute the precision, recall and F1 score of each class, as well as their macro average,
+/// from the predicted and target classes.
+///
+/// # Notes
+///
+/// When a class is never predicted its precision is undefined and is set to 0. The same goes
+/// for the recall of a class absent from the targets, and for the F1 score when both the
+/// precision and recall are 0.
+///
+/// # Shapes
+///
+/// - predictions: `[batch_size]`
+/// - targets: `[batch_size]`
+pub fn classification_report<B: Backend>(
+    predictions: Tensor<B, 1, Int>,
+    targets: Tensor<B, 1, Int>,
+    num_classes: usize,
+) -> ClassificationReport {
+    let matrix = confusion_matrix(predictions, targets, num_classes);
+
+    let num_targets = counts(matrix.clone().sum_dim(1));
+    let num_predicted = counts(matrix.clone().sum_dim(0));
+    let matrix = counts(matrix);
+
+    let ratio = |num: f64, den: f64| if den == 0.0 { 0.0 } else { num / den };
+
+    let mut precision = Vec::with_capacity(num_classes);
+    let mut recall = Vec::with_capacity(num_classes);
+    let mut f1 = Vec::with_capacity(num_classes);
+
+    for class in 0..num_classes {
+        let true_positives = matrix[class * num_classes + class];
+        let class_precision = ratio(true_positives, num_predicted[class]);
+        let class_recall = ratio(true_positives, num_targets[class]);
+        let class_f1 = ratio(
+            2.0 * class_precision * class_recall,
+            class_precision + class_recall,
+        );
+
+        precision.push(class_precision);
+        recall.push(class_recall);
+        f1.push(class_f1);
+    }
+
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / num_classes as f64;
+
+    ClassificationReport {
+        macro_precision: mean(&precision),
+        macro_recall: mean(&recall),
+        macro_f1: mean(&f1),
+        precision,
+        recall,
+        f1,
+    }
+}
+
+fn counts<B: Backend, const D: usize>(tensor: Tensor<B, D, Int>) -> Vec<f64> {
+    tensor.into_data().convert::<f64>().value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    fn assert_approx_eq(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "Expected {expected}, but got {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_classification_report() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 2, 1, 0, 2]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 1, 1, 2, 2]);
+
+        let report = classification_report(predictions, targets, 3);
+
+        assert_approx_eq(&report.precision, &[0.5, 1.0, 2.0 / 3.0]);
+        assert_approx_eq(&report.recall, &[1.0, 2.0 / 3.0, 2.0 / 3.0]);
+        assert_approx_eq(&report.f1, &[2.0 / 3.0, 0.8, 2.0 / 3.0]);
+        assert_approx_eq(
+            &[report.macro_precision, report.macro_recall, report.macro_f1],
+            &[0.72222, 0.77778, 0.71111],
+        );
+    }
+
+    #[test]
+    fn test_classification_report_with_class_never_predicted() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([0, 0, 1]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([0, 2, 1]);
+
+        let report = classification_report(predictions, targets, 3);
+
+        assert_approx_eq(&report.precision, &[0.5, 1.0, 0.0]);
+        assert_approx_eq(&report.recall, &[1.0, 1.0, 0.0]);
+        assert_approx_eq(&report.f1, &[2.0 / 3.0, 1.0, 0.0]);
+    }
+}
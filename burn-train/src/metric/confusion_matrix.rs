@@ -0,0 +1,68 @@
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+
+/// Compute the confusion matrix of the given predicted classes against the target classes.
+///
+/// The cell `[i, j]` of the matrix counts the number of items of target class `i` that were
+/// predicted as class `j`, so the diagonal contains the correct predictions.
+///
+/// # Shapes
+///
+/// - predictions: `[batch_size]`
+/// - targets: `[batch_size]`
+/// - output: `[num_classes, num_classes]`
+pub fn confusion_matrix<B: Backend>(
+    predictions: Tensor<B, 1, Int>,
+    targets: Tensor<B, 1, Int>,
+    num_classes: usize,
+) -> Tensor<B, 2, Int> {
+    let [batch_size] = predictions.dims();
+    assert_eq!(
+        [batch_size],
+        targets.dims(),
+        "The predictions and targets should have the same number of items"
+    );
+
+    let device = predictions.device();
+    let cells = targets.mul_scalar(num_classes as i64).add(predictions);
+    let counts = Tensor::<B, 1, Int>::zeros_device([num_classes * num_classes], &device).scatter(
+        0,
+        cells,
+        Tensor::ones_device([batch_size], &device),
+    );
+
+    counts.reshape([num_classes, num_classes])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Data;
+
+    #[test]
+    fn test_confusion_matrix() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 2, 1, 0, 2]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([0, 1, 2, 1, 1, 2, 2]);
+
+        let matrix = confusion_matrix(predictions, targets, 3);
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[1, 0, 0], [0, 2, 1], [1, 0, 2]])
+        );
+    }
+
+    #[test]
+    fn test_confusion_matrix_with_unseen_classes() {
+        let predictions = Tensor::<TestBackend, 1, Int>::from_data([1, 1]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data([1, 0]);
+
+        let matrix = confusion_matrix(predictions, targets, 4);
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[0, 1, 0, 0], [0, 1, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]])
+        );
+    }
+}
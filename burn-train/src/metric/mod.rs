@@ -3,12 +3,16 @@ pub mod state;
 
 mod acc;
 mod base;
+mod classification_report;
+mod confusion_matrix;
 mod cuda;
 mod learning_rate;
 mod loss;
 
 pub use acc::*;
 pub use base::*;
+pub use classification_report::*;
+pub use confusion_matrix::*;
 pub use cuda::*;
 pub use learning_rate::*;
 pub use loss::*;